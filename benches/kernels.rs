@@ -0,0 +1,123 @@
+//! Compare contiguous-slice kernels with the closure-based `Elemwise` path.
+//!
+//! Run with `cargo bench`.
+
+#[macro_use]
+extern crate bencher;
+extern crate brassfibre;
+extern crate nullvec;
+
+use bencher::Bencher;
+use brassfibre::prelude::*;
+use nullvec::prelude::dev::algos::Elemwise;
+
+const LEN: usize = 100_000;
+
+fn floats() -> Vec<f64> {
+    (0..LEN).map(|x| x as f64 * 0.5).collect()
+}
+
+fn ints() -> Vec<i64> {
+    (0..LEN as i64).collect()
+}
+
+fn nullvec() -> NullVec<f64> {
+    let mask: Vec<bool> = (0..LEN).map(|x| x % 10 == 0).collect();
+    NullVec::with_mask(floats(), Some(mask))
+}
+
+fn add_f64_closure(b: &mut Bencher) {
+    let (l, r) = (floats(), floats());
+    b.iter(|| Elemwise::elemwise_rr(&l, &r, |x, y| x + y));
+}
+
+fn add_f64_kernel(b: &mut Bencher) {
+    let (l, r) = (floats(), floats());
+    b.iter(|| Vectorized::add(&l, &r));
+}
+
+fn add_f64_series(b: &mut Bencher) {
+    let s = Series::<f64, usize>::from_vec(floats());
+    b.iter(|| &s + &s);
+}
+
+fn mul_i64_scalar_closure(b: &mut Bencher) {
+    let l = ints();
+    b.iter(|| Elemwise::broadcast_rr(&l, &3, |x, y| x * y));
+}
+
+fn mul_i64_scalar_kernel(b: &mut Bencher) {
+    let l = ints();
+    b.iter(|| Vectorized::mul_scalar(&l, 3));
+}
+
+fn lt_f64_closure(b: &mut Bencher) {
+    let (l, r) = (floats(), floats());
+    b.iter(|| Elemwise::elemwise_rr(&l, &r, |x, y| x < y));
+}
+
+fn lt_f64_kernel(b: &mut Bencher) {
+    let (l, r) = (floats(), floats());
+    b.iter(|| Vectorized::lt(&l, &r));
+}
+
+fn sum_f64_fold(b: &mut Bencher) {
+    let l = floats();
+    b.iter(|| l.iter().fold(0., |a, b| a + b));
+}
+
+fn sum_f64_kernel(b: &mut Bencher) {
+    let l = floats();
+    b.iter(|| Vectorized::sum(&l));
+}
+
+fn max_i64_fold(b: &mut Bencher) {
+    let l = ints();
+    b.iter(|| l.iter().fold(i64::min_value(), |a, &b| if b > a { b } else { a }));
+}
+
+fn max_i64_kernel(b: &mut Bencher) {
+    let l = ints();
+    b.iter(|| Vectorized::max(&l));
+}
+
+fn add_nullvec_closure(b: &mut Bencher) {
+    let (l, r) = (nullvec(), nullvec());
+    b.iter(|| &l + &r);
+}
+
+fn add_nullvec_kernel(b: &mut Bencher) {
+    let (ldata, lmask) = Vectorized::nullvec_parts(&nullvec());
+    let (rdata, rmask) = Vectorized::nullvec_parts(&nullvec());
+    let mask = Vectorized::mask_union(&lmask, &rmask);
+    b.iter(|| Vectorized::masked_add(&ldata, &rdata, &mask));
+}
+
+fn sum_nullvec_closure(b: &mut Bencher) {
+    let l = nullvec();
+    b.iter(|| l.sum());
+}
+
+fn sum_nullvec_kernel(b: &mut Bencher) {
+    let (data, mask) = Vectorized::nullvec_parts(&nullvec());
+    b.iter(|| Vectorized::masked_sum(&data, &mask));
+}
+
+benchmark_group!(arithmetic,
+                 add_f64_closure,
+                 add_f64_kernel,
+                 add_f64_series,
+                 mul_i64_scalar_closure,
+                 mul_i64_scalar_kernel);
+benchmark_group!(comparison, lt_f64_closure, lt_f64_kernel);
+benchmark_group!(reduction,
+                 sum_f64_fold,
+                 sum_f64_kernel,
+                 max_i64_fold,
+                 max_i64_kernel);
+benchmark_group!(nullable,
+                 add_nullvec_closure,
+                 add_nullvec_kernel,
+                 sum_nullvec_closure,
+                 sum_nullvec_kernel);
+benchmark_main!(arithmetic, comparison, reduction, nullable);
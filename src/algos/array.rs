@@ -0,0 +1,234 @@
+use std::f64;
+
+use num::ToPrimitive;
+use nullvec::prelude::{Array, NullVec, Scalar};
+use nullvec::prelude::dev::NullStorable;
+
+use algos::computation::{Aggregation, Summation, Product};
+use algos::kernels::{Vectorized, Primitive};
+
+macro_rules! numeric_to_f64 {
+    ($vals:ident) => {
+        $vals.iter_raw()
+            .map(|(null, v)| if null { f64::NAN } else { v.to_f64().unwrap() })
+            .collect()
+    }
+}
+
+/// Convert numeric `Array` to `Vec<f64>`, null is represented as NaN
+pub fn to_f64_vec(values: &Array) -> Vec<f64> {
+    match values {
+        &Array::Int64Array(ref vals) => numeric_to_f64!(vals),
+        &Array::Int32Array(ref vals) => numeric_to_f64!(vals),
+        &Array::Int16Array(ref vals) => numeric_to_f64!(vals),
+        &Array::Int8Array(ref vals) => numeric_to_f64!(vals),
+        &Array::IsizeArray(ref vals) => numeric_to_f64!(vals),
+        &Array::UInt64Array(ref vals) => numeric_to_f64!(vals),
+        &Array::UInt32Array(ref vals) => numeric_to_f64!(vals),
+        &Array::UInt16Array(ref vals) => numeric_to_f64!(vals),
+        &Array::UInt8Array(ref vals) => numeric_to_f64!(vals),
+        &Array::UsizeArray(ref vals) => numeric_to_f64!(vals),
+        &Array::Float64Array(ref vals) => numeric_to_f64!(vals),
+        &Array::Float32Array(ref vals) => numeric_to_f64!(vals),
+        _ => panic!("unable to convert {} to f64", values.dtype()),
+    }
+}
+
+/// Convert non-null values of numeric `Array` to `Vec<f64>`
+pub fn to_valid_f64_vec(values: &Array) -> Vec<f64> {
+    to_f64_vec(values).into_iter().filter(|x| !x.is_nan()).collect()
+}
+
+macro_rules! count_valid {
+    ($vals:ident) => {
+        $vals.iter_raw().filter(|x| !x.0).count()
+    }
+}
+
+/// Count non-null values
+pub fn array_count(values: &Array) -> usize {
+    match values {
+        &Array::Int64Array(ref vals) => count_valid!(vals),
+        &Array::Int32Array(ref vals) => count_valid!(vals),
+        &Array::Int16Array(ref vals) => count_valid!(vals),
+        &Array::Int8Array(ref vals) => count_valid!(vals),
+        &Array::IsizeArray(ref vals) => count_valid!(vals),
+        &Array::UInt64Array(ref vals) => count_valid!(vals),
+        &Array::UInt32Array(ref vals) => count_valid!(vals),
+        &Array::UInt16Array(ref vals) => count_valid!(vals),
+        &Array::UInt8Array(ref vals) => count_valid!(vals),
+        &Array::UsizeArray(ref vals) => count_valid!(vals),
+        &Array::Float64Array(ref vals) => count_valid!(vals),
+        &Array::Float32Array(ref vals) => count_valid!(vals),
+        &Array::BoolArray(ref vals) => count_valid!(vals),
+        &Array::StringArray(ref vals) => count_valid!(vals),
+    }
+}
+
+fn sum_scalar<T>(values: &Vec<T>) -> Scalar
+    where T: Clone + Summation<T>,
+          Scalar: From<T>
+{
+    match Aggregation::vec_checked_sum(values) {
+        Some(v) => Scalar::from(v),
+        None => Scalar::Null,
+    }
+}
+
+/// Sum non-null values of numeric `Array` keeping its dtype, null if all
+/// values are null or the sum overflows
+pub fn array_sum(values: &Array) -> Scalar {
+    if array_count(values) == 0 {
+        return Scalar::Null;
+    }
+    reduce_numeric_array!(values, sum_scalar)
+}
+
+fn prod_scalar<T>(values: &Vec<T>) -> Scalar
+    where T: Clone + Product<T>,
+          Scalar: From<T>
+{
+    match Aggregation::vec_checked_prod(values) {
+        Some(v) => Scalar::from(v),
+        None => Scalar::Null,
+    }
+}
+
+/// Product of non-null values of numeric `Array` keeping its dtype, null if
+/// the product overflows
+pub fn array_prod(values: &Array) -> Scalar {
+    reduce_numeric_array!(values, prod_scalar)
+}
+
+fn min_scalar<T>(values: &Vec<T>) -> Scalar
+    where T: Primitive,
+          Scalar: From<T>
+{
+    match Vectorized::min(values) {
+        Some(v) => Scalar::from(v),
+        None => Scalar::Null,
+    }
+}
+
+fn max_scalar<T>(values: &Vec<T>) -> Scalar
+    where T: Primitive,
+          Scalar: From<T>
+{
+    match Vectorized::max(values) {
+        Some(v) => Scalar::from(v),
+        None => Scalar::Null,
+    }
+}
+
+/// Minimum of non-null values of numeric `Array`, null if all values are null
+pub fn array_min(values: &Array) -> Scalar {
+    reduce_numeric_array!(values, min_scalar)
+}
+
+/// Maximum of non-null values of numeric `Array`, null if all values are null
+pub fn array_max(values: &Array) -> Scalar {
+    reduce_numeric_array!(values, max_scalar)
+}
+
+/// Convert `Scalar`s to `Array` of the dtype, `Scalar::Null` is regarded as null
+pub fn scalars_to_array(values: &[Scalar], dtype: &str) -> Array {
+    match dtype {
+        "i64" => Array::Int64Array(values.iter().map(|x| x.as_i64()).collect()),
+        "i32" => Array::Int32Array(values.iter().map(|x| x.as_i32()).collect()),
+        "i16" => Array::Int16Array(values.iter().map(|x| x.as_i16()).collect()),
+        "i8" => Array::Int8Array(values.iter().map(|x| x.as_i8()).collect()),
+        "isize" => Array::IsizeArray(values.iter().map(|x| x.as_isize()).collect()),
+        "u64" => Array::UInt64Array(values.iter().map(|x| x.as_u64()).collect()),
+        "u32" => Array::UInt32Array(values.iter().map(|x| x.as_u32()).collect()),
+        "u16" => Array::UInt16Array(values.iter().map(|x| x.as_u16()).collect()),
+        "u8" => Array::UInt8Array(values.iter().map(|x| x.as_u8()).collect()),
+        "usize" => Array::UsizeArray(values.iter().map(|x| x.as_usize()).collect()),
+        "f64" => Array::Float64Array(values.iter().map(|x| x.as_f64()).collect()),
+        "f32" => Array::Float32Array(values.iter().map(|x| x.as_f32()).collect()),
+        "bool" => Array::BoolArray(values.iter().map(|x| x.as_bool()).collect()),
+        "str" => Array::StringArray(values.iter().map(|x| x.as_str()).collect()),
+        _ => panic!("unknown dtype {}", dtype),
+    }
+}
+
+/// Create `NullVec` from values and null mask, omitting mask if nothing is null
+/// so that the result compares equal to `NullVec` created without mask
+pub fn with_mask<T: NullStorable>(values: Vec<T>, mask: Vec<bool>) -> NullVec<T> {
+    if mask.iter().any(|&x| x) {
+        NullVec::with_mask(values, Some(mask))
+    } else {
+        NullVec::new(values)
+    }
+}
+
+/// Extend `NullVec` to `len` with nulls
+pub fn pad_nullvec<T: Clone + NullStorable>(values: &NullVec<T>, len: usize) -> NullVec<T> {
+    let mut new_values: Vec<T> = Vec::with_capacity(len);
+    let mut mask: Vec<bool> = Vec::with_capacity(len);
+    for (n, v) in values.iter_raw() {
+        new_values.push(v.clone());
+        mask.push(n);
+    }
+    while new_values.len() < len {
+        new_values.push(T::default());
+        mask.push(true);
+    }
+    with_mask(new_values, mask)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use nullvec::prelude::{Array, NullVec, Scalar};
+    use super::{to_f64_vec, scalars_to_array, array_count, array_sum, array_prod, array_min,
+                array_max};
+
+    #[test]
+    fn test_to_f64_vec() {
+        let arr = Array::Int64Array(NullVec::with_mask(vec![1, 2, 3],
+                                                       Some(vec![false, true, false])));
+        let res = to_f64_vec(&arr);
+        assert_eq!(res[0], 1.);
+        assert!(res[1].is_nan());
+        assert_eq!(res[2], 3.);
+
+        let arr = Array::Float32Array(NullVec::new(vec![1.5f32, 2.5]));
+        assert_eq!(to_f64_vec(&arr), vec![1.5, 2.5]);
+    }
+
+    #[test]
+    fn test_scalars_to_array() {
+        let res = scalars_to_array(&[Scalar::Null, Scalar::i64(2)], "i64");
+        assert_eq!(res, Array::Int64Array(NullVec::with_mask(vec![0, 2],
+                                                             Some(vec![true, false]))));
+
+        let res = scalars_to_array(&[Scalar::i32(1), Scalar::i32(2)], "i32");
+        assert_eq!(res, Array::Int32Array(NullVec::new(vec![1, 2])));
+
+        let res = scalars_to_array(&[], "str");
+        assert_eq!(res, Array::StringArray(NullVec::new(vec![])));
+    }
+
+    #[test]
+    fn test_array_reduce_with_trailing_null() {
+        let arr = Array::Int64Array(NullVec::with_mask(vec![3, 1, 0],
+                                                       Some(vec![false, false, true])));
+        assert_eq!(array_count(&arr), 2);
+        assert_eq!(array_sum(&arr), Scalar::i64(4));
+        assert_eq!(array_min(&arr), Scalar::i64(1));
+        assert_eq!(array_max(&arr), Scalar::i64(3));
+
+        let arr = Array::UInt8Array(NullVec::new(vec![200, 100]));
+        assert_eq!(array_sum(&arr), Scalar::Null);
+        assert_eq!(array_prod(&arr), Scalar::Null);
+        let arr = Array::Int64Array(NullVec::with_mask(vec![3, 0, 4],
+                                                       Some(vec![false, true, false])));
+        assert_eq!(array_prod(&arr), Scalar::i64(12));
+
+        let arr = Array::Float64Array(NullVec::with_mask(vec![0., 0.], Some(vec![true, true])));
+        assert_eq!(array_count(&arr), 0);
+        assert_eq!(array_sum(&arr), Scalar::Null);
+        assert_eq!(array_min(&arr), Scalar::Null);
+        assert_eq!(array_max(&arr), Scalar::Null);
+    }
+}
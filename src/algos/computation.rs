@@ -1,8 +1,9 @@
 
 use std::cmp;
-use std::ops::{Add, Sub, Div};
+use std::f64;
+use std::ops::{Add, Sub, Mul, Div};
 
-use num::{Num, Zero, Float, ToPrimitive};
+use num::{Num, Zero, One, Float, ToPrimitive};
 
 //**********************************************
 //*Min, Max
@@ -66,16 +67,234 @@ define_int_stats!(usize);
 define_float_stats!(f64);
 define_float_stats!(f32);
 
+//**********************************************
+//*Summation
+//**********************************************
+
+pub trait Summation<A> {
+    /// Sum values keeping its type. Integers are accumulated in widened type,
+    /// and panics if the result overflows.
+    fn sum(values: &[A]) -> A;
+    /// Sum values keeping its type, `None` if integer sum overflows
+    fn checked_sum(values: &[A]) -> Option<A>;
+}
+
+macro_rules! define_int_summation {
+    ($t:ident, $wide:ident) => {
+        impl Summation<$t> for $t {
+            fn sum(values: &[$t]) -> $t {
+                match <$t as Summation<$t>>::checked_sum(values) {
+                    Some(v) => v,
+                    None => panic!("sum overflows {}", stringify!($t)),
+                }
+            }
+
+            fn checked_sum(values: &[$t]) -> Option<$t> {
+                let total: $wide = values.iter().fold(0, |a, &b| a + b as $wide);
+                if total > $t::max_value() as $wide || total < $t::min_value() as $wide {
+                    None
+                } else {
+                    Some(total as $t)
+                }
+            }
+        }
+    }
+}
+
+macro_rules! define_float_summation {
+    ($t:ident) => {
+        impl Summation<$t> for $t {
+            fn sum(values: &[$t]) -> $t {
+                kahan_sum(values.iter().map(|&x| x as f64)) as $t
+            }
+
+            fn checked_sum(values: &[$t]) -> Option<$t> {
+                Some(<$t as Summation<$t>>::sum(values))
+            }
+        }
+    }
+}
+
+define_int_summation!(i64, i128);
+define_int_summation!(i32, i128);
+define_int_summation!(i16, i128);
+define_int_summation!(i8, i128);
+define_int_summation!(isize, i128);
+define_int_summation!(u64, u128);
+define_int_summation!(u32, u128);
+define_int_summation!(u16, u128);
+define_int_summation!(u8, u128);
+define_int_summation!(usize, u128);
+define_float_summation!(f64);
+define_float_summation!(f32);
+
+//**********************************************
+//*Product
+//**********************************************
+
+pub trait Product<A> {
+    /// Multiply values keeping its type, `None` if integer product overflows
+    fn checked_prod(values: &[A]) -> Option<A>;
+}
+
+macro_rules! define_int_product {
+    ($t:ident) => {
+        impl Product<$t> for $t {
+            fn checked_prod(values: &[$t]) -> Option<$t> {
+                values.iter().fold(Some(1), |a, &b| a.and_then(|x| x.checked_mul(b)))
+            }
+        }
+    }
+}
+
+macro_rules! define_float_product {
+    ($t:ident) => {
+        impl Product<$t> for $t {
+            fn checked_prod(values: &[$t]) -> Option<$t> {
+                Some(values.iter().fold(1., |a, &b| a * b))
+            }
+        }
+    }
+}
+
+define_int_product!(i64);
+define_int_product!(i32);
+define_int_product!(i16);
+define_int_product!(i8);
+define_int_product!(isize);
+define_int_product!(u64);
+define_int_product!(u32);
+define_int_product!(u16);
+define_int_product!(u8);
+define_int_product!(usize);
+define_float_product!(f64);
+define_float_product!(f32);
+
+/// Compensated summation (Kahan-Babuska), which keeps lower order bits lost
+/// by naive summation
+pub fn kahan_sum<I: Iterator<Item = f64>>(values: I) -> f64 {
+    let mut sum = 0.;
+    let mut compensation = 0.;
+    for x in values {
+        let t = sum + x;
+        if sum.abs() >= x.abs() {
+            compensation += (sum - t) + x;
+        } else {
+            compensation += (x - t) + sum;
+        }
+        sum = t;
+    }
+    sum + compensation
+}
+
+//**********************************************
+//*Moments
+//**********************************************
+
+/// Single pass accumulator of count, mean, sum of squared deviations (M2),
+/// min and max using Welford's algorithm. Values are shifted by the first
+/// value to reduce cancellation error.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Moments {
+    count: usize,
+    shift: f64,
+    mean: f64,
+    m2: f64,
+    min: f64,
+    max: f64,
+}
+
+impl Moments {
+    pub fn new() -> Self {
+        Moments {
+            count: 0,
+            shift: 0.,
+            mean: 0.,
+            m2: 0.,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    /// Accumulate all values
+    pub fn from_values<T: ToPrimitive>(values: &[T]) -> Self {
+        let mut moments = Moments::new();
+        for x in values.iter() {
+            moments.push(ToPrimitive::to_f64(x).unwrap());
+        }
+        moments
+    }
+
+    /// Accumulate values skipping NaN
+    pub fn from_valid(values: &[f64]) -> Self {
+        let mut moments = Moments::new();
+        for &x in values.iter().filter(|x| !x.is_nan()) {
+            moments.push(x);
+        }
+        moments
+    }
+
+    pub fn push(&mut self, x: f64) {
+        if self.count == 0 && x.is_finite() {
+            self.shift = x;
+        }
+        self.count += 1;
+        let shifted = x - self.shift;
+        let delta = shifted - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (shifted - self.mean);
+        self.min = self.min.min(x);
+        self.max = self.max.max(x);
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Mean, NaN if empty
+    pub fn mean(&self) -> f64 {
+        if self.count == 0 { f64::NAN } else { self.mean + self.shift }
+    }
+
+    /// Variance with delta degrees of freedom, NaN if count <= ddof
+    pub fn var(&self, ddof: usize) -> f64 {
+        if self.count <= ddof {
+            f64::NAN
+        } else {
+            self.m2 / (self.count - ddof) as f64
+        }
+    }
+
+    pub fn std(&self, ddof: usize) -> f64 {
+        self.var(ddof).sqrt()
+    }
+
+    /// Minimum, NaN if empty
+    pub fn min(&self) -> f64 {
+        if self.count == 0 { f64::NAN } else { self.min }
+    }
+
+    /// Maximum, NaN if empty
+    pub fn max(&self) -> f64 {
+        if self.count == 0 { f64::NAN } else { self.max }
+    }
+}
+
 pub struct Aggregation;
 
 // Aggregation
 impl Aggregation {
     pub fn vec_sum<T>(values: &Vec<T>) -> T
-        where T: Clone + Zero + Add
+        where T: Clone + Summation<T>
     {
+        T::sum(values)
+    }
 
-        // ToDo: Use AsRef
-        values.iter().fold(T::zero(), |a, b| a + b.clone())
+    /// Sum keeping the type, `None` if integer sum overflows
+    pub fn vec_checked_sum<T>(values: &Vec<T>) -> Option<T>
+        where T: Clone + Summation<T>
+    {
+        T::checked_sum(values)
     }
 
     pub fn vec_count<T>(values: &Vec<T>) -> usize {
@@ -85,34 +304,22 @@ impl Aggregation {
     pub fn vec_mean<T>(values: &Vec<T>) -> f64
         where T: Clone + Zero + Add + ToPrimitive
     {
-
-        let sum: f64 = ToPrimitive::to_f64(&Aggregation::vec_sum(values)).unwrap();
+        // sum as f64 not to overflow integers
+        let sum = kahan_sum(values.iter().map(|x| ToPrimitive::to_f64(x).unwrap()));
         let count: f64 = Aggregation::vec_count(values) as f64;
         sum / count
     }
 
-    fn mean_sq<T>(values: &Vec<T>) -> f64
-        where T: Clone + Zero + Add + Sub + ToPrimitive
-    {
-        // use two pass algorithm, assuming data is not large
-        let mean = Aggregation::vec_mean(values);
-        values.iter()
-            .map(|x| ToPrimitive::to_f64(x).unwrap())
-            .fold(0., |a, b| a + (b - mean) * (b - mean))
-    }
-
     pub fn vec_var<T>(values: &Vec<T>) -> f64
         where T: Clone + Zero + Add + Sub + Div + ToPrimitive
     {
-
-        Aggregation::mean_sq(values) / (Aggregation::vec_count(values) as f64)
+        Moments::from_values(values).var(0)
     }
 
     pub fn vec_unbiased_var<T>(values: &Vec<T>) -> f64
         where T: Clone + Zero + Add + Sub + Div + ToPrimitive
     {
-
-        Aggregation::mean_sq(values) / ((Aggregation::vec_count(values) as f64) - 1.)
+        Moments::from_values(values).var(1)
     }
 
     pub fn vec_std<T>(values: &Vec<T>) -> f64
@@ -129,6 +336,87 @@ impl Aggregation {
         Aggregation::vec_unbiased_var(values).sqrt()
     }
 
+    pub fn vec_prod<T>(values: &Vec<T>) -> T
+        where T: Clone + One + Mul
+    {
+        values.iter().fold(T::one(), |a, b| a * b.clone())
+    }
+
+    /// Product keeping the type, `None` if integer product overflows
+    pub fn vec_checked_prod<T>(values: &Vec<T>) -> Option<T>
+        where T: Clone + Product<T>
+    {
+        T::checked_prod(values)
+    }
+
+    /// Return count and 2nd, 3rd and 4th central moments
+    fn central_moments<T>(values: &Vec<T>) -> (f64, f64, f64, f64)
+        where T: Clone + Zero + Add + Sub + ToPrimitive
+    {
+        let mean = Aggregation::vec_mean(values);
+        let (m2, m3, m4) = values.iter()
+            .map(|x| ToPrimitive::to_f64(x).unwrap() - mean)
+            .fold((0., 0., 0.), |(a2, a3, a4), d| {
+                let d2 = d * d;
+                (a2 + d2, a3 + d2 * d, a4 + d2 * d2)
+            });
+        let n = Aggregation::vec_count(values) as f64;
+        (n, m2 / n, m3 / n, m4 / n)
+    }
+
+    pub fn vec_skew<T>(values: &Vec<T>) -> f64
+        where T: Clone + Zero + Add + Sub + Div + ToPrimitive
+    {
+        let (_, m2, m3, _) = Aggregation::central_moments(values);
+        if m2 == 0. { 0. } else { m3 / m2.powf(1.5) }
+    }
+
+    pub fn vec_unbiased_skew<T>(values: &Vec<T>) -> f64
+        where T: Clone + Zero + Add + Sub + Div + ToPrimitive
+    {
+        let n = Aggregation::vec_count(values) as f64;
+        if n < 3. {
+            return f64::NAN;
+        }
+        Aggregation::vec_skew(values) * (n * (n - 1.)).sqrt() / (n - 2.)
+    }
+
+    /// Excess kurtosis, which is 0 for normal distribution
+    pub fn vec_kurt<T>(values: &Vec<T>) -> f64
+        where T: Clone + Zero + Add + Sub + Div + ToPrimitive
+    {
+        let (_, m2, _, m4) = Aggregation::central_moments(values);
+        if m2 == 0. { 0. } else { m4 / (m2 * m2) - 3. }
+    }
+
+    pub fn vec_unbiased_kurt<T>(values: &Vec<T>) -> f64
+        where T: Clone + Zero + Add + Sub + Div + ToPrimitive
+    {
+        let n = Aggregation::vec_count(values) as f64;
+        if n < 4. {
+            return f64::NAN;
+        }
+        let (_, m2, _, m4) = Aggregation::central_moments(values);
+        if m2 == 0. {
+            return 0.;
+        }
+        let g2 = m4 / (m2 * m2) - 3.;
+        ((n + 1.) * g2 + 6.) * (n - 1.) / ((n - 2.) * (n - 3.))
+    }
+
+    /// Standard error of the mean
+    pub fn vec_sem<T>(values: &Vec<T>) -> f64
+        where T: Clone + Zero + Add + Sub + Div + ToPrimitive
+    {
+        Aggregation::vec_std(values) / (Aggregation::vec_count(values) as f64).sqrt()
+    }
+
+    pub fn vec_unbiased_sem<T>(values: &Vec<T>) -> f64
+        where T: Clone + Zero + Add + Sub + Div + ToPrimitive
+    {
+        Aggregation::vec_unbiased_std(values) / (Aggregation::vec_count(values) as f64).sqrt()
+    }
+
     pub fn vec_min<T>(values: &Vec<T>) -> T
         where T: Clone + NanMinMax<T>
     {
@@ -148,7 +436,8 @@ impl Aggregation {
 #[cfg(test)]
 mod tests {
 
-    use super::Aggregation;
+    use std::f64;
+    use super::{Aggregation, Moments, kahan_sum};
 
     #[test]
     fn test_vec_sum_f64() {
@@ -206,6 +495,108 @@ mod tests {
         assert_eq!(Aggregation::vec_unbiased_std(&values), 1.2247448713915889);
     }
 
+    #[test]
+    fn test_vec_sum_int_overflow_intermediate() {
+        // intermediate sum exceeds i8, but the result doesn't
+        let values: Vec<i8> = vec![100, 100, -100, -90];
+        assert_eq!(Aggregation::vec_sum(&values), 10);
+
+        let values: Vec<i64> = vec![i64::max_value(), i64::max_value()];
+        assert_eq!(Aggregation::vec_mean(&values), i64::max_value() as f64);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vec_sum_int_overflow() {
+        let values: Vec<u8> = vec![200, 100];
+        Aggregation::vec_sum(&values);
+    }
+
+    #[test]
+    fn test_vec_checked_sum() {
+        let values: Vec<i8> = vec![100, 100, -100, -90];
+        assert_eq!(Aggregation::vec_checked_sum(&values), Some(10));
+
+        let values: Vec<u8> = vec![200, 100];
+        assert_eq!(Aggregation::vec_checked_sum(&values), None);
+
+        let values: Vec<i64> = vec![i64::min_value(), -1];
+        assert_eq!(Aggregation::vec_checked_sum(&values), None);
+    }
+
+    #[test]
+    fn test_kahan_sum() {
+        let values: Vec<f64> = vec![1., 1e100, 1., -1e100];
+        assert_eq!(kahan_sum(values.into_iter()), 2.);
+
+        let values: Vec<f64> = vec![0.1; 10];
+        assert_eq!(Aggregation::vec_sum(&values), 1.);
+        assert_eq!(values.iter().fold(0., |a, b| a + b), 0.9999999999999999);
+    }
+
+    #[test]
+    fn test_moments() {
+        let m = Moments::from_valid(&vec![3., f64::NAN, 1., 2., 6.]);
+        assert_eq!(m.count(), 4);
+        assert_eq!(m.mean(), 3.);
+        assert_eq!(m.var(0), 3.5);
+        assert_eq!(m.var(1), 14. / 3.);
+        assert_eq!(m.min(), 1.);
+        assert_eq!(m.max(), 6.);
+
+        let m = Moments::from_valid(&vec![f64::NAN]);
+        assert_eq!(m.count(), 0);
+        assert!(m.mean().is_nan());
+        assert!(m.var(0).is_nan());
+        assert!(m.min().is_nan());
+    }
+
+    #[test]
+    fn test_vec_var_stable() {
+        // naive sum of squares loses all precision with large offset
+        let values: Vec<f64> = vec![1e9 + 4., 1e9 + 7., 1e9 + 13., 1e9 + 16.];
+        assert_eq!(Aggregation::vec_unbiased_var(&values), 30.);
+    }
+
+    #[test]
+    fn test_vec_prod() {
+        let values: Vec<i64> = vec![1, 2, 3, 4];
+        assert_eq!(Aggregation::vec_prod(&values), 24);
+        let values: Vec<f64> = vec![];
+        assert_eq!(Aggregation::vec_prod(&values), 1.);
+
+        let values: Vec<u8> = vec![16, 16];
+        assert_eq!(Aggregation::vec_checked_prod(&values), None);
+        let values: Vec<i8> = vec![-8, 16];
+        assert_eq!(Aggregation::vec_checked_prod(&values), Some(-128));
+    }
+
+    #[test]
+    fn test_vec_skew_kurt() {
+        let values: Vec<i64> = vec![1, 2, 3, 4, 10];
+        assert!((Aggregation::vec_skew(&values) - 1.1384199576606167).abs() < 1e-10);
+        assert!((Aggregation::vec_unbiased_skew(&values) - 1.6970562748477143).abs() < 1e-10);
+        assert!((Aggregation::vec_kurt(&values) - -0.21199999999999974).abs() < 1e-10);
+        assert!((Aggregation::vec_unbiased_kurt(&values) - 3.152000000000001).abs() < 1e-10);
+
+        let values: Vec<i64> = vec![2, 2, 2, 2];
+        assert_eq!(Aggregation::vec_skew(&values), 0.);
+        assert_eq!(Aggregation::vec_kurt(&values), 0.);
+        assert_eq!(Aggregation::vec_unbiased_skew(&values), 0.);
+        assert_eq!(Aggregation::vec_unbiased_kurt(&values), 0.);
+
+        let values: Vec<i64> = vec![1, 2];
+        assert!(Aggregation::vec_unbiased_skew(&values).is_nan());
+        assert!(Aggregation::vec_unbiased_kurt(&values).is_nan());
+    }
+
+    #[test]
+    fn test_vec_sem() {
+        let values: Vec<i64> = vec![1, 2, 3, 4, 5];
+        assert!((Aggregation::vec_sem(&values) - (2f64 / 5.).sqrt()).abs() < 1e-10);
+        assert!((Aggregation::vec_unbiased_sem(&values) - (2.5f64 / 5.).sqrt()).abs() < 1e-10);
+    }
+
     #[test]
     fn test_scalar_minmax() {
         use super::NanMinMax;
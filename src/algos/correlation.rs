@@ -0,0 +1,138 @@
+use std::f64;
+
+use algos::computation::Aggregation;
+use algos::rank::{rank, RankMethod, NaOption};
+
+/// Correlation coefficient to compute
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CorrMethod {
+    /// Standard correlation coefficient
+    Pearson,
+    /// Pearson correlation of ranks
+    Spearman,
+    /// Kendall's tau-b
+    Kendall,
+}
+
+/// Pairs of values where both are not NaN
+fn complete_pairs(x: &[f64], y: &[f64]) -> (Vec<f64>, Vec<f64>) {
+    assert!(x.len() == y.len(), "Length mismatch!");
+    x.iter()
+        .zip(y.iter())
+        .filter(|&(a, b)| !a.is_nan() && !b.is_nan())
+        .map(|(a, b)| (*a, *b))
+        .unzip()
+}
+
+/// Return sum of cross deviations and squared deviations of x and y
+fn cross_moments(x: &Vec<f64>, y: &Vec<f64>) -> (f64, f64, f64) {
+    let xmean = Aggregation::vec_mean(x);
+    let ymean = Aggregation::vec_mean(y);
+    x.iter().zip(y.iter()).fold((0., 0., 0.), |(xy, xx, yy), (a, b)| {
+        let dx = a - xmean;
+        let dy = b - ymean;
+        (xy + dx * dy, xx + dx * dx, yy + dy * dy)
+    })
+}
+
+/// Covariance using pairs where both are not NaN
+pub fn cov(x: &[f64], y: &[f64], ddof: usize) -> f64 {
+    let (x, y) = complete_pairs(x, y);
+    if x.len() <= ddof {
+        return f64::NAN;
+    }
+    let (xy, _, _) = cross_moments(&x, &y);
+    xy / (x.len() - ddof) as f64
+}
+
+/// Correlation using pairs where both are not NaN
+pub fn corr(x: &[f64], y: &[f64], method: CorrMethod) -> f64 {
+    let (x, y) = complete_pairs(x, y);
+    if x.len() < 2 {
+        return f64::NAN;
+    }
+    match method {
+        CorrMethod::Pearson => pearson(&x, &y),
+        CorrMethod::Spearman => {
+            let nulls = vec![false; x.len()];
+            let xr = rank(&x, &nulls, RankMethod::Average, true, NaOption::Keep);
+            let yr = rank(&y, &nulls, RankMethod::Average, true, NaOption::Keep);
+            pearson(&xr, &yr)
+        }
+        CorrMethod::Kendall => kendall(&x, &y),
+    }
+}
+
+fn pearson(x: &Vec<f64>, y: &Vec<f64>) -> f64 {
+    let (xy, xx, yy) = cross_moments(x, y);
+    xy / (xx * yy).sqrt()
+}
+
+fn kendall(x: &[f64], y: &[f64]) -> f64 {
+    let n = x.len();
+    let mut concordant: i64 = 0;
+    let mut discordant: i64 = 0;
+    let mut xties: i64 = 0;
+    let mut yties: i64 = 0;
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let dx = x[i] - x[j];
+            let dy = y[i] - y[j];
+            if dx == 0. {
+                xties += 1;
+            }
+            if dy == 0. {
+                yties += 1;
+            }
+            if dx * dy > 0. {
+                concordant += 1;
+            } else if dx * dy < 0. {
+                discordant += 1;
+            }
+        }
+    }
+    let total = (n * (n - 1) / 2) as i64;
+    let denom = (((total - xties) * (total - yties)) as f64).sqrt();
+    (concordant - discordant) as f64 / denom
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::f64;
+    use super::{cov, corr, CorrMethod};
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-10, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn test_cov() {
+        let x = vec![1., 2., 3., 4.];
+        let y = vec![2., 4., 5., 9.];
+        assert_close(cov(&x, &y, 1), 11. / 3.);
+        assert_close(cov(&x, &y, 0), 2.75);
+        assert!(cov(&x[..1], &y[..1], 1).is_nan());
+    }
+
+    #[test]
+    fn test_corr() {
+        let x = vec![1., 2., 3., 4., 5.];
+        let y = vec![5., 6., 7., 8., 7.];
+        assert_close(corr(&x, &y, CorrMethod::Pearson), 0.8320502943378437);
+        assert_close(corr(&x, &y, CorrMethod::Spearman), 0.8207826816681233);
+        assert_close(corr(&x, &y, CorrMethod::Kendall), 0.7378647873726218);
+    }
+
+    #[test]
+    fn test_corr_pairwise_complete() {
+        let x = vec![1., f64::NAN, 3., 4., 5.];
+        let y = vec![2., 4., 6., f64::NAN, 10.];
+        assert_close(corr(&x, &y, CorrMethod::Pearson), 1.);
+        assert_close(cov(&x, &y, 1), 8.);
+
+        let x = vec![1., f64::NAN];
+        let y = vec![f64::NAN, 2.];
+        assert!(corr(&x, &y, CorrMethod::Pearson).is_nan());
+    }
+}
@@ -2,6 +2,8 @@ use std::borrow::{Borrow, Cow};
 use std::collections::{BTreeMap, HashMap};
 use std::hash::Hash;
 
+use nullvec::prelude::{Array, NullVec};
+
 pub struct Counter<'a, T>
     where T: 'a + Clone + Hash + Eq
 {
@@ -51,10 +53,148 @@ impl<'a, T> Counter<'a, T>
     }
 }
 
+impl<'a, T> Counter<'a, T>
+    where T: Clone + Hash + Eq + Ord
+{
+    /// Return the most frequent values in ascending order
+    pub fn mode(&self) -> Vec<T> {
+        let max = match self.counts.values().max() {
+            Some(max) => *max,
+            None => return vec![],
+        };
+        let mut modes: Vec<T> = self.counts
+            .iter()
+            .filter(|&(_, c)| *c == max)
+            .map(|(k, _)| k.clone().into_owned())
+            .collect();
+        modes.sort();
+        modes
+    }
+}
+
+/// Float is not hashable, count its bit representation instead.
+/// Positive and negative zero are regarded as the same.
+fn float_keys<T: Into<f64> + Copy>(values: &[T]) -> Vec<u64> {
+    values.iter()
+        .map(|&x| {
+            let x: f64 = x.into();
+            if x == 0. { 0 } else { x.to_bits() }
+        })
+        .collect()
+}
+
+fn float_mode<T: Into<f64> + Copy>(values: &[T]) -> Vec<f64> {
+    let keys = float_keys(values);
+    let mut modes: Vec<f64> = Counter::new(&keys)
+        .mode()
+        .into_iter()
+        .map(f64::from_bits)
+        .collect();
+    modes.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    modes
+}
+
+macro_rules! hashable_mode {
+    ($vals:expr) => {{
+        let values = $vals.not_null_values();
+        let modes = Counter::new(&values).mode();
+        NullVec::new(modes)
+    }}
+}
+
+/// Return the most frequent non-null values of `Array` in ascending order,
+/// keeping its dtype
+pub fn array_mode(values: &Array) -> Array {
+    match values {
+        &Array::Int64Array(ref vals) => Array::Int64Array(hashable_mode!(vals)),
+        &Array::Int32Array(ref vals) => Array::Int32Array(hashable_mode!(vals)),
+        &Array::Int16Array(ref vals) => Array::Int16Array(hashable_mode!(vals)),
+        &Array::Int8Array(ref vals) => Array::Int8Array(hashable_mode!(vals)),
+        &Array::IsizeArray(ref vals) => Array::IsizeArray(hashable_mode!(vals)),
+        &Array::UInt64Array(ref vals) => Array::UInt64Array(hashable_mode!(vals)),
+        &Array::UInt32Array(ref vals) => Array::UInt32Array(hashable_mode!(vals)),
+        &Array::UInt16Array(ref vals) => Array::UInt16Array(hashable_mode!(vals)),
+        &Array::UInt8Array(ref vals) => Array::UInt8Array(hashable_mode!(vals)),
+        &Array::UsizeArray(ref vals) => Array::UsizeArray(hashable_mode!(vals)),
+        &Array::Float64Array(ref vals) => {
+            Array::Float64Array(NullVec::new(float_mode(&vals.not_null_values())))
+        }
+        &Array::Float32Array(ref vals) => {
+            let modes: Vec<f32> = float_mode(&vals.not_null_values())
+                .into_iter()
+                .map(|x| x as f32)
+                .collect();
+            Array::Float32Array(NullVec::new(modes))
+        }
+        &Array::BoolArray(ref vals) => Array::BoolArray(hashable_mode!(vals)),
+        &Array::StringArray(ref vals) => Array::StringArray(hashable_mode!(vals)),
+    }
+}
+
+macro_rules! hashable_nunique {
+    ($vals:expr) => {{
+        let values = $vals.not_null_values();
+        Counter::new(&values).len()
+    }}
+}
+
+/// Return the number of distinct non-null values of `Array`
+pub fn array_nunique(values: &Array) -> usize {
+    match values {
+        &Array::Int64Array(ref vals) => hashable_nunique!(vals),
+        &Array::Int32Array(ref vals) => hashable_nunique!(vals),
+        &Array::Int16Array(ref vals) => hashable_nunique!(vals),
+        &Array::Int8Array(ref vals) => hashable_nunique!(vals),
+        &Array::IsizeArray(ref vals) => hashable_nunique!(vals),
+        &Array::UInt64Array(ref vals) => hashable_nunique!(vals),
+        &Array::UInt32Array(ref vals) => hashable_nunique!(vals),
+        &Array::UInt16Array(ref vals) => hashable_nunique!(vals),
+        &Array::UInt8Array(ref vals) => hashable_nunique!(vals),
+        &Array::UsizeArray(ref vals) => hashable_nunique!(vals),
+        &Array::Float64Array(ref vals) => {
+            let keys = float_keys(&vals.not_null_values());
+            Counter::new(&keys).len()
+        }
+        &Array::Float32Array(ref vals) => {
+            let keys = float_keys(&vals.not_null_values());
+            Counter::new(&keys).len()
+        }
+        &Array::BoolArray(ref vals) => hashable_nunique!(vals),
+        &Array::StringArray(ref vals) => hashable_nunique!(vals),
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
-    use super::Counter;
+    use std::f64;
+
+    use nullvec::prelude::{Array, NullVec};
+    use super::{Counter, array_mode, array_nunique};
+
+    #[test]
+    fn test_counter_mode() {
+        let vals: Vec<i64> = vec![3, 1, 3, 2, 1];
+        let c: Counter<i64> = Counter::new(&vals);
+        assert_eq!(c.mode(), vec![1, 3]);
+
+        let vals: Vec<i64> = vec![];
+        let c: Counter<i64> = Counter::new(&vals);
+        assert_eq!(c.mode(), vec![]);
+    }
+
+    #[test]
+    fn test_array_mode() {
+        let arr = Array::Int64Array(NullVec::with_mask(vec![2, 0, 0, 2, 1],
+                                                       Some(vec![false, true, true, false,
+                                                                 false])));
+        assert_eq!(array_mode(&arr), Array::Int64Array(NullVec::new(vec![2])));
+        assert_eq!(array_nunique(&arr), 2);
+
+        let arr = Array::Float64Array(NullVec::new(vec![1.5, f64::NAN, -0., 1.5, 0., f64::NAN]));
+        assert_eq!(array_mode(&arr), Array::Float64Array(NullVec::new(vec![0., 1.5])));
+        assert_eq!(array_nunique(&arr), 2);
+    }
 
     #[test]
     fn test_counter_empty() {
@@ -0,0 +1,135 @@
+use std::ops::{Add, Mul};
+
+use nullvec::prelude::NullVec;
+use nullvec::prelude::dev::NullStorable;
+
+use algos::array::with_mask;
+
+/// Cumulative operation applied to `NullVec`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CumOp {
+    Sum,
+    Prod,
+    Min,
+    Max,
+}
+
+/// Cumulate values of each group in the order of its locations, and put
+/// results back to the original locations. Values are pairs of null flag
+/// and value.
+///
+/// If `skipna` is true, nulls are kept as they are and skipped. Otherwise
+/// all values after the first null are null. Null results hold the null
+/// value which caused them.
+pub fn cumulate<T, F>(values: &[(bool, T)],
+                      groups: &[&Vec<usize>],
+                      skipna: bool,
+                      func: F)
+                      -> Vec<(bool, T)>
+    where T: Clone,
+          F: Fn(&T, &T) -> T
+{
+    let mut result: Vec<Option<(bool, T)>> = vec![None; values.len()];
+    for locs in groups.iter() {
+        let mut acc: Option<T> = None;
+        let mut first_null: Option<T> = None;
+        for &i in locs.iter() {
+            let (null, ref v) = values[i];
+            if null {
+                if first_null.is_none() {
+                    first_null = Some(v.clone());
+                }
+                result[i] = Some((true, v.clone()));
+            } else if !skipna && first_null.is_some() {
+                result[i] = Some((true, first_null.clone().unwrap()));
+            } else {
+                let current = match acc {
+                    Some(ref a) => func(a, v),
+                    None => v.clone(),
+                };
+                result[i] = Some((false, current.clone()));
+                acc = Some(current);
+            }
+        }
+    }
+    result.into_iter().map(|x| x.expect("location is not covered by any group")).collect()
+}
+
+pub fn cumulate_nullvec<T>(values: &NullVec<T>,
+                           groups: &[&Vec<usize>],
+                           skipna: bool,
+                           op: CumOp)
+                           -> NullVec<T>
+    where T: Clone + NullStorable + Add<Output = T> + Mul<Output = T> + PartialOrd
+{
+    let raw: Vec<(bool, T)> = values.iter_raw().map(|(n, v)| (n, v.clone())).collect();
+    let result = match op {
+        CumOp::Sum => cumulate(&raw, groups, skipna, |a, b| a.clone() + b.clone()),
+        CumOp::Prod => cumulate(&raw, groups, skipna, |a, b| a.clone() * b.clone()),
+        CumOp::Min => cumulate(&raw, groups, skipna, |a, b| if b < a { b.clone() } else { a.clone() }),
+        CumOp::Max => cumulate(&raw, groups, skipna, |a, b| if b > a { b.clone() } else { a.clone() }),
+    };
+    let (mask, new_values): (Vec<bool>, Vec<T>) = result.into_iter().unzip();
+    with_mask(new_values, mask)
+}
+
+/// Position of each location within its group
+pub fn cumcount(len: usize, groups: &[&Vec<usize>]) -> Vec<usize> {
+    let mut result: Vec<usize> = vec![0; len];
+    for locs in groups.iter() {
+        for (count, &i) in locs.iter().enumerate() {
+            result[i] = count;
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+
+    use nullvec::prelude::NullVec;
+    use super::{cumulate, cumulate_nullvec, cumcount, CumOp};
+
+    #[test]
+    fn test_cumulate() {
+        let values = vec![(false, 1), (false, 2), (false, 3), (false, 4)];
+        let all = vec![0, 1, 2, 3];
+        let res = cumulate(&values, &[&all], true, |a, b| a + b);
+        assert_eq!(res, vec![(false, 1), (false, 3), (false, 6), (false, 10)]);
+
+        let g1 = vec![0, 2];
+        let g2 = vec![1, 3];
+        let res = cumulate(&values, &[&g1, &g2], true, |a, b| a + b);
+        assert_eq!(res, vec![(false, 1), (false, 2), (false, 4), (false, 6)]);
+    }
+
+    #[test]
+    fn test_cumulate_null() {
+        let values = vec![(false, 1), (true, 0), (false, 3), (false, 4)];
+        let all = vec![0, 1, 2, 3];
+        let res = cumulate(&values, &[&all], true, |a, b| a + b);
+        assert_eq!(res, vec![(false, 1), (true, 0), (false, 4), (false, 8)]);
+
+        let res = cumulate(&values, &[&all], false, |a, b| a + b);
+        assert_eq!(res, vec![(false, 1), (true, 0), (true, 0), (true, 0)]);
+    }
+
+    #[test]
+    fn test_cumulate_nullvec() {
+        let nv = NullVec::with_mask(vec![3, 1, 2, 5], Some(vec![false, false, true, false]));
+        let all = vec![0, 1, 2, 3];
+        let res = cumulate_nullvec(&nv, &[&all], true, CumOp::Min);
+        assert_eq!(res, NullVec::with_mask(vec![3, 1, 2, 1],
+                                           Some(vec![false, false, true, false])));
+        let res = cumulate_nullvec(&nv, &[&all], true, CumOp::Prod);
+        assert_eq!(res, NullVec::with_mask(vec![3, 3, 2, 15],
+                                           Some(vec![false, false, true, false])));
+    }
+
+    #[test]
+    fn test_cumcount() {
+        let g1 = vec![0, 2, 3];
+        let g2 = vec![1, 4];
+        assert_eq!(cumcount(5, &[&g1, &g2]), vec![0, 0, 1, 2, 1]);
+    }
+}
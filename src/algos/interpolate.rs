@@ -0,0 +1,160 @@
+use std::f64;
+
+/// Interpolation method
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Interpolation {
+    /// Linear interpolation between surrounding valid values
+    Linear,
+    /// Take the nearest valid value, the previous one on ties
+    Nearest,
+}
+
+/// Direction in which consecutive nulls are filled
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LimitDirection {
+    /// Fill nulls after a valid value, including trailing nulls
+    Forward,
+    /// Fill nulls before a valid value, including leading nulls
+    Backward,
+    Both,
+}
+
+/// Interpolate NaN using x coordinates of each value
+///
+/// Nulls before the first (after the last) valid value are filled with
+/// that value when direction allows. `limit` is the maximum number of
+/// consecutive nulls to fill, counted from the direction's side.
+pub fn interpolate(values: &[f64],
+                   xs: &[f64],
+                   method: Interpolation,
+                   limit: Option<usize>,
+                   direction: LimitDirection)
+                   -> Vec<f64> {
+    assert!(values.len() == xs.len(), "Length mismatch!");
+    let n = values.len();
+    let mut result: Vec<f64> = values.to_vec();
+
+    let mut i = 0;
+    while i < n {
+        if !values[i].is_nan() {
+            i += 1;
+            continue;
+        }
+        // gap of nulls from start to end (exclusive)
+        let start = i;
+        while i < n && values[i].is_nan() {
+            i += 1;
+        }
+        let end = i;
+        let prev = if start > 0 { Some(start - 1) } else { None };
+        let next = if end < n { Some(end) } else { None };
+
+        for k in start..end {
+            let forward = prev.is_some() && limit.map_or(true, |l| k - start < l);
+            let backward = next.is_some() && limit.map_or(true, |l| end - 1 - k < l);
+            let allowed = match direction {
+                LimitDirection::Forward => forward,
+                LimitDirection::Backward => backward,
+                LimitDirection::Both => forward || backward,
+            };
+            if !allowed {
+                continue;
+            }
+            result[k] = match (prev, next) {
+                (Some(p), Some(q)) => {
+                    match method {
+                        Interpolation::Linear => {
+                            let ratio = (xs[k] - xs[p]) / (xs[q] - xs[p]);
+                            values[p] + (values[q] - values[p]) * ratio
+                        }
+                        Interpolation::Nearest => {
+                            if xs[q] - xs[k] < xs[k] - xs[p] { values[q] } else { values[p] }
+                        }
+                    }
+                }
+                (Some(p), None) => values[p],
+                (None, Some(q)) => values[q],
+                (None, None) => f64::NAN,
+            };
+        }
+    }
+    result
+}
+
+/// x coordinates of each location
+pub fn positions(len: usize) -> Vec<f64> {
+    (0..len).map(|x| x as f64).collect()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::f64;
+    use super::{interpolate, positions, Interpolation, LimitDirection};
+
+    fn assert_vec_eq(res: Vec<f64>, exp: Vec<f64>) {
+        assert_eq!(res.len(), exp.len());
+        for (r, e) in res.iter().zip(exp.iter()) {
+            if e.is_nan() {
+                assert!(r.is_nan(), "{:?} != {:?}", res, exp);
+            } else {
+                assert!((r - e).abs() < 1e-10, "{:?} != {:?}", res, exp);
+            }
+        }
+    }
+
+    #[test]
+    fn test_linear() {
+        let nan = f64::NAN;
+        let values = vec![nan, 1., nan, nan, 4., nan];
+        let xs = positions(6);
+        let res = interpolate(&values, &xs, Interpolation::Linear, None, LimitDirection::Forward);
+        assert_vec_eq(res, vec![nan, 1., 2., 3., 4., 4.]);
+
+        let res = interpolate(&values, &xs, Interpolation::Linear, None,
+                              LimitDirection::Backward);
+        assert_vec_eq(res, vec![1., 1., 2., 3., 4., nan]);
+
+        let res = interpolate(&values, &xs, Interpolation::Linear, None, LimitDirection::Both);
+        assert_vec_eq(res, vec![1., 1., 2., 3., 4., 4.]);
+    }
+
+    #[test]
+    fn test_limit() {
+        let nan = f64::NAN;
+        let values = vec![0., nan, nan, nan, 4.];
+        let xs = positions(5);
+        let res = interpolate(&values, &xs, Interpolation::Linear, Some(1),
+                              LimitDirection::Forward);
+        assert_vec_eq(res, vec![0., 1., nan, nan, 4.]);
+
+        let res = interpolate(&values, &xs, Interpolation::Linear, Some(1),
+                              LimitDirection::Backward);
+        assert_vec_eq(res, vec![0., nan, nan, 3., 4.]);
+
+        let res = interpolate(&values, &xs, Interpolation::Linear, Some(1),
+                              LimitDirection::Both);
+        assert_vec_eq(res, vec![0., 1., nan, 3., 4.]);
+    }
+
+    #[test]
+    fn test_xs() {
+        let nan = f64::NAN;
+        let values = vec![0., nan, 10.];
+        let xs = vec![0., 9., 10.];
+        let res = interpolate(&values, &xs, Interpolation::Linear, None, LimitDirection::Forward);
+        assert_vec_eq(res, vec![0., 9., 10.]);
+        let res = interpolate(&values, &xs, Interpolation::Nearest, None,
+                              LimitDirection::Forward);
+        assert_vec_eq(res, vec![0., 10., 10.]);
+    }
+
+    #[test]
+    fn test_nearest() {
+        let nan = f64::NAN;
+        let values = vec![1., nan, nan, nan, 5.];
+        let res = interpolate(&values, &positions(5), Interpolation::Nearest, None,
+                              LimitDirection::Forward);
+        assert_vec_eq(res, vec![1., 1., 1., 5., 5.]);
+    }
+}
@@ -0,0 +1,351 @@
+//! Elementwise kernels over contiguous slices.
+//!
+//! Unlike `Elemwise` which calls a closure per element through nullvec,
+//! these are plain loops over slices of known length without branches, so
+//! that the compiler can auto-vectorize them once monomorphized for numeric
+//! primitives. Arithmetic kernels are generic over the operator trait and
+//! back `Series` ops. Comparison, reduction and `masked_*` kernels are
+//! standalone kernels for numeric primitives, among which only `min` / `max`
+//! back `DataFrame` aggregation. Reductions keep `LANES` independent
+//! accumulators to break the dependency chain between iterations, thus float
+//! sums may differ from sequential summation in the last bits.
+
+use std::ops::{Add, Sub, Mul, Div, Rem, BitAnd, BitOr, BitXor};
+
+use num::{Zero, One};
+use nullvec::prelude::NullVec;
+use nullvec::prelude::dev::NullStorable;
+
+/// Number of independent accumulators used in reductions
+const LANES: usize = 8;
+
+/// Numeric primitives supported by `Vectorized`
+pub trait Primitive
+    : Copy + PartialOrd + Zero + One + Add<Output = Self> + Sub<Output = Self>
+    + Mul<Output = Self> + Div<Output = Self> + NullStorable {
+}
+
+macro_rules! define_primitive {
+    ($($t:ident),*) => {
+        $(impl Primitive for $t {})*
+    }
+}
+
+define_primitive!(i64, i32, i16, i8, isize, u64, u32, u16, u8, usize, f64, f32);
+
+/// Apply `func` to each pair of `lhs` and `rhs`
+#[inline(always)]
+fn zip_map<T, R, F>(lhs: &[T], rhs: &[T], func: F) -> Vec<R>
+    where F: Fn(&T, &T) -> R
+{
+    assert!(lhs.len() == rhs.len(),
+            "lhs and rhs must be the same length");
+    // re-slice so that bounds checks can be elided
+    let n = lhs.len();
+    let (lhs, rhs) = (&lhs[..n], &rhs[..n]);
+    lhs.iter().zip(rhs.iter()).map(|(x, y)| func(x, y)).collect()
+}
+
+/// Apply `func` to each element of `lhs` and scalar `rhs`
+#[inline(always)]
+fn broadcast_map<T, R, F>(lhs: &[T], rhs: &T, func: F) -> Vec<R>
+    where F: Fn(&T, &T) -> R
+{
+    lhs.iter().map(|x| func(x, rhs)).collect()
+}
+
+/// Fold `values` using `LANES` accumulators initialized by `init`, then
+/// combine accumulators with the same `func`
+#[inline(always)]
+fn lane_fold<T, F>(values: &[T], init: T, func: F) -> T
+    where T: Copy,
+          F: Fn(T, T) -> T
+{
+    let mut acc = [init; LANES];
+    let mut chunks = values.chunks_exact(LANES);
+    for chunk in &mut chunks {
+        for i in 0..LANES {
+            acc[i] = func(acc[i], chunk[i]);
+        }
+    }
+    for (i, &v) in chunks.remainder().iter().enumerate() {
+        acc[i] = func(acc[i], v);
+    }
+    acc.iter().fold(init, |a, &b| func(a, b))
+}
+
+/// Same as `lane_fold`, but values at null locations are replaced with
+/// `init` which must be the identity of `func`
+#[inline(always)]
+fn masked_lane_fold<T, F>(values: &[T], mask: &[bool], init: T, func: F) -> T
+    where T: Copy,
+          F: Fn(T, T) -> T
+{
+    assert!(values.len() == mask.len(),
+            "values and mask must be the same length");
+    let mut acc = [init; LANES];
+    let mut chunks = values.chunks_exact(LANES);
+    let mut masks = mask.chunks_exact(LANES);
+    for (chunk, m) in (&mut chunks).zip(&mut masks) {
+        for i in 0..LANES {
+            acc[i] = func(acc[i], if m[i] { init } else { chunk[i] });
+        }
+    }
+    for (i, (&v, &m)) in chunks.remainder().iter().zip(masks.remainder()).enumerate() {
+        acc[i] = func(acc[i], if m { init } else { v });
+    }
+    acc.iter().fold(init, |a, &b| func(a, b))
+}
+
+#[inline(always)]
+fn nan_min<T: PartialOrd>(acc: T, x: T) -> T {
+    // NaN never compares less, thus is skipped
+    if x < acc { x } else { acc }
+}
+
+#[inline(always)]
+fn nan_max<T: PartialOrd>(acc: T, x: T) -> T {
+    if x > acc { x } else { acc }
+}
+
+/// Apply `func` to each pair of `lhs` and `rhs`, replacing `rhs` at null
+/// locations with `fill` so that they never raise (e.g. division by zero)
+#[inline(always)]
+fn masked_zip_map<T, F>(lhs: &[T], rhs: &[T], mask: &[bool], fill: T, func: F) -> Vec<T>
+    where T: Copy,
+          F: Fn(T, T) -> T
+{
+    assert!(lhs.len() == rhs.len() && lhs.len() == mask.len(),
+            "lhs, rhs and mask must be the same length");
+    let n = lhs.len();
+    let (lhs, rhs, mask) = (&lhs[..n], &rhs[..n], &mask[..n]);
+    lhs.iter()
+        .zip(rhs.iter().zip(mask.iter()))
+        .map(|(&x, (&y, &m))| func(x, if m { fill } else { y }))
+        .collect()
+}
+
+/// Seed for min / max reductions, the first value which is neither null
+/// nor NaN
+fn first_valid<T: Primitive>(values: &[T], mask: Option<&[bool]>) -> Option<T> {
+    // only NaN is not comparable with itself
+    let is_valid = |v: &T| v.partial_cmp(v).is_some();
+    match mask {
+        Some(mask) => {
+            values.iter()
+                .zip(mask.iter())
+                .find(|&(v, &m)| !m && is_valid(v))
+                .map(|(&v, _)| v)
+        }
+        None => values.iter().find(|&v| is_valid(v)).cloned(),
+    }
+}
+
+/// Contiguous-slice kernels for numeric primitives
+pub struct Vectorized;
+
+macro_rules! define_arithmetic_kernel {
+    ($t:ident, $m:ident, $scalar:ident) => {
+        /// Elementwise op between slices of the same length
+        pub fn $m<T, O>(lhs: &[T], rhs: &[T]) -> Vec<O>
+            where T: Clone + $t<Output = O>
+        {
+            zip_map(lhs, rhs, |x, y| x.clone().$m(y.clone()))
+        }
+
+        /// Elementwise op between a slice and a scalar
+        pub fn $scalar<T, O>(lhs: &[T], rhs: T) -> Vec<O>
+            where T: Clone + $t<Output = O>
+        {
+            broadcast_map(lhs, &rhs, |x, y| x.clone().$m(y.clone()))
+        }
+    }
+}
+
+macro_rules! define_comparison_kernel {
+    ($m:ident, $scalar:ident, $op:tt) => {
+        /// Elementwise comparison between slices of the same length
+        pub fn $m<T: Primitive>(lhs: &[T], rhs: &[T]) -> Vec<bool> {
+            zip_map(lhs, rhs, |x, y| x $op y)
+        }
+
+        /// Elementwise comparison between a slice and a scalar
+        pub fn $scalar<T: Primitive>(lhs: &[T], rhs: T) -> Vec<bool> {
+            broadcast_map(lhs, &rhs, |x, y| x $op y)
+        }
+    }
+}
+
+impl Vectorized {
+    define_arithmetic_kernel!(Add, add, add_scalar);
+    define_arithmetic_kernel!(Sub, sub, sub_scalar);
+    define_arithmetic_kernel!(Mul, mul, mul_scalar);
+    define_arithmetic_kernel!(Div, div, div_scalar);
+    define_arithmetic_kernel!(Rem, rem, rem_scalar);
+    define_arithmetic_kernel!(BitAnd, bitand, bitand_scalar);
+    define_arithmetic_kernel!(BitOr, bitor, bitor_scalar);
+    define_arithmetic_kernel!(BitXor, bitxor, bitxor_scalar);
+
+    define_comparison_kernel!(eq, eq_scalar, ==);
+    define_comparison_kernel!(ne, ne_scalar, !=);
+    define_comparison_kernel!(lt, lt_scalar, <);
+    define_comparison_kernel!(le, le_scalar, <=);
+    define_comparison_kernel!(gt, gt_scalar, >);
+    define_comparison_kernel!(ge, ge_scalar, >=);
+
+    /// Sum of values, zero if empty
+    pub fn sum<T: Primitive>(values: &[T]) -> T {
+        lane_fold(values, T::zero(), |a, b| a + b)
+    }
+
+    /// Minimum ignoring NaN, `None` if there is no valid value
+    pub fn min<T: Primitive>(values: &[T]) -> Option<T> {
+        first_valid(values, None).map(|seed| lane_fold(values, seed, nan_min))
+    }
+
+    /// Maximum ignoring NaN, `None` if there is no valid value
+    pub fn max<T: Primitive>(values: &[T]) -> Option<T> {
+        first_valid(values, None).map(|seed| lane_fold(values, seed, nan_max))
+    }
+
+    /// Split `NullVec` into raw values and null flags, which can be passed
+    /// to `masked_*` kernels. Values at null locations are unspecified.
+    pub fn nullvec_parts<T: Primitive>(values: &NullVec<T>) -> (Vec<T>, Vec<bool>) {
+        let data: Vec<T> = values.iter_raw().map(|(_, &v)| v).collect();
+        (data, values.is_null())
+    }
+
+    /// Union of null flags
+    pub fn mask_union(lhs: &[bool], rhs: &[bool]) -> Vec<bool> {
+        zip_map(lhs, rhs, |x, y| *x | *y)
+    }
+
+    /// Elementwise addition of raw values, where `mask` is the union of null
+    /// flags of both sides. Values at null locations are unspecified.
+    pub fn masked_add<T: Primitive>(lhs: &[T], rhs: &[T], mask: &[bool]) -> Vec<T> {
+        masked_zip_map(lhs, rhs, mask, T::zero(), |x, y| x + y)
+    }
+
+    /// Same as `masked_add`
+    pub fn masked_sub<T: Primitive>(lhs: &[T], rhs: &[T], mask: &[bool]) -> Vec<T> {
+        masked_zip_map(lhs, rhs, mask, T::zero(), |x, y| x - y)
+    }
+
+    /// Same as `masked_add`
+    pub fn masked_mul<T: Primitive>(lhs: &[T], rhs: &[T], mask: &[bool]) -> Vec<T> {
+        masked_zip_map(lhs, rhs, mask, T::zero(), |x, y| x * y)
+    }
+
+    /// Same as `masked_add`, null divisors never raise
+    pub fn masked_div<T: Primitive>(lhs: &[T], rhs: &[T], mask: &[bool]) -> Vec<T> {
+        masked_zip_map(lhs, rhs, mask, T::one(), |x, y| x / y)
+    }
+
+    /// Sum of non-null values, zero if all values are null
+    pub fn masked_sum<T: Primitive>(values: &[T], mask: &[bool]) -> T {
+        masked_lane_fold(values, mask, T::zero(), |a, b| a + b)
+    }
+
+    /// Minimum of non-null values, `None` if all values are null
+    pub fn masked_min<T: Primitive>(values: &[T], mask: &[bool]) -> Option<T> {
+        first_valid(values, Some(mask))
+            .map(|seed| masked_lane_fold(values, mask, seed, nan_min))
+    }
+
+    /// Maximum of non-null values, `None` if all values are null
+    pub fn masked_max<T: Primitive>(values: &[T], mask: &[bool]) -> Option<T> {
+        first_valid(values, Some(mask))
+            .map(|seed| masked_lane_fold(values, mask, seed, nan_max))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::f64;
+    use nullvec::prelude::{NullVec, Nullable};
+
+    use super::Vectorized;
+
+    #[test]
+    fn test_arithmetic() {
+        let l: Vec<i64> = (0..20).collect();
+        let r: Vec<i64> = (1..21).collect();
+        let exp: Vec<i64> = (0..20).map(|x| x + x + 1).collect();
+        assert_eq!(Vectorized::add(&l, &r), exp);
+        assert_eq!(Vectorized::sub(&r, &l), vec![1; 20]);
+        assert_eq!(Vectorized::mul_scalar(&l, 2), (0..20).map(|x| x * 2).collect::<Vec<i64>>());
+        assert_eq!(Vectorized::div(&[1., 3.], &[2., 4.]), vec![0.5, 0.75]);
+        assert_eq!(Vectorized::rem_scalar(&l[..4], 3), vec![0, 1, 2, 0]);
+        assert_eq!(Vectorized::bitxor(&[true, false], &[true, true]), vec![false, true]);
+
+        // non-primitive types go through the same kernels
+        let l = vec![Nullable::new(6), Nullable::Null, Nullable::new(2)];
+        let r = vec![Nullable::new(3), Nullable::new(1), Nullable::Null];
+        assert_eq!(Vectorized::div(&l, &r),
+                   vec![Nullable::new(2), Nullable::Null, Nullable::Null]);
+        assert_eq!(Vectorized::sub_scalar(&l, Nullable::new(1)),
+                   vec![Nullable::new(5), Nullable::Null, Nullable::new(1)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_arithmetic_length_mismatch() {
+        Vectorized::add(&[1, 2], &[1]);
+    }
+
+    #[test]
+    fn test_comparison() {
+        let l = vec![1., 2., f64::NAN, 4.];
+        let r = vec![2., 2., 2., 2.];
+        assert_eq!(Vectorized::lt(&l, &r), vec![true, false, false, false]);
+        assert_eq!(Vectorized::ge_scalar(&l, 2.), vec![false, true, false, true]);
+        assert_eq!(Vectorized::ne(&l, &r), vec![true, false, true, true]);
+    }
+
+    #[test]
+    fn test_reduction() {
+        let values: Vec<i32> = (1..102).collect();
+        assert_eq!(Vectorized::sum(&values), 5151);
+        assert_eq!(Vectorized::min(&values), Some(1));
+        assert_eq!(Vectorized::max(&values), Some(101));
+
+        let values = vec![f64::NAN, 3., 1., f64::NAN, 2.];
+        assert_eq!(Vectorized::min(&values), Some(1.));
+        assert_eq!(Vectorized::max(&values), Some(3.));
+
+        let empty: Vec<f64> = vec![];
+        assert_eq!(Vectorized::sum(&empty), 0.);
+        assert_eq!(Vectorized::min(&empty), None);
+        assert_eq!(Vectorized::max(&vec![f64::NAN]), None);
+    }
+
+    #[test]
+    fn test_masked() {
+        let l = NullVec::with_mask(vec![1, 2, 3, 4], Some(vec![false, true, false, false]));
+        let r = NullVec::with_mask(vec![1, 1, 0, 2], Some(vec![false, false, true, false]));
+
+        let (ldata, lmask) = Vectorized::nullvec_parts(&l);
+        let (rdata, rmask) = Vectorized::nullvec_parts(&r);
+        assert_eq!(lmask, vec![false, true, false, false]);
+
+        let mask = Vectorized::mask_union(&lmask, &rmask);
+        assert_eq!(mask, vec![false, true, true, false]);
+
+        let res = NullVec::with_mask(Vectorized::masked_div(&ldata, &rdata, &mask), Some(mask));
+        assert_eq!(res.is_null(), vec![false, true, true, false]);
+        assert_eq!(res.not_null_values(), vec![1, 2]);
+
+        let res = Vectorized::masked_add(&ldata, &[1, 1, 1, 1], &lmask);
+        assert_eq!(res[0], 2);
+        assert_eq!(res[3], 5);
+
+        assert_eq!(Vectorized::masked_sum(&ldata, &lmask), 8);
+        assert_eq!(Vectorized::masked_min(&ldata, &lmask), Some(1));
+        assert_eq!(Vectorized::masked_max(&rdata, &rmask), Some(2));
+
+        let (data, mask) = Vectorized::nullvec_parts(&NullVec::new(vec![f64::NAN, f64::NAN]));
+        assert_eq!(Vectorized::masked_sum(&data, &mask), 0.);
+        assert_eq!(Vectorized::masked_min(&data, &mask), None);
+    }
+}
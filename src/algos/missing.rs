@@ -0,0 +1,246 @@
+use nullvec::prelude::{Array, NullVec, Nullable, Scalar};
+use nullvec::prelude::dev::NullStorable;
+
+use algos::array::{with_mask, to_f64_vec};
+
+/// Whether to drop a label if any or all of its values are null
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DropHow {
+    Any,
+    All,
+}
+
+/// Propagate last valid value forward, filling at most `limit` consecutive nulls.
+/// Values are pairs of null flag and value.
+pub fn fill_forward<T: Clone>(values: &[(bool, T)], limit: Option<usize>) -> Vec<(bool, T)> {
+    let mut result: Vec<(bool, T)> = Vec::with_capacity(values.len());
+    let mut last: Option<&T> = None;
+    let mut filled = 0;
+    for &(null, ref v) in values.iter() {
+        if !null {
+            last = Some(v);
+            filled = 0;
+            result.push((false, v.clone()));
+            continue;
+        }
+        match last {
+            Some(l) if limit.map_or(true, |n| filled < n) => {
+                filled += 1;
+                result.push((false, l.clone()));
+            }
+            _ => result.push((true, v.clone())),
+        }
+    }
+    result
+}
+
+/// Propagate next valid value backward, filling at most `limit` consecutive nulls
+pub fn fill_backward<T: Clone>(values: &[(bool, T)], limit: Option<usize>) -> Vec<(bool, T)> {
+    let reversed: Vec<(bool, T)> = values.iter().rev().cloned().collect();
+    let mut result = fill_forward(&reversed, limit);
+    result.reverse();
+    result
+}
+
+fn raw<T: Clone + NullStorable>(values: &NullVec<T>) -> Vec<(bool, T)> {
+    values.iter_raw().map(|(n, v)| (n, v.clone())).collect()
+}
+
+fn from_raw<T: NullStorable>(values: Vec<(bool, T)>) -> NullVec<T> {
+    let (mask, new_values): (Vec<bool>, Vec<T>) = values.into_iter().unzip();
+    with_mask(new_values, mask)
+}
+
+pub fn ffill_nullvec<T>(values: &NullVec<T>, limit: Option<usize>) -> NullVec<T>
+    where T: Clone + NullStorable
+{
+    from_raw(fill_forward(&raw(values), limit))
+}
+
+pub fn bfill_nullvec<T>(values: &NullVec<T>, limit: Option<usize>) -> NullVec<T>
+    where T: Clone + NullStorable
+{
+    from_raw(fill_backward(&raw(values), limit))
+}
+
+/// Null flags of `Array`
+pub fn array_is_null(values: &Array) -> Vec<bool> {
+    match values {
+        &Array::Int64Array(ref vals) => vals.is_null(),
+        &Array::Int32Array(ref vals) => vals.is_null(),
+        &Array::Int16Array(ref vals) => vals.is_null(),
+        &Array::Int8Array(ref vals) => vals.is_null(),
+        &Array::IsizeArray(ref vals) => vals.is_null(),
+        &Array::UInt64Array(ref vals) => vals.is_null(),
+        &Array::UInt32Array(ref vals) => vals.is_null(),
+        &Array::UInt16Array(ref vals) => vals.is_null(),
+        &Array::UInt8Array(ref vals) => vals.is_null(),
+        &Array::UsizeArray(ref vals) => vals.is_null(),
+        &Array::Float64Array(ref vals) => vals.is_null(),
+        &Array::Float32Array(ref vals) => vals.is_null(),
+        &Array::BoolArray(ref vals) => vals.is_null(),
+        &Array::StringArray(ref vals) => vals.is_null(),
+    }
+}
+
+fn unwrap_scalar<T: NullStorable>(value: Nullable<T>) -> T {
+    match value {
+        Nullable::Value(v) => v,
+        Nullable::Null => unreachable!(),
+    }
+}
+
+/// Fill nulls of integer `Array` with the scalar if it is representable in
+/// the dtype, otherwise upcast to `f64` so that the value is not truncated
+macro_rules! fill_integer {
+    ($values:ident, $vals:ident, $variant:ident, $conv:ident, $value:ident) => {{
+        let v = unwrap_scalar($value.$conv());
+        let f = unwrap_scalar($value.as_f64());
+        if v as f64 == f {
+            Array::$variant($vals.fill_null(v))
+        } else if $vals.has_null() {
+            let new_values: Vec<f64> = to_f64_vec($values)
+                .into_iter()
+                .map(|x| if x.is_nan() { f } else { x })
+                .collect();
+            Array::Float64Array(NullVec::new(new_values))
+        } else {
+            $values.clone()
+        }
+    }}
+}
+
+/// Fill nulls in `Array` with the scalar converted to its dtype. Integer
+/// columns are upcast to `f64` if the scalar is fractional or out of their
+/// range. Columns whose dtype is not compatible with the scalar are returned
+/// as they are.
+pub fn fillna_array(values: &Array, value: &Scalar) -> Array {
+    let numeric = match value {
+        &Scalar::Null => return values.clone(),
+        &Scalar::bool(_) |
+        &Scalar::String(_) => false,
+        _ => true,
+    };
+    match (values, value) {
+        (&Array::Int64Array(ref vals), _) if numeric => {
+            fill_integer!(values, vals, Int64Array, as_i64, value)
+        }
+        (&Array::Int32Array(ref vals), _) if numeric => {
+            fill_integer!(values, vals, Int32Array, as_i32, value)
+        }
+        (&Array::Int16Array(ref vals), _) if numeric => {
+            fill_integer!(values, vals, Int16Array, as_i16, value)
+        }
+        (&Array::Int8Array(ref vals), _) if numeric => {
+            fill_integer!(values, vals, Int8Array, as_i8, value)
+        }
+        (&Array::IsizeArray(ref vals), _) if numeric => {
+            fill_integer!(values, vals, IsizeArray, as_isize, value)
+        }
+        (&Array::UInt64Array(ref vals), _) if numeric => {
+            fill_integer!(values, vals, UInt64Array, as_u64, value)
+        }
+        (&Array::UInt32Array(ref vals), _) if numeric => {
+            fill_integer!(values, vals, UInt32Array, as_u32, value)
+        }
+        (&Array::UInt16Array(ref vals), _) if numeric => {
+            fill_integer!(values, vals, UInt16Array, as_u16, value)
+        }
+        (&Array::UInt8Array(ref vals), _) if numeric => {
+            fill_integer!(values, vals, UInt8Array, as_u8, value)
+        }
+        (&Array::UsizeArray(ref vals), _) if numeric => {
+            fill_integer!(values, vals, UsizeArray, as_usize, value)
+        }
+        (&Array::Float64Array(ref vals), _) if numeric => {
+            Array::Float64Array(vals.fill_null(unwrap_scalar(value.as_f64())))
+        }
+        (&Array::Float32Array(ref vals), _) if numeric => {
+            Array::Float32Array(vals.fill_null(unwrap_scalar(value.as_f32())))
+        }
+        (&Array::BoolArray(ref vals), &Scalar::bool(v)) => Array::BoolArray(vals.fill_null(v)),
+        (&Array::StringArray(ref vals), &Scalar::String(ref v)) => {
+            Array::StringArray(vals.fill_null(v.clone()))
+        }
+        _ => values.clone(),
+    }
+}
+
+/// Whether to keep each label, from its number of not null values
+pub fn keep_labels(counts: &[usize], total: usize, how: DropHow, thresh: Option<usize>) -> Vec<bool> {
+    counts.iter()
+        .map(|&c| match thresh {
+            Some(t) => c >= t,
+            None => {
+                match how {
+                    DropHow::Any => c == total,
+                    DropHow::All => c > 0,
+                }
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use nullvec::prelude::{Array, NullVec, Scalar};
+    use super::{fill_forward, fill_backward, fillna_array, keep_labels, DropHow};
+
+    #[test]
+    fn test_fill_forward() {
+        let values = vec![(true, 0), (false, 1), (true, 0), (true, 0), (false, 4), (true, 0)];
+        let res = fill_forward(&values, None);
+        assert_eq!(res,
+                   vec![(true, 0), (false, 1), (false, 1), (false, 1), (false, 4), (false, 4)]);
+
+        let res = fill_forward(&values, Some(1));
+        assert_eq!(res,
+                   vec![(true, 0), (false, 1), (false, 1), (true, 0), (false, 4), (false, 4)]);
+    }
+
+    #[test]
+    fn test_fill_backward() {
+        let values = vec![(true, 0), (false, 1), (true, 0), (true, 0), (false, 4), (true, 0)];
+        let res = fill_backward(&values, Some(1));
+        assert_eq!(res,
+                   vec![(false, 1), (false, 1), (true, 0), (false, 4), (false, 4), (true, 0)]);
+    }
+
+    #[test]
+    fn test_fillna_array() {
+        let arr = Array::Int64Array(NullVec::with_mask(vec![1, 2], Some(vec![true, false])));
+        assert_eq!(fillna_array(&arr, &Scalar::f64(5.)), Array::Int64Array(NullVec::new(vec![5, 2])));
+        assert_eq!(fillna_array(&arr, &Scalar::String("x".to_string())), arr);
+
+        // fractional value upcasts to f64
+        assert_eq!(fillna_array(&arr, &Scalar::f64(1.5)),
+                   Array::Float64Array(NullVec::new(vec![1.5, 2.])));
+
+        // out of range value upcasts to f64
+        let arr = Array::UInt8Array(NullVec::with_mask(vec![1, 2], Some(vec![false, true])));
+        assert_eq!(fillna_array(&arr, &Scalar::i64(-1)),
+                   Array::Float64Array(NullVec::new(vec![1., -1.])));
+        assert_eq!(fillna_array(&arr, &Scalar::i64(300)),
+                   Array::Float64Array(NullVec::new(vec![1., 300.])));
+        assert_eq!(fillna_array(&arr, &Scalar::i64(7)),
+                   Array::UInt8Array(NullVec::new(vec![1, 7])));
+
+        // nothing to fill keeps dtype
+        let arr = Array::Int64Array(NullVec::new(vec![1, 2]));
+        assert_eq!(fillna_array(&arr, &Scalar::f64(1.5)), arr);
+
+        let arr = Array::StringArray(NullVec::with_mask(vec!["a".to_string(), "b".to_string()],
+                                                        Some(vec![false, true])));
+        assert_eq!(fillna_array(&arr, &Scalar::String("x".to_string())),
+                   Array::StringArray(NullVec::new(vec!["a".to_string(), "x".to_string()])));
+    }
+
+    #[test]
+    fn test_keep_labels() {
+        let counts = vec![0, 1, 2];
+        assert_eq!(keep_labels(&counts, 2, DropHow::Any, None), vec![false, false, true]);
+        assert_eq!(keep_labels(&counts, 2, DropHow::All, None), vec![false, true, true]);
+        assert_eq!(keep_labels(&counts, 2, DropHow::Any, Some(1)), vec![false, true, true]);
+    }
+}
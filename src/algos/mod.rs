@@ -1,6 +1,17 @@
+pub mod array;
 pub mod computation;
+pub mod correlation;
 pub mod counter;
+pub mod cumulative;
 mod duplicates;
 pub mod grouper;
+pub mod interpolate;
 pub mod join;
+pub mod kernels;
+pub mod missing;
+pub mod parallel;
+pub mod quantile;
+pub mod rank;
 mod set;
+pub mod shift;
+pub mod window;
@@ -0,0 +1,69 @@
+//! Column-wise mapping which runs across threads when the `parallel`
+//! feature is enabled. Both paths share the same bounds so that callers
+//! compile identically regardless of the feature, and results are
+//! collected in input order.
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Apply `func` to each element of `values`
+#[cfg(feature = "parallel")]
+pub fn map<T, U, F>(values: &[T], func: F) -> Vec<U>
+    where T: Sync,
+          U: Send,
+          F: Fn(&T) -> U + Sync + Send
+{
+    values.par_iter().map(func).collect()
+}
+
+/// Apply `func` to each element of `values`
+#[cfg(not(feature = "parallel"))]
+pub fn map<T, U, F>(values: &[T], func: F) -> Vec<U>
+    where T: Sync,
+          U: Send,
+          F: Fn(&T) -> U + Sync + Send
+{
+    values.iter().map(func).collect()
+}
+
+/// Consume `values` applying `func` to each element
+#[cfg(feature = "parallel")]
+pub fn into_map<T, U, F>(values: Vec<T>, func: F) -> Vec<U>
+    where T: Send,
+          U: Send,
+          F: Fn(T) -> U + Sync + Send
+{
+    values.into_par_iter().map(func).collect()
+}
+
+/// Consume `values` applying `func` to each element
+#[cfg(not(feature = "parallel"))]
+pub fn into_map<T, U, F>(values: Vec<T>, func: F) -> Vec<U>
+    where T: Send,
+          U: Send,
+          F: Fn(T) -> U + Sync + Send
+{
+    values.into_iter().map(func).collect()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{map, into_map};
+
+    #[test]
+    fn test_map_keeps_order() {
+        let values: Vec<usize> = (0..1000).collect();
+        let res = map(&values, |x| x * 2);
+        let exp: Vec<usize> = (0..1000).map(|x| x * 2).collect();
+        assert_eq!(res, exp);
+    }
+
+    #[test]
+    fn test_into_map_keeps_order() {
+        let values: Vec<String> = (0..100).map(|x| x.to_string()).collect();
+        let res = into_map(values, |x| x.len());
+        let exp: Vec<usize> = (0..100).map(|x: usize| x.to_string().len()).collect();
+        assert_eq!(res, exp);
+    }
+}
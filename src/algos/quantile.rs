@@ -0,0 +1,174 @@
+use std::cmp::Ordering;
+use std::f64;
+
+use algos::computation::Moments;
+
+/// Percentiles included in `describe` by default
+pub const DEFAULT_PERCENTILES: [f64; 3] = [0.25, 0.5, 0.75];
+
+/// How to compute a quantile lying between two data points
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum QuantileInterpolation {
+    Linear,
+    Lower,
+    Higher,
+    /// Nearest data point, rounding half to even like numpy
+    Nearest,
+    Midpoint,
+}
+
+/// Compute quantile of values sorted in ascending order, which must not
+/// contain NaN. Return NaN if values are empty or `q` is not between 0 and 1.
+pub fn sorted_quantile(sorted: &[f64], q: f64, interpolation: QuantileInterpolation) -> f64 {
+    if sorted.is_empty() || !(q >= 0. && q <= 1.) {
+        return f64::NAN;
+    }
+    let pos = q * (sorted.len() - 1) as f64;
+    let lower = pos.floor() as usize;
+    let higher = pos.ceil() as usize;
+    let frac = pos - lower as f64;
+
+    match interpolation {
+        QuantileInterpolation::Linear => {
+            if lower == higher {
+                sorted[lower]
+            } else {
+                sorted[lower] + (sorted[higher] - sorted[lower]) * frac
+            }
+        }
+        QuantileInterpolation::Lower => sorted[lower],
+        QuantileInterpolation::Higher => sorted[higher],
+        QuantileInterpolation::Nearest => {
+            if frac < 0.5 || (frac == 0.5 && lower % 2 == 0) {
+                sorted[lower]
+            } else {
+                sorted[higher]
+            }
+        }
+        QuantileInterpolation::Midpoint => (sorted[lower] + sorted[higher]) / 2.,
+    }
+}
+
+/// Sort values ignoring NaN
+pub fn sort_valid(values: &[f64]) -> Vec<f64> {
+    let mut sorted: Vec<f64> = values.iter().cloned().filter(|x| !x.is_nan()).collect();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    sorted
+}
+
+/// Compute quantiles of values, NaN is ignored
+pub fn quantiles(values: &[f64], qs: &[f64], interpolation: QuantileInterpolation) -> Vec<f64> {
+    let sorted = sort_valid(values);
+    qs.iter().map(|&q| sorted_quantile(&sorted, q, interpolation)).collect()
+}
+
+/// Compute count, mean, std, min, given percentiles and max ignoring NaN.
+/// Moments are computed by single pass over values.
+pub fn describe(values: &[f64], percentiles: &[f64]) -> Vec<f64> {
+    let moments = Moments::from_valid(values);
+    let sorted = sort_valid(values);
+
+    let mut result: Vec<f64> = vec![moments.count() as f64,
+                                    moments.mean(),
+                                    moments.std(0),
+                                    moments.min()];
+    for &q in percentiles.iter() {
+        result.push(sorted_quantile(&sorted, q, QuantileInterpolation::Linear));
+    }
+    result.push(moments.max());
+    result
+}
+
+/// Sort percentiles for `describe`, adding the median if missing
+pub fn describe_percentiles(percentiles: &[f64]) -> Vec<f64> {
+    let mut new_percentiles: Vec<f64> = percentiles.to_vec();
+    new_percentiles.push(0.5);
+    new_percentiles.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    new_percentiles.dedup();
+    new_percentiles
+}
+
+/// Format quantiles as percent labels, like "25%"
+pub fn percentile_labels(qs: &[f64]) -> Vec<String> {
+    // round to suppress floating point error, such as 0.1 * 3
+    qs.iter().map(|q| format!("{}%", (q * 1e8).round() / 1e6)).collect()
+}
+
+/// Labels of `describe` result with given percentiles
+pub fn describe_labels(percentiles: &[f64]) -> Vec<String> {
+    let mut labels: Vec<String> = vec!["count".to_string(),
+                                       "mean".to_string(),
+                                       "std".to_string(),
+                                       "min".to_string()];
+    labels.append(&mut percentile_labels(percentiles));
+    labels.push("max".to_string());
+    labels
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::f64;
+    use super::{sorted_quantile, quantiles, describe, describe_percentiles, percentile_labels,
+                QuantileInterpolation};
+
+    #[test]
+    fn test_sorted_quantile() {
+        let values = vec![1., 2., 3., 4.];
+        let q = 0.5;
+        assert_eq!(sorted_quantile(&values, q, QuantileInterpolation::Linear), 2.5);
+        assert_eq!(sorted_quantile(&values, q, QuantileInterpolation::Lower), 2.);
+        assert_eq!(sorted_quantile(&values, q, QuantileInterpolation::Higher), 3.);
+        assert_eq!(sorted_quantile(&values, q, QuantileInterpolation::Nearest), 3.);
+        assert_eq!(sorted_quantile(&values, q, QuantileInterpolation::Midpoint), 2.5);
+
+        let q = 0.4;
+        assert!((sorted_quantile(&values, q, QuantileInterpolation::Linear) - 2.2).abs() < 1e-10);
+        assert_eq!(sorted_quantile(&values, q, QuantileInterpolation::Nearest), 2.);
+        let q = 0.9;
+        assert_eq!(sorted_quantile(&values, q, QuantileInterpolation::Nearest), 4.);
+
+        assert_eq!(sorted_quantile(&values, 0., QuantileInterpolation::Linear), 1.);
+        assert_eq!(sorted_quantile(&values, 1., QuantileInterpolation::Linear), 4.);
+        assert!(sorted_quantile(&vec![], 0.5, QuantileInterpolation::Linear).is_nan());
+    }
+
+    #[test]
+    fn test_nearest_half_to_even() {
+        let values = vec![1., 2., 3., 4., 5., 6.];
+        // position 0.5 -> 0, position 1.5 -> 2
+        assert_eq!(sorted_quantile(&values, 0.1, QuantileInterpolation::Nearest), 1.);
+        assert_eq!(sorted_quantile(&values, 0.3, QuantileInterpolation::Nearest), 3.);
+    }
+
+    #[test]
+    fn test_quantiles() {
+        let values = vec![5., f64::NAN, 1., 3., 2., 4.];
+        assert_eq!(quantiles(&values, &[0., 0.25, 0.5, 1.], QuantileInterpolation::Linear),
+                   vec![1., 2., 3., 5.]);
+    }
+
+    #[test]
+    fn test_describe_percentiles() {
+        assert_eq!(describe_percentiles(&[0.9, 0.1]), vec![0.1, 0.5, 0.9]);
+        assert_eq!(describe_percentiles(&[0.25, 0.5, 0.75]), vec![0.25, 0.5, 0.75]);
+        assert_eq!(percentile_labels(&[0.025, 0.5, 0.1 * 3.]), vec!["2.5%", "50%", "30%"]);
+    }
+
+    #[test]
+    fn test_describe() {
+        let values = vec![3., f64::NAN, 1., 2., 6.];
+        assert_eq!(describe(&values, &[0.5]), vec![4., 3., 3.5f64.sqrt(), 1., 2.5, 6.]);
+
+        let res = describe(&vec![f64::NAN], &[0.5]);
+        assert_eq!(res[0], 0.);
+        assert!(res[1..].iter().all(|x| x.is_nan()));
+    }
+
+    #[test]
+    fn test_quantile_out_of_range() {
+        assert!(sorted_quantile(&vec![1., 2.], 1.5, QuantileInterpolation::Linear).is_nan());
+        assert!(sorted_quantile(&vec![1., 2.], -0.1, QuantileInterpolation::Linear).is_nan());
+        assert!(sorted_quantile(&vec![1., 2.], f64::NAN, QuantileInterpolation::Linear).is_nan());
+    }
+}
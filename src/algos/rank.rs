@@ -0,0 +1,174 @@
+use std::cmp::Ordering;
+use std::f64;
+
+/// How to rank values having the same value
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RankMethod {
+    /// Average rank of the group
+    Average,
+    /// Lowest rank of the group
+    Min,
+    /// Highest rank of the group
+    Max,
+    /// Ranks assigned in order they appear
+    First,
+    /// Like `Min`, but rank always increases by 1 between groups
+    Dense,
+}
+
+/// How to rank null values
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NaOption {
+    /// Leave nulls as NaN
+    Keep,
+    /// Rank nulls as the smallest
+    Top,
+    /// Rank nulls as the largest
+    Bottom,
+}
+
+/// Rank values, `nulls` flags values to be handled by `na_option`.
+/// Nulls are regarded as the same value each other.
+pub fn rank<T: PartialOrd>(values: &[T],
+                           nulls: &[bool],
+                           method: RankMethod,
+                           ascending: bool,
+                           na_option: NaOption)
+                           -> Vec<f64> {
+    assert!(values.len() == nulls.len(), "Length mismatch!");
+
+    let mut valid: Vec<usize> = (0..values.len()).filter(|&i| !nulls[i]).collect();
+    // stable sort keeps order of appearance for ties
+    valid.sort_by(|&a, &b| {
+        let ord = values[a].partial_cmp(&values[b]).unwrap_or(Ordering::Equal);
+        if ascending { ord } else { ord.reverse() }
+    });
+
+    // groups of locations having the same value, in rank order
+    let mut ties: Vec<Vec<usize>> = vec![];
+    for loc in valid {
+        let same = match ties.last() {
+            Some(group) => values[group[0]] == values[loc],
+            None => false,
+        };
+        if same {
+            ties.last_mut().unwrap().push(loc);
+        } else {
+            ties.push(vec![loc]);
+        }
+    }
+    let null_locs: Vec<usize> = (0..values.len()).filter(|&i| nulls[i]).collect();
+    if !null_locs.is_empty() {
+        match na_option {
+            NaOption::Keep => {}
+            NaOption::Top => ties.insert(0, null_locs),
+            NaOption::Bottom => ties.push(null_locs),
+        }
+    }
+
+    let mut result: Vec<f64> = vec![f64::NAN; values.len()];
+    let mut start = 0;
+    for (dense, group) in ties.iter().enumerate() {
+        let size = group.len();
+        for (i, &loc) in group.iter().enumerate() {
+            result[loc] = match method {
+                RankMethod::Average => start as f64 + (size + 1) as f64 / 2.,
+                RankMethod::Min => (start + 1) as f64,
+                RankMethod::Max => (start + size) as f64,
+                RankMethod::First => (start + i + 1) as f64,
+                RankMethod::Dense => (dense + 1) as f64,
+            };
+        }
+        start += size;
+    }
+    result
+}
+
+/// Location of the minimum value, nulls are skipped.
+/// The first location is returned for ties.
+pub fn argmin<T: PartialOrd>(values: &[T], nulls: &[bool]) -> Option<usize> {
+    argbest(values, nulls, Ordering::Less)
+}
+
+/// Location of the maximum value, nulls are skipped.
+/// The first location is returned for ties.
+pub fn argmax<T: PartialOrd>(values: &[T], nulls: &[bool]) -> Option<usize> {
+    argbest(values, nulls, Ordering::Greater)
+}
+
+fn argbest<T: PartialOrd>(values: &[T], nulls: &[bool], target: Ordering) -> Option<usize> {
+    let mut best: Option<usize> = None;
+    for (i, v) in values.iter().enumerate() {
+        if nulls[i] {
+            continue;
+        }
+        best = match best {
+            Some(b) if v.partial_cmp(&values[b]) != Some(target) => Some(b),
+            _ => Some(i),
+        };
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{rank, argmin, argmax, RankMethod, NaOption};
+
+    #[test]
+    fn test_rank_methods() {
+        let values = vec![3, 1, 3, 2];
+        let nulls = vec![false; 4];
+        assert_eq!(rank(&values, &nulls, RankMethod::Average, true, NaOption::Keep),
+                   vec![3.5, 1., 3.5, 2.]);
+        assert_eq!(rank(&values, &nulls, RankMethod::Min, true, NaOption::Keep),
+                   vec![3., 1., 3., 2.]);
+        assert_eq!(rank(&values, &nulls, RankMethod::Max, true, NaOption::Keep),
+                   vec![4., 1., 4., 2.]);
+        assert_eq!(rank(&values, &nulls, RankMethod::First, true, NaOption::Keep),
+                   vec![3., 1., 4., 2.]);
+        assert_eq!(rank(&values, &nulls, RankMethod::Dense, true, NaOption::Keep),
+                   vec![3., 1., 3., 2.]);
+    }
+
+    #[test]
+    fn test_rank_descending() {
+        let values = vec![3, 1, 3, 2];
+        let nulls = vec![false; 4];
+        assert_eq!(rank(&values, &nulls, RankMethod::Average, false, NaOption::Keep),
+                   vec![1.5, 4., 1.5, 3.]);
+        assert_eq!(rank(&values, &nulls, RankMethod::First, false, NaOption::Keep),
+                   vec![1., 4., 2., 3.]);
+    }
+
+    #[test]
+    fn test_rank_nulls() {
+        let values = vec![2., 0., 1., 0.];
+        let nulls = vec![false, true, false, true];
+        let res = rank(&values, &nulls, RankMethod::Average, true, NaOption::Keep);
+        assert_eq!(res[0], 2.);
+        assert!(res[1].is_nan());
+        assert_eq!(res[2], 1.);
+        assert!(res[3].is_nan());
+
+        assert_eq!(rank(&values, &nulls, RankMethod::Average, true, NaOption::Top),
+                   vec![4., 1.5, 3., 1.5]);
+        assert_eq!(rank(&values, &nulls, RankMethod::Min, true, NaOption::Bottom),
+                   vec![2., 3., 1., 3.]);
+    }
+
+    #[test]
+    fn test_argminmax() {
+        let values = vec![3., 1., 5., 1., 5.];
+        let nulls = vec![false, false, false, false, false];
+        assert_eq!(argmin(&values, &nulls), Some(1));
+        assert_eq!(argmax(&values, &nulls), Some(2));
+
+        let nulls = vec![false, true, true, true, true];
+        assert_eq!(argmin(&values, &nulls), Some(0));
+        assert_eq!(argmax(&values, &nulls), Some(0));
+
+        let nulls = vec![true; 5];
+        assert_eq!(argmin(&values, &nulls), None);
+    }
+}
@@ -0,0 +1,106 @@
+use std::f64;
+
+use nullvec::prelude::NullVec;
+use nullvec::prelude::dev::NullStorable;
+
+use algos::array::with_mask;
+
+/// Return the source location of each location after shifting by periods,
+/// `None` if it is shifted out of range
+pub fn shift_locs(len: usize, periods: isize) -> Vec<Option<usize>> {
+    (0..len as isize)
+        .map(|i| {
+            let j = i - periods;
+            if j >= 0 && j < len as isize {
+                Some(j as usize)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Shift values keeping nulls, locations shifted out of range are null
+pub fn shift_nullvec<T>(values: &NullVec<T>, periods: isize) -> NullVec<T>
+    where T: Clone + NullStorable
+{
+    let raw: Vec<(bool, T)> = values.iter_raw().map(|(n, v)| (n, v.clone())).collect();
+
+    let mut new_values: Vec<T> = Vec::with_capacity(raw.len());
+    let mut mask: Vec<bool> = Vec::with_capacity(raw.len());
+    for loc in shift_locs(raw.len(), periods) {
+        match loc {
+            Some(j) => {
+                new_values.push(raw[j].1.clone());
+                mask.push(raw[j].0);
+            }
+            None => {
+                new_values.push(T::default());
+                mask.push(true);
+            }
+        }
+    }
+    with_mask(new_values, mask)
+}
+
+/// Difference from the element `periods` before, NaN is regarded as null
+pub fn diff(values: &[f64], periods: isize) -> Vec<f64> {
+    shift_locs(values.len(), periods)
+        .into_iter()
+        .zip(values.iter())
+        .map(|(loc, v)| match loc {
+            Some(j) => v - values[j],
+            None => f64::NAN,
+        })
+        .collect()
+}
+
+/// Percentage change from the element `periods` before, NaN is regarded as null
+pub fn pct_change(values: &[f64], periods: isize) -> Vec<f64> {
+    shift_locs(values.len(), periods)
+        .into_iter()
+        .zip(values.iter())
+        .map(|(loc, v)| match loc {
+            Some(j) => v / values[j] - 1.,
+            None => f64::NAN,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::f64;
+    use nullvec::prelude::NullVec;
+    use super::{shift_locs, shift_nullvec, diff, pct_change};
+
+    #[test]
+    fn test_shift_locs() {
+        assert_eq!(shift_locs(4, 1), vec![None, Some(0), Some(1), Some(2)]);
+        assert_eq!(shift_locs(4, -2), vec![Some(2), Some(3), None, None]);
+        assert_eq!(shift_locs(3, 0), vec![Some(0), Some(1), Some(2)]);
+        assert_eq!(shift_locs(2, 5), vec![None, None]);
+    }
+
+    #[test]
+    fn test_shift_nullvec() {
+        let nv = NullVec::with_mask(vec![1, 2, 3], Some(vec![false, true, false]));
+        let res = shift_nullvec(&nv, 1);
+        assert_eq!(res, NullVec::with_mask(vec![0, 1, 2], Some(vec![true, false, true])));
+    }
+
+    #[test]
+    fn test_diff_pct_change() {
+        let values = vec![1., 2., f64::NAN, 8.];
+        let res = diff(&values, 1);
+        assert!(res[0].is_nan());
+        assert_eq!(res[1], 1.);
+        assert!(res[2].is_nan());
+        assert!(res[3].is_nan());
+
+        let res = pct_change(&vec![1., 2., 4., 5.], 2);
+        assert!(res[0].is_nan());
+        assert!(res[1].is_nan());
+        assert_eq!(&res[2..], &[3., 1.5]);
+    }
+}
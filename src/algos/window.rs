@@ -0,0 +1,582 @@
+use std::cmp;
+use std::collections::VecDeque;
+use std::f64;
+
+// Moving window kernels. Input values are f64 where NaN is regarded as null,
+// nulls are skipped and are not counted as observation.
+
+/// Accumulator which can be updated when a value enters / leaves the window
+trait Accumulator {
+    fn add(&mut self, location: usize, value: f64);
+    fn remove(&mut self, location: usize, value: f64);
+    fn count(&self) -> usize;
+    fn value(&self) -> f64;
+}
+
+struct SumAccumulator {
+    count: usize,
+    sum: f64,
+}
+
+impl Accumulator for SumAccumulator {
+    fn add(&mut self, _: usize, value: f64) {
+        self.count += 1;
+        self.sum += value;
+    }
+
+    fn remove(&mut self, _: usize, value: f64) {
+        self.count -= 1;
+        self.sum -= value;
+    }
+
+    fn count(&self) -> usize {
+        self.count
+    }
+
+    fn value(&self) -> f64 {
+        self.sum
+    }
+}
+
+struct MeanAccumulator {
+    inner: SumAccumulator,
+}
+
+impl Accumulator for MeanAccumulator {
+    fn add(&mut self, location: usize, value: f64) {
+        self.inner.add(location, value);
+    }
+
+    fn remove(&mut self, location: usize, value: f64) {
+        self.inner.remove(location, value);
+    }
+
+    fn count(&self) -> usize {
+        self.inner.count
+    }
+
+    fn value(&self) -> f64 {
+        self.inner.sum / self.inner.count as f64
+    }
+}
+
+/// Welford's online algorithm, which supports removal
+struct VarAccumulator {
+    count: usize,
+    mean: f64,
+    m2: f64,
+    ddof: usize,
+}
+
+impl Accumulator for VarAccumulator {
+    fn add(&mut self, _: usize, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    fn remove(&mut self, _: usize, value: f64) {
+        self.count -= 1;
+        if self.count == 0 {
+            self.mean = 0.;
+            self.m2 = 0.;
+        } else {
+            let delta = value - self.mean;
+            self.mean -= delta / self.count as f64;
+            self.m2 -= delta * (value - self.mean);
+        }
+    }
+
+    fn count(&self) -> usize {
+        self.count
+    }
+
+    fn value(&self) -> f64 {
+        if self.count <= self.ddof {
+            f64::NAN
+        } else {
+            // m2 can be slightly negative because of rounding error
+            self.m2.max(0.) / (self.count - self.ddof) as f64
+        }
+    }
+}
+
+/// Monotonic deque, front always holds the min (or max) of the window
+struct MinMaxAccumulator {
+    count: usize,
+    deque: VecDeque<(usize, f64)>,
+    is_max: bool,
+}
+
+impl Accumulator for MinMaxAccumulator {
+    fn add(&mut self, location: usize, value: f64) {
+        self.count += 1;
+        while let Some(&(_, last)) = self.deque.back() {
+            let dominated = if self.is_max { last <= value } else { last >= value };
+            if dominated {
+                self.deque.pop_back();
+            } else {
+                break;
+            }
+        }
+        self.deque.push_back((location, value));
+    }
+
+    fn remove(&mut self, location: usize, _: f64) {
+        self.count -= 1;
+        if let Some(&(front, _)) = self.deque.front() {
+            if front == location {
+                self.deque.pop_front();
+            }
+        }
+    }
+
+    fn count(&self) -> usize {
+        self.count
+    }
+
+    fn value(&self) -> f64 {
+        self.deque.front().unwrap().1
+    }
+}
+
+/// Fixed size moving window
+pub struct RollingWindow {
+    pub window: usize,
+    pub min_periods: usize,
+    pub center: bool,
+}
+
+impl RollingWindow {
+    pub fn new(window: usize, min_periods: usize, center: bool) -> Self {
+        assert!(window > 0, "window must be positive");
+        RollingWindow {
+            window: window,
+            min_periods: min_periods,
+            center: center,
+        }
+    }
+
+    /// Location offset of the result label from the window end
+    fn offset(&self) -> usize {
+        if self.center { (self.window - 1) / 2 } else { 0 }
+    }
+
+    fn roll<A: Accumulator>(&self, values: &[f64], mut acc: A) -> Vec<f64> {
+        let n = values.len();
+        let offset = self.offset();
+        let min_periods = cmp::max(self.min_periods, 1);
+
+        let mut result: Vec<f64> = Vec::with_capacity(n);
+        // when centered, window runs off the end by offset
+        for i in 0..(n + offset) {
+            if i < n && !values[i].is_nan() {
+                acc.add(i, values[i]);
+            }
+            if i >= self.window {
+                let j = i - self.window;
+                if !values[j].is_nan() {
+                    acc.remove(j, values[j]);
+                }
+            }
+            if i >= offset {
+                if acc.count() >= min_periods {
+                    result.push(acc.value());
+                } else {
+                    result.push(f64::NAN);
+                }
+            }
+        }
+        result
+    }
+
+    pub fn sum(&self, values: &[f64]) -> Vec<f64> {
+        self.roll(values,
+                  SumAccumulator {
+                      count: 0,
+                      sum: 0.,
+                  })
+    }
+
+    pub fn mean(&self, values: &[f64]) -> Vec<f64> {
+        self.roll(values,
+                  MeanAccumulator {
+                      inner: SumAccumulator {
+                          count: 0,
+                          sum: 0.,
+                      },
+                  })
+    }
+
+    pub fn var(&self, values: &[f64], ddof: usize) -> Vec<f64> {
+        self.roll(values,
+                  VarAccumulator {
+                      count: 0,
+                      mean: 0.,
+                      m2: 0.,
+                      ddof: ddof,
+                  })
+    }
+
+    pub fn std(&self, values: &[f64], ddof: usize) -> Vec<f64> {
+        self.var(values, ddof).into_iter().map(|x| x.sqrt()).collect()
+    }
+
+    pub fn min(&self, values: &[f64]) -> Vec<f64> {
+        self.roll(values,
+                  MinMaxAccumulator {
+                      count: 0,
+                      deque: VecDeque::with_capacity(self.window),
+                      is_max: false,
+                  })
+    }
+
+    pub fn max(&self, values: &[f64]) -> Vec<f64> {
+        self.roll(values,
+                  MinMaxAccumulator {
+                      count: 0,
+                      deque: VecDeque::with_capacity(self.window),
+                      is_max: true,
+                  })
+    }
+
+    /// Number of not null values in each window, regardless of min_periods
+    pub fn count(&self, values: &[f64]) -> Vec<usize> {
+        let n = values.len();
+        let offset = self.offset();
+
+        let mut result: Vec<usize> = Vec::with_capacity(n);
+        let mut count = 0;
+        for i in 0..(n + offset) {
+            if i < n && !values[i].is_nan() {
+                count += 1;
+            }
+            if i >= self.window && !values[i - self.window].is_nan() {
+                count -= 1;
+            }
+            if i >= offset {
+                result.push(count);
+            }
+        }
+        result
+    }
+}
+
+/// Window which grows from the first row
+pub struct ExpandingWindow {
+    pub min_periods: usize,
+}
+
+impl ExpandingWindow {
+    pub fn new(min_periods: usize) -> Self {
+        ExpandingWindow { min_periods: min_periods }
+    }
+
+    /// Rolling window which covers all values, thus nothing is removed
+    fn rolling(&self, values: &[f64]) -> RollingWindow {
+        RollingWindow::new(cmp::max(values.len(), 1), self.min_periods, false)
+    }
+
+    pub fn sum(&self, values: &[f64]) -> Vec<f64> {
+        self.rolling(values).sum(values)
+    }
+
+    pub fn mean(&self, values: &[f64]) -> Vec<f64> {
+        self.rolling(values).mean(values)
+    }
+
+    pub fn var(&self, values: &[f64], ddof: usize) -> Vec<f64> {
+        self.rolling(values).var(values, ddof)
+    }
+
+    pub fn std(&self, values: &[f64], ddof: usize) -> Vec<f64> {
+        self.rolling(values).std(values, ddof)
+    }
+
+    pub fn min(&self, values: &[f64]) -> Vec<f64> {
+        self.rolling(values).min(values)
+    }
+
+    pub fn max(&self, values: &[f64]) -> Vec<f64> {
+        self.rolling(values).max(values)
+    }
+
+    pub fn count(&self, values: &[f64]) -> Vec<usize> {
+        self.rolling(values).count(values)
+    }
+}
+
+/// Exponentially weighted window, following pandas' `ewm` semantics
+pub struct EwmWindow {
+    pub alpha: f64,
+    pub adjust: bool,
+    pub ignore_na: bool,
+    pub min_periods: usize,
+}
+
+impl EwmWindow {
+    pub fn new(alpha: f64, adjust: bool, ignore_na: bool, min_periods: usize) -> Self {
+        assert!(alpha > 0. && alpha <= 1., "alpha must be in (0, 1]");
+        EwmWindow {
+            alpha: alpha,
+            adjust: adjust,
+            ignore_na: ignore_na,
+            min_periods: min_periods,
+        }
+    }
+
+    pub fn mean(&self, values: &[f64]) -> Vec<f64> {
+        let min_periods = cmp::max(self.min_periods, 1);
+        let old_wt_factor = 1. - self.alpha;
+        let new_wt = if self.adjust { 1. } else { self.alpha };
+
+        let mut result: Vec<f64> = Vec::with_capacity(values.len());
+        let mut weighted = f64::NAN;
+        let mut old_wt = 1.;
+        let mut nobs = 0;
+
+        for &cur in values.iter() {
+            let is_obs = !cur.is_nan();
+            if is_obs {
+                nobs += 1;
+            }
+            if !weighted.is_nan() {
+                if is_obs || !self.ignore_na {
+                    old_wt *= old_wt_factor;
+                    if is_obs {
+                        // avoid numerical error on constant series
+                        if weighted != cur {
+                            weighted = (old_wt * weighted + new_wt * cur) / (old_wt + new_wt);
+                        }
+                        if self.adjust {
+                            old_wt += new_wt;
+                        } else {
+                            old_wt = 1.;
+                        }
+                    }
+                }
+            } else if is_obs {
+                weighted = cur;
+            }
+            result.push(if nobs >= min_periods { weighted } else { f64::NAN });
+        }
+        result
+    }
+
+    /// Weighted variance, ddof 0 returns biased estimate and otherwise
+    /// the bias corrected one
+    pub fn var(&self, values: &[f64], ddof: usize) -> Vec<f64> {
+        let min_periods = cmp::max(self.min_periods, 1);
+        let old_wt_factor = 1. - self.alpha;
+        let new_wt = if self.adjust { 1. } else { self.alpha };
+
+        let mut result: Vec<f64> = Vec::with_capacity(values.len());
+        let mut mean = f64::NAN;
+        let mut cov = 0.;
+        let mut sum_wt = 1.;
+        let mut sum_wt2 = 1.;
+        let mut old_wt = 1.;
+        let mut nobs = 0;
+
+        for &cur in values.iter() {
+            let is_obs = !cur.is_nan();
+            if is_obs {
+                nobs += 1;
+            }
+            if !mean.is_nan() {
+                if is_obs || !self.ignore_na {
+                    sum_wt *= old_wt_factor;
+                    sum_wt2 *= old_wt_factor * old_wt_factor;
+                    old_wt *= old_wt_factor;
+                    if is_obs {
+                        let old_mean = mean;
+                        if mean != cur {
+                            mean = (old_wt * old_mean + new_wt * cur) / (old_wt + new_wt);
+                        }
+                        cov = (old_wt * (cov + (old_mean - mean) * (old_mean - mean)) +
+                               new_wt * (cur - mean) * (cur - mean)) /
+                              (old_wt + new_wt);
+                        sum_wt += new_wt;
+                        sum_wt2 += new_wt * new_wt;
+                        old_wt += new_wt;
+                        if !self.adjust {
+                            sum_wt /= old_wt;
+                            sum_wt2 /= old_wt * old_wt;
+                            old_wt = 1.;
+                        }
+                    }
+                }
+            } else if is_obs {
+                mean = cur;
+            }
+
+            if nobs < min_periods {
+                result.push(f64::NAN);
+            } else if ddof == 0 {
+                result.push(cov);
+            } else {
+                let numerator = sum_wt * sum_wt;
+                let denominator = numerator - sum_wt2;
+                if denominator > 0. {
+                    result.push(numerator / denominator * cov);
+                } else {
+                    result.push(f64::NAN);
+                }
+            }
+        }
+        result
+    }
+
+    pub fn std(&self, values: &[f64], ddof: usize) -> Vec<f64> {
+        self.var(values, ddof).into_iter().map(|x| x.sqrt()).collect()
+    }
+}
+
+/// Apply window function to each group, and put results back to original locations
+pub fn apply_by_groups<W, F>(values: &[f64], groups: &[&Vec<usize>], func: F) -> Vec<W>
+    where W: Clone,
+          F: Fn(&[f64]) -> Vec<W>
+{
+    let mut result: Vec<Option<W>> = vec![None; values.len()];
+    for locs in groups.iter() {
+        let group_values: Vec<f64> = locs.iter().map(|&i| values[i]).collect();
+        for (&i, v) in locs.iter().zip(func(&group_values).into_iter()) {
+            result[i] = Some(v);
+        }
+    }
+    result.into_iter().map(|x| x.expect("location is not covered by any group")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::f64;
+    use super::{RollingWindow, ExpandingWindow, EwmWindow, apply_by_groups};
+
+    fn assert_vec_eq(res: Vec<f64>, exp: Vec<f64>) {
+        assert_eq!(res.len(), exp.len());
+        for (r, e) in res.iter().zip(exp.iter()) {
+            if e.is_nan() {
+                assert!(r.is_nan(), "{:?} != {:?}", res, exp);
+            } else {
+                assert!((r - e).abs() < 1e-10, "{:?} != {:?}", res, exp);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rolling_sum_mean() {
+        let values = vec![1., 2., 3., 4., 5.];
+        let w = RollingWindow::new(3, 3, false);
+        assert_vec_eq(w.sum(&values), vec![f64::NAN, f64::NAN, 6., 9., 12.]);
+        assert_vec_eq(w.mean(&values), vec![f64::NAN, f64::NAN, 2., 3., 4.]);
+
+        let w = RollingWindow::new(3, 1, false);
+        assert_vec_eq(w.sum(&values), vec![1., 3., 6., 9., 12.]);
+    }
+
+    #[test]
+    fn test_rolling_center() {
+        let values = vec![1., 2., 3., 4., 5.];
+        let w = RollingWindow::new(3, 1, true);
+        assert_vec_eq(w.sum(&values), vec![3., 6., 9., 12., 9.]);
+        assert_eq!(w.count(&values), vec![2, 3, 3, 3, 2]);
+
+        let w = RollingWindow::new(4, 4, true);
+        assert_vec_eq(w.sum(&values), vec![f64::NAN, f64::NAN, 10., 14., f64::NAN]);
+    }
+
+    #[test]
+    fn test_rolling_null() {
+        let values = vec![1., f64::NAN, 3., 4., f64::NAN, f64::NAN, 7.];
+        let w = RollingWindow::new(3, 2, false);
+        assert_vec_eq(w.sum(&values),
+                      vec![f64::NAN, f64::NAN, 4., 7., 7., f64::NAN, f64::NAN]);
+        assert_eq!(w.count(&values), vec![1, 1, 2, 2, 2, 1, 1]);
+        assert_vec_eq(w.min(&values),
+                      vec![f64::NAN, f64::NAN, 1., 3., 3., f64::NAN, f64::NAN]);
+        assert_vec_eq(w.max(&values),
+                      vec![f64::NAN, f64::NAN, 3., 4., 4., f64::NAN, f64::NAN]);
+    }
+
+    #[test]
+    fn test_rolling_var() {
+        let values = vec![1., 2., 4., 8., 16.];
+        let w = RollingWindow::new(3, 2, false);
+        assert_vec_eq(w.var(&values, 1),
+                      vec![f64::NAN, 0.5, 7. / 3., 28. / 3., 112. / 3.]);
+        assert_vec_eq(w.var(&values, 0),
+                      vec![f64::NAN, 0.25, 14. / 9., 56. / 9., 224. / 9.]);
+        assert_vec_eq(w.std(&values, 1),
+                      vec![f64::NAN, 0.5f64.sqrt(), (7. / 3f64).sqrt(), (28. / 3f64).sqrt(),
+                           (112. / 3f64).sqrt()]);
+    }
+
+    #[test]
+    fn test_rolling_minmax() {
+        let values = vec![5., 3., 4., 1., 2., 6.];
+        let w = RollingWindow::new(3, 1, false);
+        assert_vec_eq(w.min(&values), vec![5., 3., 3., 1., 1., 1.]);
+        assert_vec_eq(w.max(&values), vec![5., 5., 5., 4., 4., 6.]);
+    }
+
+    #[test]
+    fn test_expanding() {
+        let values = vec![3., f64::NAN, 1., 5., 2.];
+        let w = ExpandingWindow::new(2);
+        assert_vec_eq(w.mean(&values), vec![f64::NAN, f64::NAN, 2., 3., 2.75]);
+        assert_vec_eq(w.min(&values), vec![f64::NAN, f64::NAN, 1., 1., 1.]);
+        assert_vec_eq(w.max(&values), vec![f64::NAN, f64::NAN, 3., 5., 5.]);
+        assert_vec_eq(w.var(&values, 1), vec![f64::NAN, f64::NAN, 2., 4., 35. / 12.]);
+        assert_eq!(w.count(&values), vec![1, 1, 2, 3, 4]);
+        assert_eq!(w.sum(&vec![]), vec![]);
+    }
+
+    #[test]
+    fn test_ewm_mean() {
+        // pandas: Series([1, 2, 3, 4]).ewm(alpha=0.5).mean()
+        let values = vec![1., 2., 3., 4.];
+        let w = EwmWindow::new(0.5, true, false, 0);
+        assert_vec_eq(w.mean(&values),
+                      vec![1., 1.6666666666666667, 2.4285714285714284, 3.2666666666666666]);
+
+        // pandas: Series([1, 2, 3, 4]).ewm(alpha=0.5, adjust=False).mean()
+        let w = EwmWindow::new(0.5, false, false, 0);
+        assert_vec_eq(w.mean(&values), vec![1., 1.5, 2.25, 3.125]);
+    }
+
+    #[test]
+    fn test_ewm_mean_null() {
+        // pandas: Series([1, nan, 3]).ewm(alpha=0.5).mean()
+        let values = vec![1., f64::NAN, 3.];
+        let w = EwmWindow::new(0.5, true, false, 0);
+        assert_vec_eq(w.mean(&values), vec![1., 1., 2.6]);
+
+        // pandas: Series([1, nan, 3]).ewm(alpha=0.5, ignore_na=True).mean()
+        let w = EwmWindow::new(0.5, true, true, 0);
+        assert_vec_eq(w.mean(&values), vec![1., 1., 2.3333333333333335]);
+    }
+
+    #[test]
+    fn test_ewm_var() {
+        // pandas: Series([1, 2, 3, 4]).ewm(alpha=0.5).var()
+        let values = vec![1., 2., 3., 4.];
+        let w = EwmWindow::new(0.5, true, false, 0);
+        assert_vec_eq(w.var(&values, 1),
+                      vec![f64::NAN, 0.5, 0.9285714285714286, 1.3857142857142857]);
+        // pandas: Series([1, 2, 3, 4]).ewm(alpha=0.5).var(bias=True)
+        assert_vec_eq(w.var(&values, 0),
+                      vec![0., 0.2222222222222222, 0.5306122448979592, 0.8622222222222222]);
+    }
+
+    #[test]
+    fn test_apply_by_groups() {
+        let values = vec![1., 10., 2., 20., 3.];
+        let g1 = vec![0, 2, 4];
+        let g2 = vec![1, 3];
+        let w = ExpandingWindow::new(1);
+        let res = apply_by_groups(&values, &[&g1, &g2], |x| w.sum(x));
+        assert_vec_eq(res, vec![1., 10., 3., 30., 6.]);
+    }
+}
@@ -0,0 +1,152 @@
+use std::cmp;
+
+use super::{Datetime, civil_from_days, days_from_civil, div_floor, NANOS_PER_SECOND,
+            NANOS_PER_MINUTE, NANOS_PER_HOUR, NANOS_PER_DAY};
+
+/// Bin width used to resample time series
+///
+/// Each variant holds the number of units per bin. Bins are aligned to
+/// the epoch in local time, weekly bins start on Monday and monthly bins
+/// start on the first day of the month.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Frequency {
+    Second(u32),
+    Minute(u32),
+    Hour(u32),
+    Day(u32),
+    Week(u32),
+    Month(u32),
+}
+
+impl Frequency {
+    /// Bin width in nanoseconds, `None` for calendar frequencies
+    pub fn nanos(&self) -> Option<i64> {
+        match *self {
+            Frequency::Second(n) => Some(n as i64 * NANOS_PER_SECOND),
+            Frequency::Minute(n) => Some(n as i64 * NANOS_PER_MINUTE),
+            Frequency::Hour(n) => Some(n as i64 * NANOS_PER_HOUR),
+            Frequency::Day(n) => Some(n as i64 * NANOS_PER_DAY),
+            Frequency::Week(n) => Some(n as i64 * 7 * NANOS_PER_DAY),
+            Frequency::Month(_) => None,
+        }
+    }
+
+    /// Return the start of the bin which contains given timestamp
+    pub fn floor(&self, value: &Datetime) -> Datetime {
+        let local = value.local_nanos();
+        let start = match *self {
+            Frequency::Month(n) => {
+                assert!(n > 0, "frequency must be positive");
+                let (year, month, _) = civil_from_days(div_floor(local, NANOS_PER_DAY));
+                let months = div_floor(year * 12 + month - 1, n as i64) * n as i64;
+                days_from_civil(div_floor(months, 12), months - div_floor(months, 12) * 12 + 1, 1) *
+                NANOS_PER_DAY
+            }
+            Frequency::Week(_) => {
+                // 1969-12-29 is Monday
+                let width = self.nanos().unwrap();
+                assert!(width > 0, "frequency must be positive");
+                let origin = -3 * NANOS_PER_DAY;
+                div_floor(local - origin, width) * width + origin
+            }
+            _ => {
+                let width = self.nanos().unwrap();
+                assert!(width > 0, "frequency must be positive");
+                div_floor(local, width) * width
+            }
+        };
+        // convert back from local time
+        Datetime {
+            nanos: start + (value.nanos - local),
+            offset: value.offset,
+        }
+    }
+
+    /// Move timestamp by given number of periods
+    ///
+    /// Monthly shift keeps the time of day, and the day is clipped to the
+    /// end of the month.
+    pub fn shift(&self, value: &Datetime, periods: i64) -> Datetime {
+        match *self {
+            Frequency::Month(n) => {
+                let local = value.local_nanos();
+                let days = div_floor(local, NANOS_PER_DAY);
+                let time = local - days * NANOS_PER_DAY;
+                let (year, month, day) = civil_from_days(days);
+
+                let months = year * 12 + month - 1 + periods * n as i64;
+                let new_year = div_floor(months, 12);
+                let new_month = months - new_year * 12 + 1;
+                let last_day = days_from_civil(new_year + new_month / 12, new_month % 12 + 1, 1) -
+                               days_from_civil(new_year, new_month, 1);
+                let new_days = days_from_civil(new_year, new_month, cmp::min(day, last_day));
+                value.add_nanos(new_days * NANOS_PER_DAY + time - local)
+            }
+            _ => value.add_nanos(periods * self.nanos().unwrap()),
+        }
+    }
+
+    /// Return bin labels for each timestamp
+    pub fn bins(&self, values: &[Datetime]) -> Vec<Datetime> {
+        values.iter().map(|x| self.floor(x)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::Frequency;
+    use super::super::Datetime;
+
+    #[test]
+    fn test_floor_duration() {
+        let dt = Datetime::from_ymd_hms(2017, 3, 4, 10, 47, 13);
+        assert_eq!(Frequency::Second(10).floor(&dt),
+                   Datetime::from_ymd_hms(2017, 3, 4, 10, 47, 10));
+        assert_eq!(Frequency::Minute(15).floor(&dt),
+                   Datetime::from_ymd_hms(2017, 3, 4, 10, 45, 0));
+        assert_eq!(Frequency::Hour(1).floor(&dt),
+                   Datetime::from_ymd_hms(2017, 3, 4, 10, 0, 0));
+        assert_eq!(Frequency::Day(1).floor(&dt), Datetime::from_ymd(2017, 3, 4));
+    }
+
+    #[test]
+    fn test_floor_calendar() {
+        // Saturday
+        let dt = Datetime::from_ymd_hms(2017, 3, 4, 10, 47, 13);
+        assert_eq!(Frequency::Week(1).floor(&dt), Datetime::from_ymd(2017, 2, 27));
+        assert_eq!(Frequency::Month(1).floor(&dt), Datetime::from_ymd(2017, 3, 1));
+        assert_eq!(Frequency::Month(3).floor(&dt), Datetime::from_ymd(2017, 1, 1));
+
+        let dt = Datetime::from_ymd(1969, 11, 15);
+        assert_eq!(Frequency::Month(1).floor(&dt), Datetime::from_ymd(1969, 11, 1));
+    }
+
+    #[test]
+    fn test_shift() {
+        let dt = Datetime::from_ymd_hms(2017, 1, 31, 10, 0, 0);
+        assert_eq!(Frequency::Hour(2).shift(&dt, 3),
+                   Datetime::from_ymd_hms(2017, 1, 31, 16, 0, 0));
+        assert_eq!(Frequency::Day(1).shift(&dt, -31),
+                   Datetime::from_ymd_hms(2016, 12, 31, 10, 0, 0));
+        assert_eq!(Frequency::Month(1).shift(&dt, 1),
+                   Datetime::from_ymd_hms(2017, 2, 28, 10, 0, 0));
+        assert_eq!(Frequency::Month(1).shift(&dt, 13),
+                   Datetime::from_ymd_hms(2018, 2, 28, 10, 0, 0));
+        assert_eq!(Frequency::Month(3).shift(&dt, -1),
+                   Datetime::from_ymd_hms(2016, 10, 31, 10, 0, 0));
+        assert_eq!(Frequency::Month(1).shift(&Datetime::from_ymd(2016, 1, 30), 1),
+                   Datetime::from_ymd(2016, 2, 29));
+    }
+
+    #[test]
+    fn test_floor_offset() {
+        // 2017-03-04 01:30 at +09:00
+        let dt = Datetime::with_offset(Datetime::from_ymd_hms(2017, 3, 3, 16, 30, 0).nanos,
+                                       9 * 3600);
+        let res = Frequency::Day(1).floor(&dt);
+        assert_eq!(res, Datetime::from_ymd_hms(2017, 3, 3, 15, 0, 0));
+        assert_eq!(res.offset, Some(9 * 3600));
+        assert_eq!(res.day(), 4);
+    }
+}
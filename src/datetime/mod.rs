@@ -0,0 +1,319 @@
+//! Timestamp type stored as epoch nanoseconds
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use num::ToPrimitive;
+
+mod freq;
+mod parse;
+
+pub use self::freq::Frequency;
+pub use self::parse::DatetimeParseError;
+
+pub const NANOS_PER_SECOND: i64 = 1_000_000_000;
+pub const NANOS_PER_MINUTE: i64 = 60 * NANOS_PER_SECOND;
+pub const NANOS_PER_HOUR: i64 = 60 * NANOS_PER_MINUTE;
+pub const NANOS_PER_DAY: i64 = 24 * NANOS_PER_HOUR;
+
+/// Point in time, stored as nanoseconds since 1970-01-01T00:00:00Z
+///
+/// `offset` is the UTC offset (in seconds) the timestamp was observed in.
+/// It only affects field accessors and formatting; equality, hashing and
+/// ordering compare the instant only.
+#[derive(Clone, Copy)]
+pub struct Datetime {
+    pub nanos: i64,
+    pub offset: Option<i32>,
+}
+
+/// /////////////////////////////////////////////////////////////////////////////
+/// Constructor
+/// /////////////////////////////////////////////////////////////////////////////
+
+impl Datetime {
+    pub fn new(nanos: i64) -> Self {
+        Datetime {
+            nanos: nanos,
+            offset: None,
+        }
+    }
+
+    pub fn with_offset(nanos: i64, offset: i32) -> Self {
+        Datetime {
+            nanos: nanos,
+            offset: Some(offset),
+        }
+    }
+
+    /// Create from calendar fields, regarded as UTC
+    pub fn from_ymd_hms(year: i32, month: u32, day: u32, hour: u32, minute: u32, second: u32)
+                        -> Self {
+        let days = days_from_civil(year as i64, month as i64, day as i64);
+        let nanos = days * NANOS_PER_DAY + hour as i64 * NANOS_PER_HOUR +
+                    minute as i64 * NANOS_PER_MINUTE +
+                    second as i64 * NANOS_PER_SECOND;
+        Datetime::new(nanos)
+    }
+
+    pub fn from_ymd(year: i32, month: u32, day: u32) -> Self {
+        Datetime::from_ymd_hms(year, month, day, 0, 0, 0)
+    }
+
+    /// Parse string using strftime-like format
+    ///
+    /// Supported directives are `%Y`, `%m`, `%d`, `%H`, `%M`, `%S`, `%f`
+    /// (fraction of second), `%b` (abbreviated month name), `%z` (`Z`,
+    /// `+hh:mm` or `+hhmm`) and `%%`.
+    pub fn parse(value: &str, format: &str) -> Result<Self, DatetimeParseError> {
+        parse::parse(value, format)
+    }
+
+    /// Return new instance shifted by given nanoseconds, keeping offset
+    pub fn add_nanos(&self, nanos: i64) -> Self {
+        Datetime {
+            nanos: self.nanos + nanos,
+            offset: self.offset,
+        }
+    }
+}
+
+/// /////////////////////////////////////////////////////////////////////////////
+/// Fields
+/// /////////////////////////////////////////////////////////////////////////////
+
+impl Datetime {
+    /// Nanoseconds since epoch in local time (applying offset)
+    pub fn local_nanos(&self) -> i64 {
+        match self.offset {
+            Some(offset) => self.nanos + offset as i64 * NANOS_PER_SECOND,
+            None => self.nanos,
+        }
+    }
+
+    /// Days since epoch in local time
+    fn local_days(&self) -> i64 {
+        div_floor(self.local_nanos(), NANOS_PER_DAY)
+    }
+
+    /// Nanoseconds elapsed since local midnight
+    fn local_time(&self) -> i64 {
+        mod_floor(self.local_nanos(), NANOS_PER_DAY)
+    }
+
+    pub fn year(&self) -> i32 {
+        civil_from_days(self.local_days()).0 as i32
+    }
+
+    pub fn month(&self) -> u32 {
+        civil_from_days(self.local_days()).1 as u32
+    }
+
+    pub fn day(&self) -> u32 {
+        civil_from_days(self.local_days()).2 as u32
+    }
+
+    pub fn hour(&self) -> u32 {
+        (self.local_time() / NANOS_PER_HOUR) as u32
+    }
+
+    pub fn minute(&self) -> u32 {
+        (self.local_time() % NANOS_PER_HOUR / NANOS_PER_MINUTE) as u32
+    }
+
+    pub fn second(&self) -> u32 {
+        (self.local_time() % NANOS_PER_MINUTE / NANOS_PER_SECOND) as u32
+    }
+
+    pub fn nanosecond(&self) -> u32 {
+        (self.local_time() % NANOS_PER_SECOND) as u32
+    }
+
+    /// Day of the week, Monday is 0 and Sunday is 6
+    pub fn weekday(&self) -> u32 {
+        // 1970-01-01 is Thursday
+        mod_floor(self.local_days() + 3, 7) as u32
+    }
+}
+
+/// /////////////////////////////////////////////////////////////////////////////
+/// Calendar
+/// /////////////////////////////////////////////////////////////////////////////
+
+fn div_floor(a: i64, b: i64) -> i64 {
+    let d = a / b;
+    if (a % b != 0) && ((a < 0) != (b < 0)) {
+        d - 1
+    } else {
+        d
+    }
+}
+
+fn mod_floor(a: i64, b: i64) -> i64 {
+    a - div_floor(a, b) * b
+}
+
+/// Days since epoch from proleptic Gregorian calendar date
+pub fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = div_floor(y, 400);
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+/// Proleptic Gregorian calendar date (year, month, day) from days since epoch
+pub fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719468;
+    let era = div_floor(z, 146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// /////////////////////////////////////////////////////////////////////////////
+/// Eq, Ord, Hash
+/// /////////////////////////////////////////////////////////////////////////////
+
+impl PartialEq for Datetime {
+    fn eq(&self, other: &Datetime) -> bool {
+        self.nanos == other.nanos
+    }
+}
+
+impl Eq for Datetime {}
+
+impl PartialOrd for Datetime {
+    fn partial_cmp(&self, other: &Datetime) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Datetime {
+    fn cmp(&self, other: &Datetime) -> Ordering {
+        self.nanos.cmp(&other.nanos)
+    }
+}
+
+impl Hash for Datetime {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.nanos.hash(state);
+    }
+}
+
+/// /////////////////////////////////////////////////////////////////////////////
+/// Conversion
+/// /////////////////////////////////////////////////////////////////////////////
+
+/// Convert to epoch nanoseconds, used as spacing of time index
+impl ToPrimitive for Datetime {
+    fn to_i64(&self) -> Option<i64> {
+        Some(self.nanos)
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.nanos.to_u64()
+    }
+}
+
+/// /////////////////////////////////////////////////////////////////////////////
+/// Formatting
+/// /////////////////////////////////////////////////////////////////////////////
+
+impl fmt::Display for Datetime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(write!(f,
+                    "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+                    self.year(),
+                    self.month(),
+                    self.day(),
+                    self.hour(),
+                    self.minute(),
+                    self.second()));
+        let nanos = self.nanosecond();
+        if nanos != 0 {
+            try!(write!(f, ".{:09}", nanos));
+        }
+        if let Some(offset) = self.offset {
+            let sign = if offset < 0 { '-' } else { '+' };
+            let offset = offset.abs();
+            try!(write!(f, "{}{:02}:{:02}", sign, offset / 3600, offset % 3600 / 60));
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Datetime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Datetime({})", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{Datetime, days_from_civil, civil_from_days};
+
+    #[test]
+    fn test_civil_roundtrip() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2000, 3, 1), 11017);
+        assert_eq!(days_from_civil(1969, 12, 31), -1);
+
+        for days in -800000..800000 {
+            let (y, m, d) = civil_from_days(days);
+            assert_eq!(days_from_civil(y, m, d), days);
+        }
+    }
+
+    #[test]
+    fn test_fields() {
+        let dt = Datetime::from_ymd_hms(2017, 2, 28, 13, 45, 10);
+        assert_eq!(dt.year(), 2017);
+        assert_eq!(dt.month(), 2);
+        assert_eq!(dt.day(), 28);
+        assert_eq!(dt.hour(), 13);
+        assert_eq!(dt.minute(), 45);
+        assert_eq!(dt.second(), 10);
+        // Tuesday
+        assert_eq!(dt.weekday(), 1);
+    }
+
+    #[test]
+    fn test_fields_before_epoch() {
+        let dt = Datetime::from_ymd_hms(1969, 12, 31, 23, 59, 59);
+        assert_eq!(dt.nanos, -1_000_000_000);
+        assert_eq!(dt.year(), 1969);
+        assert_eq!(dt.month(), 12);
+        assert_eq!(dt.day(), 31);
+        assert_eq!(dt.hour(), 23);
+        // Wednesday
+        assert_eq!(dt.weekday(), 2);
+    }
+
+    #[test]
+    fn test_offset() {
+        let utc = Datetime::from_ymd_hms(2017, 1, 1, 23, 0, 0);
+        let local = Datetime::with_offset(utc.nanos, 9 * 3600);
+        assert_eq!(utc, local);
+        assert_eq!(local.day(), 2);
+        assert_eq!(local.hour(), 8);
+        assert_eq!(local.to_string(), "2017-01-02 08:00:00+09:00");
+    }
+
+    #[test]
+    fn test_format() {
+        let dt = Datetime::from_ymd_hms(2017, 1, 2, 3, 4, 5);
+        assert_eq!(dt.to_string(), "2017-01-02 03:04:05");
+        assert_eq!(dt.add_nanos(1500).to_string(),
+                   "2017-01-02 03:04:05.000001500");
+    }
+}
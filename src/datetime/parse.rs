@@ -0,0 +1,316 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use super::{Datetime, days_from_civil, NANOS_PER_SECOND, NANOS_PER_MINUTE, NANOS_PER_HOUR,
+            NANOS_PER_DAY};
+
+const MONTH_NAMES: [&'static str; 12] = ["jan", "feb", "mar", "apr", "may", "jun", "jul",
+                                         "aug", "sep", "oct", "nov", "dec"];
+
+/// Error raised when a string doesn't match the datetime format
+#[derive(Clone, Debug, PartialEq)]
+pub struct DatetimeParseError {
+    pub value: String,
+    pub format: String,
+    reason: &'static str,
+}
+
+impl DatetimeParseError {
+    pub fn new(value: &str, format: &str, reason: &'static str) -> Self {
+        DatetimeParseError {
+            value: value.to_string(),
+            format: format.to_string(),
+            reason: reason,
+        }
+    }
+}
+
+impl fmt::Display for DatetimeParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "unable to parse '{}' with format '{}': {}",
+               self.value,
+               self.format,
+               self.reason)
+    }
+}
+
+impl Error for DatetimeParseError {
+    fn description(&self) -> &str {
+        self.reason
+    }
+}
+
+/// ISO 8601 like formats tried by `FromStr`, most specific first
+const ISO_FORMATS: [&'static str; 10] = ["%Y-%m-%dT%H:%M:%S.%f%z",
+                                         "%Y-%m-%d %H:%M:%S.%f%z",
+                                         "%Y-%m-%dT%H:%M:%S%z",
+                                         "%Y-%m-%d %H:%M:%S%z",
+                                         "%Y-%m-%dT%H:%M:%S.%f",
+                                         "%Y-%m-%d %H:%M:%S.%f",
+                                         "%Y-%m-%dT%H:%M:%S",
+                                         "%Y-%m-%d %H:%M:%S",
+                                         "%Y-%m-%d %H:%M",
+                                         "%Y-%m-%d"];
+
+impl FromStr for Datetime {
+    type Err = DatetimeParseError;
+
+    /// Parse ISO 8601 like string, either date only or date and time
+    /// separated by `T` or space, with optional fraction and UTC offset
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        for format in ISO_FORMATS.iter() {
+            if let Ok(dt) = parse(value, format) {
+                return Ok(dt);
+            }
+        }
+        Err(DatetimeParseError::new(value, "ISO 8601", "doesn't match any format"))
+    }
+}
+
+/// Consume up to `max` ascii digits, returning the value and the number of digits
+fn take_digits(chars: &[char], pos: &mut usize, max: usize) -> Option<(i64, usize)> {
+    let mut value: i64 = 0;
+    let mut n = 0;
+    while n < max && *pos < chars.len() {
+        match chars[*pos].to_digit(10) {
+            Some(d) => {
+                value = value * 10 + d as i64;
+                *pos += 1;
+                n += 1;
+            }
+            None => break,
+        }
+    }
+    if n == 0 { None } else { Some((value, n)) }
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+pub fn parse(value: &str, format: &str) -> Result<Datetime, DatetimeParseError> {
+    let err = |reason| Err(DatetimeParseError::new(value, format, reason));
+
+    let chars: Vec<char> = value.chars().collect();
+    let mut fmt_chars = format.chars();
+    let mut pos = 0;
+
+    let (mut year, mut month, mut day) = (1970, 1, 1);
+    let (mut hour, mut minute, mut second, mut fraction) = (0, 0, 0, 0);
+    let mut offset: Option<i32> = None;
+
+    while let Some(c) = fmt_chars.next() {
+        if c != '%' {
+            if pos < chars.len() && chars[pos] == c {
+                pos += 1;
+                continue;
+            }
+            return err("literal doesn't match");
+        }
+        let directive = match fmt_chars.next() {
+            Some(d) => d,
+            None => return err("format ends with '%'"),
+        };
+        match directive {
+            'Y' => {
+                let negative = pos < chars.len() && chars[pos] == '-';
+                if negative {
+                    pos += 1;
+                }
+                year = match take_digits(&chars, &mut pos, 4) {
+                    Some((v, 4)) => if negative { -v } else { v },
+                    _ => return err("invalid year"),
+                };
+            }
+            'm' => {
+                month = match take_digits(&chars, &mut pos, 2) {
+                    Some((v, _)) if v >= 1 && v <= 12 => v,
+                    _ => return err("invalid month"),
+                };
+            }
+            'd' => {
+                day = match take_digits(&chars, &mut pos, 2) {
+                    Some((v, _)) if v >= 1 && v <= 31 => v,
+                    _ => return err("invalid day"),
+                };
+            }
+            'H' => {
+                hour = match take_digits(&chars, &mut pos, 2) {
+                    Some((v, _)) if v <= 23 => v,
+                    _ => return err("invalid hour"),
+                };
+            }
+            'M' => {
+                minute = match take_digits(&chars, &mut pos, 2) {
+                    Some((v, _)) if v <= 59 => v,
+                    _ => return err("invalid minute"),
+                };
+            }
+            'S' => {
+                second = match take_digits(&chars, &mut pos, 2) {
+                    Some((v, _)) if v <= 60 => v,
+                    _ => return err("invalid second"),
+                };
+            }
+            'f' => {
+                fraction = match take_digits(&chars, &mut pos, 9) {
+                    Some((v, n)) => v * 10i64.pow(9 - n as u32),
+                    _ => return err("invalid fraction"),
+                };
+            }
+            'b' => {
+                if pos + 3 > chars.len() {
+                    return err("invalid month name");
+                }
+                let name: String = chars[pos..pos + 3].iter().collect::<String>().to_lowercase();
+                month = match MONTH_NAMES.iter().position(|&m| m == name) {
+                    Some(i) => i as i64 + 1,
+                    None => return err("invalid month name"),
+                };
+                pos += 3;
+            }
+            'z' => {
+                if pos < chars.len() && chars[pos] == 'Z' {
+                    pos += 1;
+                    offset = Some(0);
+                    continue;
+                }
+                let sign = match chars.get(pos) {
+                    Some(&'+') => 1,
+                    Some(&'-') => -1,
+                    _ => return err("invalid offset"),
+                };
+                pos += 1;
+                let hours = match take_digits(&chars, &mut pos, 2) {
+                    Some((v, 2)) => v,
+                    _ => return err("invalid offset"),
+                };
+                if pos < chars.len() && chars[pos] == ':' {
+                    pos += 1;
+                }
+                let minutes = match take_digits(&chars, &mut pos, 2) {
+                    Some((v, 2)) => v,
+                    _ => return err("invalid offset"),
+                };
+                offset = Some(sign * (hours * 3600 + minutes * 60) as i32);
+            }
+            '%' => {
+                if pos < chars.len() && chars[pos] == '%' {
+                    pos += 1;
+                } else {
+                    return err("literal doesn't match");
+                }
+            }
+            _ => return err("unsupported directive"),
+        }
+    }
+    if pos != chars.len() {
+        return err("trailing characters");
+    }
+    // checked after all directives, as day may precede month and year
+    if day > days_in_month(year, month) {
+        return err("invalid day");
+    }
+
+    let days = days_from_civil(year, month, day);
+    let local = days * NANOS_PER_DAY + hour * NANOS_PER_HOUR + minute * NANOS_PER_MINUTE +
+                second * NANOS_PER_SECOND + fraction;
+    match offset {
+        Some(offset) => {
+            Ok(Datetime::with_offset(local - offset as i64 * NANOS_PER_SECOND, offset))
+        }
+        None => Ok(Datetime::new(local)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::super::Datetime;
+
+    #[test]
+    fn test_parse_date() {
+        let dt = Datetime::parse("2017-03-04", "%Y-%m-%d").unwrap();
+        assert_eq!(dt, Datetime::from_ymd(2017, 3, 4));
+    }
+
+    #[test]
+    fn test_parse_datetime() {
+        let dt = Datetime::parse("2017/03/04 05:06:07.25", "%Y/%m/%d %H:%M:%S.%f").unwrap();
+        let exp = Datetime::from_ymd_hms(2017, 3, 4, 5, 6, 7).add_nanos(250_000_000);
+        assert_eq!(dt, exp);
+    }
+
+    #[test]
+    fn test_parse_month_name() {
+        let dt = Datetime::parse("04 Mar 2017", "%d %b %Y").unwrap();
+        assert_eq!(dt, Datetime::from_ymd(2017, 3, 4));
+    }
+
+    #[test]
+    fn test_parse_offset() {
+        let dt = Datetime::parse("2017-03-04T09:00:00+09:00", "%Y-%m-%dT%H:%M:%S%z").unwrap();
+        assert_eq!(dt, Datetime::from_ymd(2017, 3, 4));
+        assert_eq!(dt.offset, Some(9 * 3600));
+        assert_eq!(dt.hour(), 9);
+
+        let dt = Datetime::parse("2017-03-04T00:00:00Z", "%Y-%m-%dT%H:%M:%S%z").unwrap();
+        assert_eq!(dt, Datetime::from_ymd(2017, 3, 4));
+        assert_eq!(dt.offset, Some(0));
+
+        let dt = Datetime::parse("2017-03-03 19:30 -0430", "%Y-%m-%d %H:%M %z").unwrap();
+        assert_eq!(dt, Datetime::from_ymd(2017, 3, 4));
+    }
+
+    #[test]
+    fn test_from_str() {
+        let dt: Datetime = "2017-03-04".parse().unwrap();
+        assert_eq!(dt, Datetime::from_ymd(2017, 3, 4));
+
+        let dt: Datetime = "2017-03-04T05:06:07".parse().unwrap();
+        assert_eq!(dt, Datetime::from_ymd_hms(2017, 3, 4, 5, 6, 7));
+
+        let dt: Datetime = "2017-03-04 05:06".parse().unwrap();
+        assert_eq!(dt, Datetime::from_ymd_hms(2017, 3, 4, 5, 6, 0));
+
+        // round-trip with Display
+        let exp = Datetime::with_offset(Datetime::from_ymd(2017, 3, 4).nanos + 123, 9 * 3600);
+        let dt: Datetime = exp.to_string().parse().unwrap();
+        assert_eq!(dt, exp);
+        assert_eq!(dt.offset, Some(9 * 3600));
+
+        let e = "2017/03/04".parse::<Datetime>().unwrap_err();
+        assert_eq!(e.to_string(),
+                   "unable to parse '2017/03/04' with format 'ISO 8601': doesn't match any format");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(Datetime::parse("2017-13-04", "%Y-%m-%d").is_err());
+        assert!(Datetime::parse("2017-03-04 10", "%Y-%m-%d").is_err());
+        assert!(Datetime::parse("17-03-04", "%Y-%m-%d").is_err());
+        assert!(Datetime::parse("2017-03-04", "%Y-%m-%d %Q").is_err());
+
+        let e = Datetime::parse("2017-02-29", "%Y-%m-%d").unwrap_err();
+        assert_eq!(e.to_string(),
+                   "unable to parse '2017-02-29' with format '%Y-%m-%d': invalid day");
+        assert!(Datetime::parse("2017-04-31", "%Y-%m-%d").is_err());
+        assert!(Datetime::parse("31 Apr 2017", "%d %b %Y").is_err());
+        assert!(Datetime::parse("1900-02-29", "%Y-%m-%d").is_err());
+        assert_eq!(Datetime::parse("2016-02-29", "%Y-%m-%d").unwrap(),
+                   Datetime::from_ymd(2016, 2, 29));
+        assert_eq!(Datetime::parse("2000-02-29", "%Y-%m-%d").unwrap(),
+                   Datetime::from_ymd(2000, 2, 29));
+
+        let e = Datetime::parse("2017/03/04", "%Y-%m-%d").unwrap_err();
+        assert_eq!(e.to_string(),
+                   "unable to parse '2017/03/04' with format '%Y-%m-%d': literal doesn't match");
+    }
+}
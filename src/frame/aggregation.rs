@@ -1,16 +1,19 @@
 use std::borrow::Cow;
 use std::hash::Hash;
 
-use nullvec::prelude::{Array, Scalar, Nullable, NullVec};
-use nullvec::prelude::BasicAggregation as NBasicAggregation;
-use nullvec::prelude::NumericAggregation as NNumericAggregation;
-use nullvec::prelude::ComparisonAggregation as NComparisonAggregation;
-
+use nullvec::prelude::{Array, Scalar};
 
 use super::DataFrame;
+use algos::array::{to_f64_vec, to_valid_f64_vec, array_count, array_sum, array_prod,
+                   array_min, array_max};
+use algos::computation::Aggregation;
+use algos::parallel;
+use algos::quantile::{quantiles, describe, describe_percentiles, describe_labels, percentile_labels,
+                      QuantileInterpolation};
 use indexer::Indexer;
 use series::Series;
-use traits::{BasicAggregation, NumericAggregation, ComparisonAggregation, Description};
+use traits::{BasicAggregation, NumericAggregation, HigherOrderAggregation, ComparisonAggregation,
+             Description};
 
 impl<'v, 'i, 'c, I, C> BasicAggregation<'c> for DataFrame<'v, 'i, 'c, I, C>
     where I: Clone + Eq + Hash,
@@ -20,16 +23,17 @@ impl<'v, 'i, 'c, I, C> BasicAggregation<'c> for DataFrame<'v, 'i, 'c, I, C>
     type Kept = Series<'c, 'c, Scalar, C>;
     type Counted = Series<'c, 'c, usize, C>;
 
+    /// Sum non-null values of each numeric column keeping its dtype, null if
+    /// all values are null or integer sum overflows
     fn sum(&'c self) -> Self::Kept {
         let ndf = self.get_numeric_data();
-        // ToDo: FIXME
-        let new_values: Vec<Scalar> = ndf.values.iter().map(|x| x.sum()).collect();
+        let new_values: Vec<Scalar> = parallel::map(&ndf.values, |x| array_sum(x));
         Series::from_cow(Cow::Owned(new_values), ndf.columns)
     }
 
     fn count(&'c self) -> Self::Counted {
         let ndf = self.get_numeric_data();
-        let new_values: Vec<usize> = ndf.values.iter().map(|x| x.count()).collect();
+        let new_values: Vec<usize> = parallel::map(&ndf.values, |x| array_count(x));
         Series::from_cow(Cow::Owned(new_values), ndf.columns)
     }
 }
@@ -42,44 +46,77 @@ impl<'v, 'i, 'c, I, C> NumericAggregation<'c> for DataFrame<'v, 'i, 'c, I, C>
     type Coerced = Series<'c, 'c, f64, C>;
 
     fn mean(&'c self) -> Self::Coerced {
-        let ndf = self.get_numeric_data();
-        // ToDo: FIXME
-        let new_values_tmp: NullVec<f64> = ndf.values.iter().map(|x| x.mean()).collect();
-        let new_values: Vec<f64> = new_values_tmp.into();
-        Series::from_cow(Cow::Owned(new_values), ndf.columns)
+        self.aggregate_f64(Aggregation::vec_mean)
     }
 
     fn var(&'c self) -> Self::Coerced {
-        let ndf = self.get_numeric_data();
-        // ToDo: FIXME
-        let new_values_tmp: NullVec<f64> = ndf.values.iter().map(|x| x.var()).collect();
-        let new_values: Vec<f64> = new_values_tmp.into();
-        Series::from_cow(Cow::Owned(new_values), ndf.columns)
+        self.aggregate_f64(Aggregation::vec_var)
     }
 
     fn unbiased_var(&'c self) -> Self::Coerced {
-        let ndf = self.get_numeric_data();
-        // ToDo: FIXME
-        let new_values_tmp: NullVec<f64> = ndf.values.iter().map(|x| x.unbiased_var()).collect();
-        let new_values: Vec<f64> = new_values_tmp.into();
-        Series::from_cow(Cow::Owned(new_values), ndf.columns)
+        self.aggregate_f64(Aggregation::vec_unbiased_var)
     }
 
     fn std(&'c self) -> Self::Coerced {
+        self.aggregate_f64(Aggregation::vec_std)
+    }
+
+    fn unbiased_std(&'c self) -> Self::Coerced {
+        self.aggregate_f64(Aggregation::vec_unbiased_std)
+    }
+}
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+    where I: Clone + Eq + Hash,
+          C: 'c + Clone + Eq + Hash
+{
+    /// Apply passed function to non-null values of each numeric column
+    fn aggregate_f64(&'c self, func: fn(&Vec<f64>) -> f64) -> Series<'c, 'c, f64, C> {
         let ndf = self.get_numeric_data();
-        // ToDo: FIXME
-        let new_values_tmp: NullVec<f64> = ndf.values.iter().map(|x| x.std()).collect();
-        let new_values: Vec<f64> = new_values_tmp.into();
+        let new_values: Vec<f64> = parallel::map(&ndf.values, |x| func(&to_valid_f64_vec(x)));
         Series::from_cow(Cow::Owned(new_values), ndf.columns)
     }
+}
 
-    fn unbiased_std(&'c self) -> Self::Coerced {
+impl<'v, 'i, 'c, I, C> HigherOrderAggregation<'c> for DataFrame<'v, 'i, 'c, I, C>
+    where I: Clone + Eq + Hash,
+          C: 'c + Clone + Eq + Hash
+{
+    // ToDo: use 'n lifetime for values
+    type Kept = Series<'c, 'c, Scalar, C>;
+    type Coerced = Series<'c, 'c, f64, C>;
+
+    /// Product of non-null values of each numeric column keeping its dtype,
+    /// null if integer product overflows
+    fn prod(&'c self) -> Self::Kept {
         let ndf = self.get_numeric_data();
-        // ToDo: FIXME
-        let new_values_tmp: NullVec<f64> = ndf.values.iter().map(|x| x.unbiased_std()).collect();
-        let new_values: Vec<f64> = new_values_tmp.into();
+        let new_values: Vec<Scalar> = parallel::map(&ndf.values, |x| array_prod(x));
         Series::from_cow(Cow::Owned(new_values), ndf.columns)
     }
+
+    fn skew(&'c self) -> Self::Coerced {
+        self.aggregate_f64(Aggregation::vec_skew)
+    }
+
+    fn unbiased_skew(&'c self) -> Self::Coerced {
+        self.aggregate_f64(Aggregation::vec_unbiased_skew)
+    }
+
+    fn kurt(&'c self) -> Self::Coerced {
+        self.aggregate_f64(Aggregation::vec_kurt)
+    }
+
+    fn unbiased_kurt(&'c self) -> Self::Coerced {
+        self.aggregate_f64(Aggregation::vec_unbiased_kurt)
+    }
+
+    fn sem(&'c self) -> Self::Coerced {
+        self.aggregate_f64(Aggregation::vec_sem)
+    }
+
+    fn unbiased_sem(&'c self) -> Self::Coerced {
+        self.aggregate_f64(Aggregation::vec_unbiased_sem)
+    }
 }
 
 impl<'v, 'i, 'c, I, C> ComparisonAggregation<'c> for DataFrame<'v, 'i, 'c, I, C>
@@ -91,15 +128,13 @@ impl<'v, 'i, 'c, I, C> ComparisonAggregation<'c> for DataFrame<'v, 'i, 'c, I, C>
 
     fn min(&'c self) -> Self::Kept {
         let ndf = self.get_numeric_data();
-        // ToDo: FIXME
-        let new_values: Vec<Scalar> = ndf.values.iter().map(|x| x.min()).collect();
+        let new_values: Vec<Scalar> = parallel::map(&ndf.values, |x| array_min(x));
         Series::from_cow(Cow::Owned(new_values), ndf.columns)
     }
 
     fn max(&'c self) -> Self::Kept {
         let ndf = self.get_numeric_data();
-        // ToDo: FIXME
-        let new_values: Vec<Scalar> = ndf.values.iter().map(|x| x.max()).collect();
+        let new_values: Vec<Scalar> = parallel::map(&ndf.values, |x| array_max(x));
         Series::from_cow(Cow::Owned(new_values), ndf.columns)
     }
 }
@@ -108,27 +143,59 @@ impl<'v, 'i, 'c, I, C> Description<'c> for DataFrame<'v, 'i, 'c, I, C>
     where I: Clone + Eq + Hash,
           C: Clone + Eq + Hash
 {
-    type Described = DataFrame<'v, 'c, 'c, &'c str, C>;
+    type Described = DataFrame<'v, 'c, 'c, String, C>;
 
-    fn describe(&'c self) -> Self::Described {
+    fn describe_percentiles(&'c self, percentiles: &[f64]) -> Self::Described {
         let ndf = self.get_numeric_data();
 
-        let new_index: Vec<&str> = vec!["count", "mean", "std", "min", "max"];
+        let percentiles = describe_percentiles(percentiles);
+        let new_index = describe_labels(&percentiles);
 
-        let describe = |x: &Array| {
-            let values: Vec<Nullable<f64>> = vec![Nullable::new(x.count() as f64),
-                                                  x.mean(),
-                                                  x.std(),
-                                                  x.min().as_f64(),
-                                                  x.max().as_f64()];
-            let nvalues: NullVec<f64> = values.into();
-            Array::Float64Array(nvalues)
+        let describe_column = |x: &Array| {
+            let values: Vec<f64> = describe(&to_f64_vec(x), &percentiles);
+            Array::from(values)
         };
 
-        let new_values: Vec<Cow<Array>> = ndf.values
-            .iter()
-            .map(|ref x| Cow::Owned(describe(x)))
-            .collect();
+        let new_values: Vec<Cow<Array>> =
+            parallel::map(&ndf.values, |x| Cow::Owned(describe_column(x)));
         DataFrame::from_cow(new_values, Cow::Owned(Indexer::new(new_index)), ndf.columns)
     }
 }
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+    where I: Clone + Eq + Hash,
+          C: 'c + Clone + Eq + Hash
+{
+    /// Return the median of each numeric column
+    pub fn median(&'c self) -> Series<'c, 'c, f64, C> {
+        self.quantile(0.5, QuantileInterpolation::Linear)
+    }
+
+    /// Return the value at quantile `q` (between 0 and 1) of each numeric column,
+    /// NaN if `q` is out of range
+    pub fn quantile(&'c self,
+                    q: f64,
+                    interpolation: QuantileInterpolation)
+                    -> Series<'c, 'c, f64, C> {
+        let ndf = self.get_numeric_data();
+        let new_values: Vec<f64> = parallel::map(&ndf.values, |x| {
+            quantiles(&to_f64_vec(x), &[q], interpolation)[0]
+        });
+        Series::from_cow(Cow::Owned(new_values), ndf.columns)
+    }
+
+    /// Return values at each quantile of each numeric column, labeled as
+    /// percentile like "25%"
+    pub fn quantiles(&'c self,
+                     qs: &[f64],
+                     interpolation: QuantileInterpolation)
+                     -> DataFrame<'c, 'c, 'c, String, C> {
+        let ndf = self.get_numeric_data();
+        let new_values: Vec<Cow<Array>> = parallel::map(&ndf.values, |x| {
+            Cow::Owned(quantiles(&to_f64_vec(x), qs, interpolation).into())
+        });
+        DataFrame::from_cow(new_values,
+                            Cow::Owned(Indexer::new(percentile_labels(qs))),
+                            ndf.columns)
+    }
+}
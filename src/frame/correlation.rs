@@ -0,0 +1,42 @@
+use std::hash::Hash;
+
+use nullvec::prelude::Array;
+
+use super::DataFrame;
+use algos::array::to_f64_vec;
+use algos::correlation::{corr, cov, CorrMethod};
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+    where I: Clone + Eq + Hash,
+          C: Clone + Eq + Hash
+{
+    /// Apply passed function to each pair of numeric columns, and return
+    /// a square DataFrame labeled by numeric columns
+    fn pairwise<'a, F>(&'c self, func: F) -> DataFrame<'a, 'a, 'a, C, C>
+        where F: Fn(&[f64], &[f64]) -> f64
+    {
+        let ndf = self.get_numeric_data();
+        let values: Vec<Vec<f64>> = ndf.values.iter().map(|x| to_f64_vec(x)).collect();
+
+        let new_values: Vec<Array> = values.iter()
+            .map(|x| {
+                let column: Vec<f64> = values.iter().map(|y| func(y, x)).collect();
+                column.into()
+            })
+            .collect();
+        let labels: Vec<C> = ndf.columns.values.clone();
+        DataFrame::from_vec(new_values, labels.clone(), labels)
+    }
+
+    /// Compute correlation between numeric columns, using pairs where both
+    /// are not null
+    pub fn corr<'a>(&'c self, method: CorrMethod) -> DataFrame<'a, 'a, 'a, C, C> {
+        self.pairwise(|x, y| corr(x, y, method))
+    }
+
+    /// Compute unbiased covariance between numeric columns, using pairs
+    /// where both are not null
+    pub fn cov<'a>(&'c self) -> DataFrame<'a, 'a, 'a, C, C> {
+        self.pairwise(|x, y| cov(x, y, 1))
+    }
+}
@@ -0,0 +1,63 @@
+use std::borrow::{Borrow, Cow};
+use std::hash::Hash;
+
+use nullvec::prelude::Array;
+
+use super::DataFrame;
+use algos::cumulative::{cumulate_nullvec, cumcount, CumOp};
+use groupby::GroupBy;
+use series::Series;
+use traits::Slicer;
+
+/// /////////////////////////////////////////////////////////////////////////////
+/// Cumulative aggregation
+/// /////////////////////////////////////////////////////////////////////////////
+
+macro_rules! define_cumulative_op {
+    ($m:ident, $op:expr) => {
+        impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+            where I: Clone + Eq + Hash,
+                  C: Clone + Eq + Hash
+        {
+            /// Cumulate each numeric column. If `skipna` is false, all values
+            /// after the first null are null.
+            pub fn $m<'a>(&'a self, skipna: bool) -> DataFrame<'a, 'a, 'a, I, C> {
+                let all: Vec<usize> = (0..self.index.len()).collect();
+                self.map_numeric(|x| {
+                    map_numeric_array!(x, cumulate_nullvec, &[&all], skipna, $op)
+                })
+            }
+        }
+
+        impl<'b, 'v, 'i, 'c, I, C, G> GroupBy<'b, DataFrame<'v, 'i, 'c, I, C>, G>
+            where I: Clone + Eq + Hash,
+                  C: Clone + Eq + Hash,
+                  G: Clone + Eq + Hash + Ord
+        {
+            /// Cumulate each numeric column within each group, keeping original row order
+            pub fn $m<'a>(&'a self, skipna: bool) -> DataFrame<'a, 'a, 'a, I, C> {
+                let locs = self.locs();
+                self.data.map_numeric(|x| {
+                    map_numeric_array!(x, cumulate_nullvec, &locs, skipna, $op)
+                })
+            }
+        }
+    }
+}
+
+define_cumulative_op!(cumsum, CumOp::Sum);
+define_cumulative_op!(cumprod, CumOp::Prod);
+define_cumulative_op!(cummin, CumOp::Min);
+define_cumulative_op!(cummax, CumOp::Max);
+
+impl<'b, 'v, 'i, 'c, I, C, G> GroupBy<'b, DataFrame<'v, 'i, 'c, I, C>, G>
+    where I: Clone + Eq + Hash,
+          C: Clone + Eq + Hash,
+          G: Clone + Eq + Hash + Ord
+{
+    /// Number each row within its group, starting from 0
+    pub fn cumcount<'a>(&'a self) -> Series<'a, 'a, usize, I> {
+        let new_values = cumcount(self.data.index.len(), &self.locs());
+        Series::from_cow(Cow::Owned(new_values), Cow::Borrowed(self.data.index.borrow()))
+    }
+}
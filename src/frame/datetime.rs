@@ -0,0 +1,36 @@
+use std::hash::Hash;
+
+use super::DataFrame;
+use datetime::{Datetime, Frequency};
+use groupby::GroupBy;
+use traits::RowIndex;
+
+impl<'v, 'i, 'c, C> DataFrame<'v, 'i, 'c, Datetime, C>
+    where C: Clone + Eq + Hash
+{
+    /// Slice rows using time bounds of the index (inclusive)
+    pub fn time_slice(&'c self, start: &Datetime, end: &Datetime) -> Self {
+        let locs = self.index.time_locs(start, end);
+        self.ilocs(&locs)
+    }
+}
+
+impl<'v, 'i, 'c, C> DataFrame<'v, 'i, 'c, Datetime, C>
+    where C: Clone + Eq + Hash
+{
+    /// Move index labels by given number of periods, values are unchanged
+    pub fn tshift<'n>(&self, periods: i64, freq: Frequency) -> DataFrame<'v, 'n, 'c, Datetime, C> {
+        self.set_index(self.index.tshift(periods, freq))
+    }
+}
+
+impl<'v, 'i, 'c, C> DataFrame<'v, 'i, 'c, Datetime, C>
+    where C: Clone + Eq + Hash
+{
+    /// Group rows into time bins, labeled by the start of each bin.
+    /// Bins which contain no row are not included.
+    pub fn resample(&'c self, freq: Frequency) -> GroupBy<DataFrame<Datetime, C>, Datetime> {
+        let bins = freq.bins(&self.index.values);
+        GroupBy::new(&self, bins)
+    }
+}
@@ -0,0 +1,358 @@
+use std::hash::Hash;
+
+use nullvec::prelude::{Array, Scalar};
+
+use super::DataFrame;
+use algos::array::{to_f64_vec, to_valid_f64_vec, scalars_to_array, array_count, array_sum,
+                   array_prod, array_min, array_max};
+use algos::computation::Aggregation;
+use algos::counter::{array_mode, array_nunique};
+use algos::parallel;
+use algos::rank::{argmin, argmax};
+use algos::quantile::{quantiles, describe, describe_labels, describe_percentiles,
+                      QuantileInterpolation};
+use groupby::GroupBy;
+use traits::{Slicer, BasicAggregation, NumericAggregation, HigherOrderAggregation,
+             ComparisonAggregation, Description};
+
+/// /////////////////////////////////////////////////////////////////////////////
+/// Aggregation
+/// /////////////////////////////////////////////////////////////////////////////
+
+fn first_valid<L: Iterator<Item = usize>>(values: &Array, locs: L) -> Scalar {
+    for loc in locs {
+        let value = values.iloc(&loc);
+        if value != Scalar::Null {
+            return value;
+        }
+    }
+    Scalar::Null
+}
+
+impl<'v, 'i, 'c, I, C, G> GroupBy<'c, DataFrame<'v, 'i, 'c, I, C>, G>
+    where I: Clone + Eq + Hash,
+          C: Clone + Eq + Hash,
+          G: 'c + Clone + Eq + Hash + Ord
+{
+    /// Apply passed function to each column of each group. Results are
+    /// stored as `dtype`, or the dtype of non-null results (the source
+    /// dtype if all are null) if `None`.
+    fn aggregate<'n, F>(&'c self,
+                        func: F,
+                        numeric_only: bool,
+                        dtype: Option<&str>)
+                        -> DataFrame<'n, 'n, 'n, G, C>
+        where F: Fn(&Array) -> Scalar + Sync + Send
+    {
+        let groups = self.groups();
+        let locs = self.locs();
+
+        let mut targets: Vec<&Array> = Vec::with_capacity(self.data.values.len());
+        let mut new_columns: Vec<C> = Vec::with_capacity(self.data.values.len());
+
+        for (values, label) in self.data.values.iter().zip(self.data.columns.iter()) {
+            if numeric_only && !values.is_numeric() {
+                continue;
+            }
+            targets.push(values);
+            new_columns.push(label.clone());
+        }
+        let new_values: Vec<Array> = parallel::map(&targets, |values| {
+            let aggregated: Vec<Scalar> = locs.iter().map(|l| func(&values.ilocs(l))).collect();
+            let dtype = match dtype {
+                Some(dtype) => dtype.to_string(),
+                None => {
+                    aggregated.iter()
+                        .find(|x| **x != Scalar::Null)
+                        .map_or(values.dtype(), |x| x.dtype())
+                }
+            };
+            scalars_to_array(&aggregated, &dtype)
+        });
+        DataFrame::from_vec(new_values, groups, new_columns)
+    }
+
+    /// Return the first non-null value of each column of each group, null
+    /// if all values are null
+    pub fn first(&'c self) -> DataFrame<'c, 'c, 'c, G, C> {
+        self.aggregate(|x: &Array| first_valid(x, 0..x.len()), false, None)
+    }
+
+    /// Return the last non-null value of each column of each group, null
+    /// if all values are null
+    pub fn last(&'c self) -> DataFrame<'c, 'c, 'c, G, C> {
+        self.aggregate(|x: &Array| first_valid(x, (0..x.len()).rev()), false, None)
+    }
+}
+
+impl<'v, 'i, 'c, I, C, G> GroupBy<'c, DataFrame<'v, 'i, 'c, I, C>, G>
+    where I: Clone + Eq + Hash,
+          C: Clone + Eq + Hash,
+          G: 'c + Clone + Eq + Hash + Ord
+{
+    /// Return the median of each numeric column of each group
+    pub fn median(&'c self) -> DataFrame<'c, 'c, 'c, G, C> {
+        self.quantile(0.5, QuantileInterpolation::Linear)
+    }
+
+    /// Return the value at quantile `q` (between 0 and 1) of each numeric
+    /// column of each group
+    pub fn quantile(&'c self,
+                    q: f64,
+                    interpolation: QuantileInterpolation)
+                    -> DataFrame<'c, 'c, 'c, G, C> {
+        self.aggregate(|x: &Array| {
+                           let values = to_f64_vec(x);
+                           Scalar::f64(quantiles(&values, &[q], interpolation)[0])
+                       },
+                       true,
+                       Some("f64"))
+    }
+
+    /// Return the number of distinct non-null values of each column of each group
+    pub fn nunique(&'c self) -> DataFrame<'c, 'c, 'c, G, C> {
+        self.aggregate(|x: &Array| Scalar::usize(array_nunique(x)), false, Some("usize"))
+    }
+
+    /// Return the most frequent non-null value of each column of each group,
+    /// the smallest one on ties. Null if all values are null.
+    pub fn mode(&'c self) -> DataFrame<'c, 'c, 'c, G, C> {
+        self.aggregate(|x: &Array| {
+                           let modes = array_mode(x);
+                           if modes.len() == 0 { Scalar::Null } else { modes.iloc(&0) }
+                       },
+                       false,
+                       None)
+    }
+}
+
+impl<'v, 'i, 'c, I, C, G> GroupBy<'c, DataFrame<'v, 'i, 'c, I, C>, G>
+    where I: Clone + Eq + Hash,
+          C: Clone + Eq + Hash,
+          G: 'c + Clone + Eq + Hash + Ord,
+          Array: From<Vec<I>>
+{
+    /// Apply passed function to values of each numeric column of each group
+    /// converted to f64, and take the index label at the returned location
+    fn locate<F>(&'c self, func: F) -> DataFrame<'c, 'c, 'c, G, C>
+        where F: Fn(&[f64], &[bool]) -> Option<usize> + Sync + Send
+    {
+        let groups = self.groups();
+        let locs = self.locs();
+        let labels = Array::from(self.data.index.values.clone());
+
+        let mut targets: Vec<&Array> = Vec::with_capacity(self.data.values.len());
+        let mut new_columns: Vec<C> = Vec::with_capacity(self.data.values.len());
+        for (values, label) in self.data.values.iter().zip(self.data.columns.iter()) {
+            if values.is_numeric() {
+                targets.push(values);
+                new_columns.push(label.clone());
+            }
+        }
+        let new_values: Vec<Array> = parallel::map(&targets, |values| {
+            let values = to_f64_vec(values);
+            let located: Vec<Scalar> = locs.iter()
+                .map(|l| {
+                    let group: Vec<f64> = l.iter().map(|&i| values[i]).collect();
+                    let nulls: Vec<bool> = group.iter().map(|x| x.is_nan()).collect();
+                    match func(&group, &nulls) {
+                        Some(i) => labels.iloc(&l[i]),
+                        None => Scalar::Null,
+                    }
+                })
+                .collect();
+            scalars_to_array(&located, &labels.dtype())
+        });
+        DataFrame::from_vec(new_values, groups, new_columns)
+    }
+
+    /// Return label of the minimum value of each numeric column of each
+    /// group, null if all values are null
+    pub fn idxmin(&'c self) -> DataFrame<'c, 'c, 'c, G, C> {
+        self.locate(argmin)
+    }
+
+    /// Return label of the maximum value of each numeric column of each
+    /// group, null if all values are null
+    pub fn idxmax(&'c self) -> DataFrame<'c, 'c, 'c, G, C> {
+        self.locate(argmax)
+    }
+}
+
+impl<'v, 'i, 'c, I, C, G> BasicAggregation<'c> for GroupBy<'c, DataFrame<'v, 'i, 'c, I, C>, G>
+    where I: Clone + Eq + Hash,
+          C: Clone + Eq + Hash,
+          G: 'c + Clone + Eq + Hash + Ord
+{
+    type Kept = DataFrame<'c, 'c, 'c, G, C>;
+    type Counted = DataFrame<'c, 'c, 'c, G, C>;
+
+    fn sum(&'c self) -> Self::Kept {
+        self.aggregate(|x: &Array| array_sum(x), true, None)
+    }
+
+    fn count(&'c self) -> Self::Counted {
+        self.aggregate(|x: &Array| Scalar::usize(array_count(x)), true, Some("usize"))
+    }
+}
+
+impl<'v, 'i, 'c, I, C, G> NumericAggregation<'c> for GroupBy<'c, DataFrame<'v, 'i, 'c, I, C>, G>
+    where I: Clone + Eq + Hash,
+          C: Clone + Eq + Hash,
+          G: 'c + Clone + Eq + Hash + Ord
+{
+    type Coerced = DataFrame<'c, 'c, 'c, G, C>;
+
+    fn mean(&'c self) -> Self::Coerced {
+        self.aggregate(|x: &Array| Scalar::f64(Aggregation::vec_mean(&to_valid_f64_vec(x))),
+                       true,
+                       Some("f64"))
+    }
+
+    fn var(&'c self) -> Self::Coerced {
+        self.aggregate(|x: &Array| Scalar::f64(Aggregation::vec_var(&to_valid_f64_vec(x))),
+                       true,
+                       Some("f64"))
+    }
+
+    fn unbiased_var(&'c self) -> Self::Coerced {
+        self.aggregate(|x: &Array| Scalar::f64(Aggregation::vec_unbiased_var(&to_valid_f64_vec(x))),
+                       true,
+                       Some("f64"))
+    }
+
+    fn std(&'c self) -> Self::Coerced {
+        self.aggregate(|x: &Array| Scalar::f64(Aggregation::vec_std(&to_valid_f64_vec(x))),
+                       true,
+                       Some("f64"))
+    }
+
+    fn unbiased_std(&'c self) -> Self::Coerced {
+        self.aggregate(|x: &Array| Scalar::f64(Aggregation::vec_unbiased_std(&to_valid_f64_vec(x))),
+                       true,
+                       Some("f64"))
+    }
+}
+
+impl<'v, 'i, 'c, I, C, G> HigherOrderAggregation<'c>
+    for GroupBy<'c, DataFrame<'v, 'i, 'c, I, C>, G>
+    where I: Clone + Eq + Hash,
+          C: Clone + Eq + Hash,
+          G: 'c + Clone + Eq + Hash + Ord
+{
+    type Kept = DataFrame<'c, 'c, 'c, G, C>;
+    type Coerced = DataFrame<'c, 'c, 'c, G, C>;
+
+    fn prod(&'c self) -> Self::Kept {
+        self.aggregate(|x: &Array| array_prod(x),
+                       true,
+                       None)
+    }
+
+    fn skew(&'c self) -> Self::Coerced {
+        self.aggregate(|x: &Array| Scalar::f64(Aggregation::vec_skew(&to_valid_f64_vec(x))),
+                       true,
+                       Some("f64"))
+    }
+
+    fn unbiased_skew(&'c self) -> Self::Coerced {
+        self.aggregate(|x: &Array| {
+                           Scalar::f64(Aggregation::vec_unbiased_skew(&to_valid_f64_vec(x)))
+                       },
+                       true,
+                       Some("f64"))
+    }
+
+    fn kurt(&'c self) -> Self::Coerced {
+        self.aggregate(|x: &Array| Scalar::f64(Aggregation::vec_kurt(&to_valid_f64_vec(x))),
+                       true,
+                       Some("f64"))
+    }
+
+    fn unbiased_kurt(&'c self) -> Self::Coerced {
+        self.aggregate(|x: &Array| {
+                           Scalar::f64(Aggregation::vec_unbiased_kurt(&to_valid_f64_vec(x)))
+                       },
+                       true,
+                       Some("f64"))
+    }
+
+    fn sem(&'c self) -> Self::Coerced {
+        self.aggregate(|x: &Array| Scalar::f64(Aggregation::vec_sem(&to_valid_f64_vec(x))),
+                       true,
+                       Some("f64"))
+    }
+
+    fn unbiased_sem(&'c self) -> Self::Coerced {
+        self.aggregate(|x: &Array| {
+                           Scalar::f64(Aggregation::vec_unbiased_sem(&to_valid_f64_vec(x)))
+                       },
+                       true,
+                       Some("f64"))
+    }
+}
+
+impl<'v, 'i, 'c, I, C, G> ComparisonAggregation<'c>
+    for GroupBy<'c, DataFrame<'v, 'i, 'c, I, C>, G>
+    where I: Clone + Eq + Hash,
+          C: Clone + Eq + Hash,
+          G: 'c + Clone + Eq + Hash + Ord
+{
+    type Kept = DataFrame<'c, 'c, 'c, G, C>;
+
+    fn min(&'c self) -> Self::Kept {
+        self.aggregate(|x: &Array| array_min(x), true, None)
+    }
+
+    fn max(&'c self) -> Self::Kept {
+        self.aggregate(|x: &Array| array_max(x), true, None)
+    }
+}
+
+impl<'v, 'i, 'c, I, C, G> Description<'c> for GroupBy<'c, DataFrame<'v, 'i, 'c, I, C>, G>
+    where I: Clone + Eq + Hash,
+          C: Clone + Eq + Hash,
+          G: 'c + Clone + Eq + Hash + Ord
+{
+    type Described = DataFrame<'c, 'c, 'c, G, (C, String)>;
+
+    /// Describe each group as a row. Columns are pairs of numeric column label
+    /// and statistic label.
+    fn describe_percentiles(&'c self, percentiles: &[f64]) -> Self::Described {
+        let groups = self.groups();
+        let locs = self.locs();
+
+        let percentiles = describe_percentiles(percentiles);
+        let labels = describe_labels(&percentiles);
+
+        let mut targets: Vec<&Array> = Vec::with_capacity(self.data.values.len());
+        let mut columns: Vec<C> = Vec::with_capacity(self.data.values.len());
+        for (values, label) in self.data.values.iter().zip(self.data.columns.iter()) {
+            if values.is_numeric() {
+                targets.push(values);
+                columns.push(label.clone());
+            }
+        }
+        let described: Vec<Vec<Array>> = parallel::map(&targets, |values| {
+            let rows: Vec<Vec<f64>> = locs.iter()
+                .map(|l| describe(&to_f64_vec(&values.ilocs(l)), &percentiles))
+                .collect();
+            (0..labels.len())
+                .map(|i| {
+                    let column: Vec<f64> = rows.iter().map(|r| r[i]).collect();
+                    column.into()
+                })
+                .collect()
+        });
+
+        let mut new_values: Vec<Array> = Vec::with_capacity(targets.len() * labels.len());
+        let mut new_columns: Vec<(C, String)> = Vec::with_capacity(new_values.capacity());
+        for (column, arrays) in columns.iter().zip(described) {
+            for (label, values) in labels.iter().zip(arrays) {
+                new_values.push(values);
+                new_columns.push((column.clone(), label.clone()));
+            }
+        }
+        DataFrame::from_vec(new_values, groups, new_columns)
+    }
+}
@@ -0,0 +1,61 @@
+use std::borrow::{Borrow, Cow};
+use std::hash::Hash;
+
+use num::ToPrimitive;
+use nullvec::prelude::Array;
+
+use super::DataFrame;
+use algos::array::{to_f64_vec, array_count};
+use algos::interpolate::{interpolate, positions, Interpolation, LimitDirection};
+use traits::Slicer;
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+    where I: Clone + Eq + Hash,
+          C: Clone + Eq + Hash
+{
+    /// Interpolate numeric columns having nulls as `Float64Array`, treating
+    /// rows as equally spaced. Other columns are kept as they are.
+    pub fn interpolate<'a>(&'a self,
+                           method: Interpolation,
+                           limit: Option<usize>,
+                           direction: LimitDirection)
+                           -> DataFrame<'a, 'a, 'a, I, C> {
+        let xs = positions(self.index.len());
+        self.interpolate_at(&xs, method, limit, direction)
+    }
+
+    fn interpolate_at<'a>(&'a self,
+                          xs: &[f64],
+                          method: Interpolation,
+                          limit: Option<usize>,
+                          direction: LimitDirection)
+                          -> DataFrame<'a, 'a, 'a, I, C> {
+        let new_values: Vec<Cow<Array>> = self.values
+            .iter()
+            .map(|x| if x.is_numeric() && array_count(x) < x.len() {
+                let values = to_f64_vec(x);
+                Cow::Owned(interpolate(&values, xs, method, limit, direction).into())
+            } else {
+                Cow::Borrowed(x.borrow())
+            })
+            .collect();
+        DataFrame::from_cow(new_values,
+                            Cow::Borrowed(self.index.borrow()),
+                            Cow::Borrowed(self.columns.borrow()))
+    }
+}
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+    where I: Clone + Eq + Hash + ToPrimitive,
+          C: Clone + Eq + Hash
+{
+    /// Interpolate numeric columns having nulls linearly, using numeric or
+    /// time index labels as spacing
+    pub fn interpolate_index<'a>(&'a self,
+                                 limit: Option<usize>,
+                                 direction: LimitDirection)
+                                 -> DataFrame<'a, 'a, 'a, I, C> {
+        let xs: Vec<f64> = self.index.iter().map(|x| x.to_f64().unwrap()).collect();
+        self.interpolate_at(&xs, Interpolation::Linear, limit, direction)
+    }
+}
@@ -0,0 +1,115 @@
+use std::borrow::{Borrow, Cow};
+use std::hash::Hash;
+
+use nullvec::prelude::{Array, Scalar};
+
+use super::DataFrame;
+use algos::missing::{array_is_null, ffill_nullvec, bfill_nullvec, fillna_array, keep_labels,
+                     DropHow};
+use traits::{Slicer, IndexerIndex, RowIndex, ColIndex};
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+    where I: Clone + Eq + Hash,
+          C: Clone + Eq + Hash
+{
+    /// Apply passed function to all columns
+    fn map_columns<'a, F>(&'a self, func: F) -> DataFrame<'a, 'a, 'a, I, C>
+        where F: Fn(&Array) -> Array
+    {
+        let new_values: Vec<Cow<Array>> = self.values
+            .iter()
+            .map(|x| Cow::Owned(func(x)))
+            .collect();
+        DataFrame::from_cow(new_values,
+                            Cow::Borrowed(self.index.borrow()),
+                            Cow::Borrowed(self.columns.borrow()))
+    }
+
+    pub fn isnull<'a>(&'a self) -> DataFrame<'a, 'a, 'a, I, C> {
+        self.map_columns(|x| array_is_null(x).into())
+    }
+
+    pub fn notnull<'a>(&'a self) -> DataFrame<'a, 'a, 'a, I, C> {
+        self.map_columns(|x| {
+            let flags: Vec<bool> = array_is_null(x).iter().map(|f| !f).collect();
+            flags.into()
+        })
+    }
+
+    /// Replace null values with given value, converted to the dtype of each
+    /// column. Integer columns are upcast to `f64` if the value is fractional
+    /// or out of their range. Columns whose dtype is not compatible are left
+    /// untouched.
+    pub fn fillna<'a>(&'a self, value: &Scalar) -> DataFrame<'a, 'a, 'a, I, C> {
+        self.map_columns(|x| fillna_array(x, value))
+    }
+
+    /// Propagate last valid value forward, filling at most `limit` consecutive nulls
+    pub fn ffill<'a>(&'a self, limit: Option<usize>) -> DataFrame<'a, 'a, 'a, I, C> {
+        self.map_columns(|x| map_array!(x, ffill_nullvec, limit))
+    }
+
+    /// Propagate next valid value backward, filling at most `limit` consecutive nulls
+    pub fn bfill<'a>(&'a self, limit: Option<usize>) -> DataFrame<'a, 'a, 'a, I, C> {
+        self.map_columns(|x| map_array!(x, bfill_nullvec, limit))
+    }
+
+    /// Remove rows which contain null
+    ///
+    /// - `how`: drop if `Any` or `All` values are null
+    /// - `subset`: columns to consider, all columns if `None`
+    /// - `thresh`: if specified, keep rows which have at least `thresh` not
+    ///   null values. This overrides `how`.
+    pub fn dropna(&'c self, how: DropHow, subset: Option<&[C]>, thresh: Option<usize>) -> Self {
+        let locs: Vec<usize> = match subset {
+            Some(labels) => self.columns.get_locs(labels),
+            None => (0..self.values.len()).collect(),
+        };
+        let mut counts: Vec<usize> = vec![0; self.index.len()];
+        for loc in locs.iter() {
+            for (c, null) in counts.iter_mut().zip(array_is_null(&self.values[*loc])) {
+                if !null {
+                    *c += 1;
+                }
+            }
+        }
+        let flags = keep_labels(&counts, locs.len(), how, thresh);
+        let rows: Vec<usize> = flags.iter()
+            .enumerate()
+            .filter(|&(_, &f)| f)
+            .map(|(i, _)| i)
+            .collect();
+        self.ilocs(&rows)
+    }
+
+    /// Remove columns which contain null
+    ///
+    /// - `how`: drop if `Any` or `All` values are null
+    /// - `subset`: rows to consider, all rows if `None`
+    /// - `thresh`: if specified, keep columns which have at least `thresh`
+    ///   not null values. This overrides `how`.
+    pub fn dropna_columns(&'i self,
+                          how: DropHow,
+                          subset: Option<&[I]>,
+                          thresh: Option<usize>)
+                          -> Self {
+        let locs: Vec<usize> = match subset {
+            Some(labels) => self.index.get_locs(labels),
+            None => (0..self.index.len()).collect(),
+        };
+        let counts: Vec<usize> = self.values
+            .iter()
+            .map(|x| {
+                let nulls = array_is_null(x);
+                locs.iter().filter(|&&loc| !nulls[loc]).count()
+            })
+            .collect();
+        let flags = keep_labels(&counts, locs.len(), how, thresh);
+        let columns: Vec<usize> = flags.iter()
+            .enumerate()
+            .filter(|&(_, &f)| f)
+            .map(|(i, _)| i)
+            .collect();
+        self.igets(&columns)
+    }
+}
@@ -3,17 +3,33 @@ use std::hash::Hash;
 use std::slice;
 use std::vec;
 
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
 use nullvec::prelude::Array;
 
+use algos::parallel;
 use groupby::GroupBy;
 use indexer::Indexer;
 use traits::{Slicer, IndexerIndex, RowIndex, ColIndex};
 
 mod aggregation;
+mod correlation;
+mod cumulative;
+mod datetime;
 mod formatting;
+mod groupby;
+mod interpolate;
+mod missing;
+mod rank;
 mod reshape;
+#[cfg(feature = "serde")]
+mod serialize;
+mod shift;
+mod window;
 
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct DataFrame<'v, 'i, 'c, I, C>
     where I: 'i + Clone + Hash,
           C: 'c + Clone + Hash
@@ -21,6 +37,7 @@ pub struct DataFrame<'v, 'i, 'c, I, C>
     /// 2-dimentional block contains multiple type.
     /// I: type of indexer
     /// C: type of columns
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize::serialize"))]
     pub values: Vec<Cow<'v, Array>>,
     pub index: Cow<'i, Indexer<I>>,
     pub columns: Cow<'c, Indexer<C>>,
@@ -58,11 +75,9 @@ impl<'v, 'i, 'c, I, C> RowIndex<'c> for DataFrame<'v, 'i, 'c, I, C>
         let new_index = self.index.reindex(locations);
         // boudaries are checked in Indexer.reindex
 
-        let mut new_values: Vec<Cow<Array>> = Vec::with_capacity(self.columns.len());
-        for current in self.values.iter() {
-            let new_value = unsafe { current.ilocs_unchecked(locations) };
-            new_values.push(Cow::Owned(new_value));
-        }
+        let new_values: Vec<Cow<Array>> = parallel::map(&self.values, |current| {
+            Cow::Owned(unsafe { current.ilocs_unchecked(locations) })
+        });
         DataFrame::from_cow(new_values,
                             Cow::Owned(new_index),
                             Cow::Borrowed(self.columns.borrow()))
@@ -82,11 +97,12 @@ impl<'v, 'i, 'c, I, C> ColIndex<'i> for DataFrame<'v, 'i, 'c, I, C>
     type Column = Array;
 
     fn get(&'i self, label: &Self::Key) -> Self::Column {
-        unimplemented!();
+        let loc = self.columns.get_loc(label);
+        self.iget(&loc)
     }
 
     fn iget(&'i self, loc: &usize) -> Self::Column {
-        unimplemented!();
+        self.values[*loc].clone().into_owned()
     }
 
     fn gets<'l>(&'i self, labels: &'l [Self::Key]) -> Self {
@@ -171,6 +187,25 @@ impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
         self.igets(&indexer)
     }
 
+    /// Apply passed function to each numeric column, non-numeric columns are dropped
+    fn map_numeric<'a, F>(&'a self, func: F) -> DataFrame<'a, 'a, 'a, I, C>
+        where F: Fn(&Array) -> Array
+    {
+        let mut new_values: Vec<Cow<Array>> = Vec::with_capacity(self.values.len());
+        let mut new_columns: Vec<C> = Vec::with_capacity(self.values.len());
+
+        for (values, label) in self.values.iter().zip(self.columns.iter()) {
+            if !values.is_numeric() {
+                continue;
+            }
+            new_values.push(Cow::Owned(func(values)));
+            new_columns.push(label.clone());
+        }
+        DataFrame::from_cow(new_values,
+                            Cow::Borrowed(self.index.borrow()),
+                            Cow::Owned(new_columns.into()))
+    }
+
     fn assert_binop(&self, other: &Self) {
         assert!(self.index == other.index, "index must be the same!");
         assert!(self.columns == other.columns, "columns must be the same!");
@@ -183,6 +218,16 @@ impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
         self.columns.to_mut().push(name);
     }
 
+    /// Replace index with given labels
+    pub fn set_index<'n, J, X>(&self, index: X) -> DataFrame<'v, 'n, 'c, J, C>
+        where J: 'n + Clone + Eq + Hash,
+              X: Into<Indexer<J>>
+    {
+        let index: Indexer<J> = index.into();
+        assert!(self.index.len() == index.len(), "Length mismatch!");
+        DataFrame::from_cow(self.values.clone(), Cow::Owned(index), self.columns.clone())
+    }
+
     pub fn groupby<G>(&'i self, other: Vec<G>) -> GroupBy<DataFrame<I, C>, G>
         where G: Clone + Eq + Hash + Ord
     {
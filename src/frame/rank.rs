@@ -0,0 +1,121 @@
+use std::borrow::{Borrow, Cow};
+use std::hash::Hash;
+
+use nullvec::prelude::Array;
+
+use super::DataFrame;
+use algos::array::{to_f64_vec, pad_nullvec};
+use algos::counter::{array_mode, array_nunique};
+use algos::rank::{rank, argmin, argmax, RankMethod, NaOption};
+use algos::window::apply_by_groups;
+use groupby::GroupBy;
+use indexer::Indexer;
+use series::Series;
+use traits::Slicer;
+
+/// Rank values converted to f64, NaN is regarded as null
+fn rank_f64(values: &[f64], method: RankMethod, ascending: bool, na_option: NaOption) -> Vec<f64> {
+    let nulls: Vec<bool> = values.iter().map(|x| x.is_nan()).collect();
+    rank(values, &nulls, method, ascending, na_option)
+}
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+    where I: Clone + Eq + Hash,
+          C: Clone + Eq + Hash
+{
+    /// Compute rank of each value of numeric columns starting from 1
+    pub fn rank<'a>(&'a self,
+                    method: RankMethod,
+                    ascending: bool,
+                    na_option: NaOption)
+                    -> DataFrame<'a, 'a, 'a, I, C> {
+        self.map_numeric(|x| rank_f64(&to_f64_vec(x), method, ascending, na_option).into())
+    }
+
+    /// Return the most frequent values of each column in ascending order.
+    /// Columns having less modes are padded with nulls.
+    pub fn mode<'a>(&self) -> DataFrame<'a, 'a, 'a, usize, C> {
+        let modes: Vec<Array> = self.values.iter().map(|x| array_mode(x)).collect();
+        let len = modes.iter().map(|x| x.len()).max().unwrap_or(0);
+        let new_values: Vec<Array> = modes.iter()
+            .map(|x| map_array!(x, pad_nullvec, len))
+            .collect();
+        DataFrame::from_vec(new_values,
+                            Indexer::<usize>::from_len(len),
+                            self.columns.values.clone())
+    }
+
+    /// Return the number of distinct non-null values of each column
+    pub fn nunique(&'c self) -> Series<'c, 'c, usize, C> {
+        let new_values: Vec<usize> = self.values.iter().map(|x| array_nunique(x)).collect();
+        Series::from_cow(Cow::Owned(new_values), Cow::Borrowed(self.columns.borrow()))
+    }
+
+    /// Apply passed function to each numeric column converted to f64
+    fn locate_numeric<'a, F>(&'a self, func: F) -> Series<'a, 'a, Option<usize>, C>
+        where F: Fn(&[f64], &[bool]) -> Option<usize>
+    {
+        let ndf = self.get_numeric_data();
+        let new_values: Vec<Option<usize>> = ndf.values
+            .iter()
+            .map(|x| {
+                let values = to_f64_vec(x);
+                let nulls: Vec<bool> = values.iter().map(|v| v.is_nan()).collect();
+                func(&values, &nulls)
+            })
+            .collect();
+        Series::new(new_values, ndf.columns.into_owned())
+    }
+
+    /// Return location of the minimum value of each numeric column,
+    /// `None` if all values are null
+    pub fn argmin<'a>(&'a self) -> Series<'a, 'a, Option<usize>, C> {
+        self.locate_numeric(argmin)
+    }
+
+    /// Return location of the maximum value of each numeric column,
+    /// `None` if all values are null
+    pub fn argmax<'a>(&'a self) -> Series<'a, 'a, Option<usize>, C> {
+        self.locate_numeric(argmax)
+    }
+
+    /// Return label of the minimum value of each numeric column,
+    /// `None` if all values are null
+    pub fn idxmin<'a>(&'a self) -> Series<'a, 'a, Option<I>, C> {
+        let locs = self.argmin();
+        let new_values: Vec<Option<I>> =
+            locs.values.iter().map(|x| x.map(|l| self.index.iloc(&l))).collect();
+        Series::from_cow(Cow::Owned(new_values), locs.index)
+    }
+
+    /// Return label of the maximum value of each numeric column,
+    /// `None` if all values are null
+    pub fn idxmax<'a>(&'a self) -> Series<'a, 'a, Option<I>, C> {
+        let locs = self.argmax();
+        let new_values: Vec<Option<I>> =
+            locs.values.iter().map(|x| x.map(|l| self.index.iloc(&l))).collect();
+        Series::from_cow(Cow::Owned(new_values), locs.index)
+    }
+}
+
+impl<'b, 'v, 'i, 'c, I, C, G> GroupBy<'b, DataFrame<'v, 'i, 'c, I, C>, G>
+    where I: Clone + Eq + Hash,
+          C: Clone + Eq + Hash,
+          G: Clone + Eq + Hash + Ord
+{
+    /// Compute rank of each value of numeric columns within its group,
+    /// keeping original order
+    pub fn rank<'a>(&'a self,
+                    method: RankMethod,
+                    ascending: bool,
+                    na_option: NaOption)
+                    -> DataFrame<'a, 'a, 'a, I, C> {
+        let locs = self.locs();
+        self.data.map_numeric(|x| {
+            apply_by_groups(&to_f64_vec(x),
+                            &locs,
+                            |v| rank_f64(v, method, ascending, na_option))
+                .into()
+        })
+    }
+}
@@ -0,0 +1,147 @@
+//! Serialize `DataFrame` values, keeping dtype and null mask of each `Array`
+
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+use serde::de::Error;
+use std::borrow::Cow;
+use std::hash::Hash;
+
+use nullvec::prelude::{Array, NullVec};
+use nullvec::prelude::dev::NullStorable;
+
+use indexer::Indexer;
+use traits::Slicer;
+use super::DataFrame;
+
+/// Raw values with null mask, `None` if the array has no null. Values at null
+/// locations are filled with default.
+fn to_parts<T: NullStorable + Clone>(values: &NullVec<T>) -> (Vec<T>, Option<Vec<bool>>) {
+    let data: Vec<T> = values.iter_raw()
+        .map(|(null, v)| if null { T::default() } else { v.clone() })
+        .collect();
+    let mask = if values.has_null() { Some(values.is_null()) } else { None };
+    (data, mask)
+}
+
+fn from_parts<T: NullStorable, E: Error>(values: Vec<T>,
+                                         mask: Option<Vec<bool>>)
+                                         -> Result<NullVec<T>, E> {
+    if let Some(ref mask) = mask {
+        if mask.len() != values.len() {
+            return Err(E::custom(format!("mask length {} doesn't match values length {}",
+                                         mask.len(),
+                                         values.len())));
+        }
+    }
+    Ok(NullVec::with_mask(values, mask))
+}
+
+macro_rules! typed_array {
+    ($($variant:ident, $t:ty, $dtype:tt);*) => {
+
+        /// `Array` tagged by its dtype
+        #[derive(Serialize, Deserialize)]
+        enum TypedArray {
+            $(
+                #[serde(rename = $dtype)]
+                $variant { values: Vec<$t>, mask: Option<Vec<bool>> },
+            )*
+        }
+
+        impl<'a> From<&'a Array> for TypedArray {
+            fn from(array: &'a Array) -> Self {
+                match *array {
+                    $(
+                        Array::$variant(ref vals) => {
+                            let (values, mask) = to_parts(vals);
+                            TypedArray::$variant { values: values, mask: mask }
+                        }
+                    )*
+                }
+            }
+        }
+
+        impl TypedArray {
+            fn into_array<E: Error>(self) -> Result<Array, E> {
+                match self {
+                    $(
+                        TypedArray::$variant { values, mask } => {
+                            Ok(Array::$variant(try!(from_parts(values, mask))))
+                        }
+                    )*
+                }
+            }
+        }
+    }
+}
+
+typed_array!(Int64Array, i64, "i64";
+             Int32Array, i32, "i32";
+             Int16Array, i16, "i16";
+             Int8Array, i8, "i8";
+             IsizeArray, isize, "isize";
+             UInt64Array, u64, "u64";
+             UInt32Array, u32, "u32";
+             UInt16Array, u16, "u16";
+             UInt8Array, u8, "u8";
+             UsizeArray, usize, "usize";
+             Float64Array, f64, "f64";
+             Float32Array, f32, "f32";
+             BoolArray, bool, "bool";
+             StringArray, String, "str");
+
+pub fn serialize<'v, S>(values: &[Cow<'v, Array>], serializer: S) -> Result<S::Ok, S::Error>
+    where S: Serializer
+{
+    serializer.collect_seq(values.iter().map(|x| TypedArray::from(x.as_ref())))
+}
+
+fn deserialize_values<'de, 'v, D>(deserializer: D) -> Result<Vec<Cow<'v, Array>>, D::Error>
+    where D: Deserializer<'de>
+{
+    let typed: Vec<TypedArray> = try!(Vec::deserialize(deserializer));
+    let mut values: Vec<Cow<'v, Array>> = Vec::with_capacity(typed.len());
+    for array in typed.into_iter() {
+        values.push(Cow::Owned(try!(array.into_array())));
+    }
+    Ok(values)
+}
+
+/// Same fields as `DataFrame`, to be validated before building it
+#[derive(Deserialize)]
+#[serde(rename = "DataFrame", bound(deserialize = "I: Deserialize<'de>, C: Deserialize<'de>"))]
+struct RawFrame<'v, I: Clone + Hash, C: Clone + Hash> {
+    #[serde(deserialize_with = "deserialize_values")]
+    values: Vec<Cow<'v, Array>>,
+    index: Indexer<I>,
+    columns: Indexer<C>,
+}
+
+impl<'de, 'v, 'i, 'c, I, C> Deserialize<'de> for DataFrame<'v, 'i, 'c, I, C>
+    where I: Clone + Hash + Deserialize<'de>,
+          C: Clone + Hash + Deserialize<'de>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: Deserializer<'de>
+    {
+        let raw: RawFrame<I, C> = try!(RawFrame::deserialize(deserializer));
+        if raw.values.len() != raw.columns.values.len() {
+            return Err(D::Error::custom(format!("{} arrays don't match columns length {}",
+                                                raw.values.len(),
+                                                raw.columns.values.len())));
+        }
+        let len = raw.index.values.len();
+        for value in raw.values.iter() {
+            if value.len() != len {
+                return Err(D::Error::custom(format!("array length {} doesn't match index \
+                                                     length {}",
+                                                    value.len(),
+                                                    len)));
+            }
+        }
+        Ok(DataFrame {
+            values: raw.values,
+            index: Cow::Owned(raw.index),
+            columns: Cow::Owned(raw.columns),
+        })
+    }
+}
@@ -0,0 +1,35 @@
+use std::borrow::{Borrow, Cow};
+use std::hash::Hash;
+
+use nullvec::prelude::Array;
+
+use super::DataFrame;
+use algos::array::to_f64_vec;
+use algos::shift::{shift_nullvec, diff, pct_change};
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+    where I: Clone + Eq + Hash,
+          C: Clone + Eq + Hash
+{
+    /// Shift rows by given periods keeping the index. Positive periods
+    /// move rows forward, and locations shifted out of range are null.
+    pub fn shift<'a>(&'a self, periods: isize) -> DataFrame<'a, 'a, 'a, I, C> {
+        let new_values: Vec<Cow<Array>> = self.values
+            .iter()
+            .map(|x| Cow::Owned(map_array!(x.as_ref(), shift_nullvec, periods)))
+            .collect();
+        DataFrame::from_cow(new_values,
+                            Cow::Borrowed(self.index.borrow()),
+                            Cow::Borrowed(self.columns.borrow()))
+    }
+
+    /// Difference from the row `periods` before, for each numeric column
+    pub fn diff<'a>(&'a self, periods: isize) -> DataFrame<'a, 'a, 'a, I, C> {
+        self.map_numeric(|x| diff(&to_f64_vec(x), periods).into())
+    }
+
+    /// Percentage change from the row `periods` before, for each numeric column
+    pub fn pct_change<'a>(&'a self, periods: isize) -> DataFrame<'a, 'a, 'a, I, C> {
+        self.map_numeric(|x| pct_change(&to_f64_vec(x), periods).into())
+    }
+}
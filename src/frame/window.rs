@@ -0,0 +1,134 @@
+use std::hash::Hash;
+
+use nullvec::prelude::Array;
+
+use super::DataFrame;
+use algos::array::to_f64_vec;
+use algos::window::apply_by_groups;
+use groupby::GroupBy;
+use traits::{BasicAggregation, NumericAggregation, ComparisonAggregation};
+use window::{Rolling, Expanding, Ewm, Decay};
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+    where I: Clone + Eq + Hash,
+          C: Clone + Eq + Hash
+{
+    pub fn rolling(&self, window: usize) -> Rolling<DataFrame<'v, 'i, 'c, I, C>> {
+        Rolling::new(&self, window)
+    }
+
+    pub fn expanding(&self) -> Expanding<DataFrame<'v, 'i, 'c, I, C>> {
+        Expanding::new(&self)
+    }
+
+    pub fn ewm(&self, decay: Decay) -> Ewm<DataFrame<'v, 'i, 'c, I, C>> {
+        Ewm::new(&self, decay)
+    }
+}
+
+impl<'b, 'v, 'i, 'c, I, C, G> GroupBy<'b, DataFrame<'v, 'i, 'c, I, C>, G>
+    where I: Clone + Eq + Hash,
+          C: Clone + Eq + Hash,
+          G: Clone + Eq + Hash + Ord
+{
+    pub fn rolling(&self, window: usize) -> Rolling<GroupBy<'b, DataFrame<'v, 'i, 'c, I, C>, G>> {
+        Rolling::new(&self, window)
+    }
+
+    pub fn expanding(&self) -> Expanding<GroupBy<'b, DataFrame<'v, 'i, 'c, I, C>, G>> {
+        Expanding::new(&self)
+    }
+
+    pub fn ewm(&self, decay: Decay) -> Ewm<GroupBy<'b, DataFrame<'v, 'i, 'c, I, C>, G>> {
+        Ewm::new(&self, decay)
+    }
+}
+
+/// /////////////////////////////////////////////////////////////////////////////
+/// Aggregation
+/// /////////////////////////////////////////////////////////////////////////////
+
+/// Apply passed kernel to each numeric column
+fn roll<'a, 'v, 'i, 'c, I, C, W, F>(data: &'a DataFrame<'v, 'i, 'c, I, C>,
+                                    func: F)
+                                    -> DataFrame<'a, 'a, 'a, I, C>
+    where I: Clone + Eq + Hash,
+          C: Clone + Eq + Hash,
+          Vec<W>: Into<Array>,
+          F: Fn(&[f64]) -> Vec<W>
+{
+    data.map_numeric(|x| func(&to_f64_vec(x)).into())
+}
+
+/// Apply passed kernel to each group of each numeric column, keeping original row order
+fn roll_groups<'a, 'b, 'v, 'i, 'c, I, C, G, W, F>(data: &'a GroupBy<'b,
+                                                                     DataFrame<'v, 'i, 'c, I, C>,
+                                                                     G>,
+                                                  func: F)
+                                                  -> DataFrame<'a, 'a, 'a, I, C>
+    where I: Clone + Eq + Hash,
+          C: Clone + Eq + Hash,
+          G: Clone + Eq + Hash + Ord,
+          W: Clone,
+          Vec<W>: Into<Array>,
+          F: Fn(&[f64]) -> Vec<W>
+{
+    let locs = data.locs();
+    data.data.map_numeric(|x| apply_by_groups(&to_f64_vec(x), &locs, |v| func(v)).into())
+}
+
+impl_window_basic_aggregation!(Rolling, DataFrame<'v, 'i, 'c, I, C>, roll,
+                               DataFrame<'a, 'a, 'a, I, C>, DataFrame<'a, 'a, 'a, I, C>,
+                               ['v, 'i, 'c, I, C],
+                               [I: Clone + Eq + Hash, C: Clone + Eq + Hash]);
+impl_window_numeric_aggregation!(Rolling, DataFrame<'v, 'i, 'c, I, C>, roll,
+                                 DataFrame<'a, 'a, 'a, I, C>,
+                                 ['v, 'i, 'c, I, C],
+                                 [I: Clone + Eq + Hash, C: Clone + Eq + Hash]);
+
+impl_window_basic_aggregation!(Expanding, DataFrame<'v, 'i, 'c, I, C>, roll,
+                               DataFrame<'a, 'a, 'a, I, C>, DataFrame<'a, 'a, 'a, I, C>,
+                               ['v, 'i, 'c, I, C],
+                               [I: Clone + Eq + Hash, C: Clone + Eq + Hash]);
+impl_window_numeric_aggregation!(Expanding, DataFrame<'v, 'i, 'c, I, C>, roll,
+                                 DataFrame<'a, 'a, 'a, I, C>,
+                                 ['v, 'i, 'c, I, C],
+                                 [I: Clone + Eq + Hash, C: Clone + Eq + Hash]);
+
+impl_window_numeric_aggregation!(Ewm, DataFrame<'v, 'i, 'c, I, C>, roll,
+                                 DataFrame<'a, 'a, 'a, I, C>,
+                                 ['v, 'i, 'c, I, C],
+                                 [I: Clone + Eq + Hash, C: Clone + Eq + Hash]);
+
+impl_window_basic_aggregation!(Rolling, GroupBy<'b, DataFrame<'v, 'i, 'c, I, C>, G>,
+                               roll_groups,
+                               DataFrame<'a, 'a, 'a, I, C>, DataFrame<'a, 'a, 'a, I, C>,
+                               ['b, 'v, 'i, 'c, I, C, G],
+                               [I: Clone + Eq + Hash, C: Clone + Eq + Hash,
+                                G: Clone + Eq + Hash + Ord]);
+impl_window_numeric_aggregation!(Rolling, GroupBy<'b, DataFrame<'v, 'i, 'c, I, C>, G>,
+                                 roll_groups,
+                                 DataFrame<'a, 'a, 'a, I, C>,
+                                 ['b, 'v, 'i, 'c, I, C, G],
+                                 [I: Clone + Eq + Hash, C: Clone + Eq + Hash,
+                                  G: Clone + Eq + Hash + Ord]);
+
+impl_window_basic_aggregation!(Expanding, GroupBy<'b, DataFrame<'v, 'i, 'c, I, C>, G>,
+                               roll_groups,
+                               DataFrame<'a, 'a, 'a, I, C>, DataFrame<'a, 'a, 'a, I, C>,
+                               ['b, 'v, 'i, 'c, I, C, G],
+                               [I: Clone + Eq + Hash, C: Clone + Eq + Hash,
+                                G: Clone + Eq + Hash + Ord]);
+impl_window_numeric_aggregation!(Expanding, GroupBy<'b, DataFrame<'v, 'i, 'c, I, C>, G>,
+                                 roll_groups,
+                                 DataFrame<'a, 'a, 'a, I, C>,
+                                 ['b, 'v, 'i, 'c, I, C, G],
+                                 [I: Clone + Eq + Hash, C: Clone + Eq + Hash,
+                                  G: Clone + Eq + Hash + Ord]);
+
+impl_window_numeric_aggregation!(Ewm, GroupBy<'b, DataFrame<'v, 'i, 'c, I, C>, G>,
+                                 roll_groups,
+                                 DataFrame<'a, 'a, 'a, I, C>,
+                                 ['b, 'v, 'i, 'c, I, C, G],
+                                 [I: Clone + Eq + Hash, C: Clone + Eq + Hash,
+                                  G: Clone + Eq + Hash + Ord]);
@@ -41,4 +41,9 @@ impl<'a, D, G> GroupBy<'a, D, G>
         keys.sort();
         keys
     }
+
+    /// Locations of each group, in the order of `groups`
+    pub fn locs(&self) -> Vec<&Vec<usize>> {
+        self.groups().iter().map(|g| self.grouper.get(g).unwrap()).collect()
+    }
 }
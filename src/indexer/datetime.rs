@@ -0,0 +1,162 @@
+use std::borrow::Cow;
+
+use nullvec::prelude::Array;
+
+use super::Indexer;
+use datetime::{Datetime, DatetimeParseError, Frequency};
+use series::Series;
+use traits::Slicer;
+
+/// Indexer whose labels are timestamps
+pub type DatetimeIndex = Indexer<Datetime>;
+
+/// /////////////////////////////////////////////////////////////////////////////
+/// Constructor
+/// /////////////////////////////////////////////////////////////////////////////
+
+impl Indexer<Datetime> {
+    /// Parse each string using strftime-like format
+    pub fn parse<S: AsRef<str>>(values: &[S], format: &str)
+                                -> Result<Self, DatetimeParseError> {
+        let mut new_values: Vec<Datetime> = Vec::with_capacity(values.len());
+        for value in values.iter() {
+            new_values.push(try!(Datetime::parse(value.as_ref(), format)));
+        }
+        Ok(Indexer::new(new_values))
+    }
+
+    /// Create from an `Array` column
+    ///
+    /// String columns are parsed using given format, integer columns
+    /// are regarded as epoch nanoseconds.
+    pub fn from_array(values: &Array, format: &str) -> Result<Self, DatetimeParseError> {
+        match values {
+            &Array::StringArray(ref vals) => {
+                let mut new_values: Vec<Datetime> = Vec::with_capacity(vals.len());
+                for (null, v) in vals.iter_raw() {
+                    if null {
+                        return Err(DatetimeParseError::new("null", format, "null is not allowed"));
+                    }
+                    new_values.push(try!(Datetime::parse(v, format)));
+                }
+                Ok(Indexer::new(new_values))
+            }
+            &Array::Int64Array(ref vals) => {
+                let mut new_values: Vec<Datetime> = Vec::with_capacity(vals.len());
+                for (null, v) in vals.iter_raw() {
+                    if null {
+                        return Err(DatetimeParseError::new("null", format, "null is not allowed"));
+                    }
+                    new_values.push(Datetime::new(*v));
+                }
+                Ok(Indexer::new(new_values))
+            }
+            _ => {
+                Err(DatetimeParseError::new(&values.dtype(),
+                                            format,
+                                            "unsupported dtype, expected str or i64"))
+            }
+        }
+    }
+}
+
+/// /////////////////////////////////////////////////////////////////////////////
+/// Range slicing
+/// /////////////////////////////////////////////////////////////////////////////
+
+impl Indexer<Datetime> {
+    pub fn is_monotonic(&self) -> bool {
+        self.values.windows(2).all(|w| w[0] <= w[1])
+    }
+
+    /// Return locations whose label is between start and end (inclusive)
+    pub fn time_locs(&self, start: &Datetime, end: &Datetime) -> Vec<usize> {
+        if self.is_monotonic() {
+            let first = self.values.iter().take_while(|x| *x < start).count();
+            let last = self.values.iter().take_while(|x| *x <= end).count();
+            if first < last { (first..last).collect() } else { vec![] }
+        } else {
+            self.values
+                .iter()
+                .enumerate()
+                .filter(|&(_, x)| start <= x && x <= end)
+                .map(|(i, _)| i)
+                .collect()
+        }
+    }
+
+    /// Slice using time bounds (inclusive)
+    pub fn time_slice(&self, start: &Datetime, end: &Datetime) -> Self {
+        let locs = self.time_locs(start, end);
+        self.ilocs(&locs)
+    }
+}
+
+/// /////////////////////////////////////////////////////////////////////////////
+/// Shift
+/// /////////////////////////////////////////////////////////////////////////////
+
+impl Indexer<Datetime> {
+    /// Move each label by given number of periods
+    pub fn tshift(&self, periods: i64, freq: Frequency) -> Self {
+        self.values.iter().map(|x| freq.shift(x, periods)).collect()
+    }
+}
+
+/// /////////////////////////////////////////////////////////////////////////////
+/// Field accessors
+/// /////////////////////////////////////////////////////////////////////////////
+
+macro_rules! define_field_accessor {
+    ($m:ident, $t:ident) => {
+        impl Indexer<Datetime> {
+            pub fn $m<'a>(&self) -> Series<'a, 'a, $t, Datetime> {
+                let new_values: Vec<$t> = self.values.iter().map(|x| x.$m()).collect();
+                Series::from_cow(Cow::Owned(new_values), Cow::Owned(self.clone()))
+            }
+        }
+    }
+}
+
+define_field_accessor!(year, i32);
+define_field_accessor!(month, u32);
+define_field_accessor!(day, u32);
+define_field_accessor!(hour, u32);
+define_field_accessor!(minute, u32);
+define_field_accessor!(second, u32);
+define_field_accessor!(weekday, u32);
+
+#[cfg(test)]
+mod tests {
+
+    use datetime::Datetime;
+    use super::DatetimeIndex;
+
+    #[test]
+    fn test_time_locs_sorted() {
+        let idx = DatetimeIndex::parse(&vec!["2017-01-01", "2017-01-02", "2017-01-03",
+                                             "2017-01-04"],
+                                       "%Y-%m-%d")
+            .unwrap();
+        assert!(idx.is_monotonic());
+        let locs = idx.time_locs(&Datetime::from_ymd(2017, 1, 2),
+                                 &Datetime::from_ymd(2017, 1, 3));
+        assert_eq!(locs, vec![1, 2]);
+
+        let locs = idx.time_locs(&Datetime::from_ymd(2016, 1, 1),
+                                 &Datetime::from_ymd(2016, 12, 31));
+        assert_eq!(locs, vec![]);
+    }
+
+    #[test]
+    fn test_time_locs_unsorted() {
+        let idx = DatetimeIndex::parse(&vec!["2017-01-03", "2017-01-01", "2017-01-04",
+                                             "2017-01-02"],
+                                       "%Y-%m-%d")
+            .unwrap();
+        assert!(!idx.is_monotonic());
+        let locs = idx.time_locs(&Datetime::from_ymd(2017, 1, 2),
+                                 &Datetime::from_ymd(2017, 1, 3));
+        assert_eq!(locs, vec![0, 3]);
+    }
+}
@@ -6,23 +6,33 @@ use std::iter::FromIterator;
 use std::slice;
 use std::vec;
 
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
 use nullvec::prelude::dev::algos::Indexing;
 use traits::{Slicer, IndexerIndex, Append};
 
 mod convert;
+mod datetime;
 mod formatting;
 mod indexing;
 mod ops;
 mod sort;
 
+pub use self::datetime::DatetimeIndex;
+
 /// Hash index
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "U: Deserialize<'de>")))]
 pub struct Indexer<U: Clone + Hash> {
     // index must be hashable, note that float can't be hashed
     pub values: Vec<U>,
 
     // provides interior mutability
     // ToDo: use Cow?
+    // not serialized, rebuilt on lookup after deserialization
+    #[cfg_attr(feature = "serde", serde(skip))]
     htable: RefCell<HashMap<U, usize>>,
 }
 
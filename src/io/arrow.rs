@@ -0,0 +1,375 @@
+use arrow_array;
+use arrow_array::{ArrayRef, RecordBatch, new_empty_array};
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Int8Type, Int16Type, Int32Type, Int64Type, UInt8Type, UInt16Type,
+                         UInt32Type, UInt64Type, Float32Type, Float64Type};
+use arrow_ipc::reader::{FileReader, StreamReader};
+use arrow_ipc::writer::{FileWriter, StreamWriter};
+use arrow_schema::{ArrowError, DataType, Field, Schema, SchemaRef};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::io::{Read, Seek, Write};
+use std::string::ToString;
+use std::sync::Arc;
+
+use nullvec::prelude::{Array, NullVec, Slicer};
+use nullvec::prelude::dev::NullStorable;
+
+use frame::DataFrame;
+
+/// Field metadata key storing `Array::dtype` which Arrow doesn't have
+const DTYPE_KEY: &str = "brassfibre:dtype";
+
+fn invalid<T>(message: String) -> Result<T, ArrowError> {
+    Err(ArrowError::InvalidArgumentError(message))
+}
+
+/// /////////////////////////////////////////////////////////////////////////////
+/// Conversion between Arrow and values
+/// /////////////////////////////////////////////////////////////////////////////
+
+macro_rules! to_arrow {
+    ($vals:expr, $klass:ident, $f:expr) => {
+        Arc::new($vals.iter_raw()
+            .map(|(null, v)| if null { None } else { Some($f(v)) })
+            .collect::<arrow_array::$klass>()) as ArrayRef
+    }
+}
+
+/// Convert `Array` to Arrow array, with field metadata if the dtype doesn't
+/// exist in Arrow
+fn array_to_arrow(array: &Array) -> (ArrayRef, Option<&'static str>) {
+    match *array {
+        Array::Int64Array(ref vals) => (to_arrow!(vals, Int64Array, |v: &i64| *v), None),
+        Array::Int32Array(ref vals) => (to_arrow!(vals, Int32Array, |v: &i32| *v), None),
+        Array::Int16Array(ref vals) => (to_arrow!(vals, Int16Array, |v: &i16| *v), None),
+        Array::Int8Array(ref vals) => (to_arrow!(vals, Int8Array, |v: &i8| *v), None),
+        Array::IsizeArray(ref vals) => {
+            (to_arrow!(vals, Int64Array, |v: &isize| *v as i64), Some("isize"))
+        }
+        Array::UInt64Array(ref vals) => (to_arrow!(vals, UInt64Array, |v: &u64| *v), None),
+        Array::UInt32Array(ref vals) => (to_arrow!(vals, UInt32Array, |v: &u32| *v), None),
+        Array::UInt16Array(ref vals) => (to_arrow!(vals, UInt16Array, |v: &u16| *v), None),
+        Array::UInt8Array(ref vals) => (to_arrow!(vals, UInt8Array, |v: &u8| *v), None),
+        Array::UsizeArray(ref vals) => {
+            (to_arrow!(vals, UInt64Array, |v: &usize| *v as u64), Some("usize"))
+        }
+        Array::Float64Array(ref vals) => (to_arrow!(vals, Float64Array, |v: &f64| *v), None),
+        Array::Float32Array(ref vals) => (to_arrow!(vals, Float32Array, |v: &f32| *v), None),
+        Array::BoolArray(ref vals) => (to_arrow!(vals, BooleanArray, |v: &bool| *v), None),
+        Array::StringArray(ref vals) => {
+            (to_arrow!(vals, StringArray, |v: &String| v.clone()), None)
+        }
+    }
+}
+
+fn null_mask(array: &dyn arrow_array::Array) -> Option<Vec<bool>> {
+    if array.null_count() == 0 {
+        None
+    } else {
+        Some((0..array.len()).map(|i| array.is_null(i)).collect())
+    }
+}
+
+/// Create `NullVec` filling values at null locations with default, as they
+/// are undefined in Arrow
+fn to_nullvec<T: NullStorable>(mut values: Vec<T>, mask: Option<Vec<bool>>) -> NullVec<T> {
+    if let Some(ref mask) = mask {
+        for (value, &null) in values.iter_mut().zip(mask.iter()) {
+            if null {
+                *value = T::default();
+            }
+        }
+    }
+    NullVec::with_mask(values, mask)
+}
+
+macro_rules! from_primitive {
+    ($array:expr, $t:ident, $klass:ident) => {
+        Array::$klass(to_nullvec($array.as_primitive::<$t>().values().to_vec(),
+                                 null_mask($array)))
+    }
+}
+
+/// Convert Arrow array to `Array`, following dtype in field metadata
+fn array_from_arrow(field: &Field, array: &ArrayRef) -> Result<Array, ArrowError> {
+    let array: &dyn arrow_array::Array = array.as_ref();
+    let converted = match *field.data_type() {
+        DataType::Int64 => from_primitive!(array, Int64Type, Int64Array),
+        DataType::Int32 => from_primitive!(array, Int32Type, Int32Array),
+        DataType::Int16 => from_primitive!(array, Int16Type, Int16Array),
+        DataType::Int8 => from_primitive!(array, Int8Type, Int8Array),
+        DataType::UInt64 => from_primitive!(array, UInt64Type, UInt64Array),
+        DataType::UInt32 => from_primitive!(array, UInt32Type, UInt32Array),
+        DataType::UInt16 => from_primitive!(array, UInt16Type, UInt16Array),
+        DataType::UInt8 => from_primitive!(array, UInt8Type, UInt8Array),
+        DataType::Float64 => from_primitive!(array, Float64Type, Float64Array),
+        DataType::Float32 => from_primitive!(array, Float32Type, Float32Array),
+        DataType::Boolean => {
+            let values = array.as_boolean().values().iter().collect();
+            Array::BoolArray(to_nullvec(values, null_mask(array)))
+        }
+        DataType::Utf8 => {
+            let values = (0..array.len())
+                .map(|i| array.as_string::<i32>().value(i).to_string())
+                .collect();
+            Array::StringArray(to_nullvec(values, null_mask(array)))
+        }
+        DataType::LargeUtf8 => {
+            let values = (0..array.len())
+                .map(|i| array.as_string::<i64>().value(i).to_string())
+                .collect();
+            Array::StringArray(to_nullvec(values, null_mask(array)))
+        }
+        ref other => {
+            return invalid(format!("unsupported Arrow data type {} in column '{}'",
+                                   other,
+                                   field.name()))
+        }
+    };
+    let converted = match (field.metadata().get(DTYPE_KEY).map(|x| x.as_str()), converted) {
+        (Some("isize"), Array::Int64Array(vals)) => {
+            let mask = if vals.has_null() { Some(vals.is_null()) } else { None };
+            let values = vals.iter_raw().map(|(_, &v)| v as isize).collect();
+            Array::IsizeArray(NullVec::with_mask(values, mask))
+        }
+        (Some("usize"), Array::UInt64Array(vals)) => {
+            let mask = if vals.has_null() { Some(vals.is_null()) } else { None };
+            let values = vals.iter_raw().map(|(_, &v)| v as usize).collect();
+            Array::UsizeArray(NullVec::with_mask(values, mask))
+        }
+        (_, converted) => converted,
+    };
+    Ok(converted)
+}
+
+fn concat_nullvec<T: NullStorable + Clone>(parts: Vec<&NullVec<T>>) -> NullVec<T> {
+    let has_null = parts.iter().any(|x| x.has_null());
+    let mut data: Vec<T> = vec![];
+    let mut mask: Vec<bool> = vec![];
+    for part in parts.into_iter() {
+        for (null, v) in part.iter_raw() {
+            data.push(v.clone());
+            mask.push(null);
+        }
+    }
+    NullVec::with_mask(data, if has_null { Some(mask) } else { None })
+}
+
+macro_rules! concat_arrays {
+    ($first:expr, $parts:expr, $($variant:ident),*) => {
+        match *$first {
+            $(
+                Array::$variant(_) => {
+                    let parts = $parts.iter()
+                        .map(|x| match *x {
+                            Array::$variant(ref vals) => vals,
+                            _ => unreachable!(),
+                        })
+                        .collect();
+                    Array::$variant(concat_nullvec(parts))
+                }
+            )*
+        }
+    }
+}
+
+/// Concatenate `Array`s converted from the same field
+fn concat(parts: Vec<Array>) -> Array {
+    concat_arrays!(&parts[0],
+                   parts,
+                   Int64Array,
+                   Int32Array,
+                   Int16Array,
+                   Int8Array,
+                   IsizeArray,
+                   UInt64Array,
+                   UInt32Array,
+                   UInt16Array,
+                   UInt8Array,
+                   UsizeArray,
+                   Float64Array,
+                   Float32Array,
+                   BoolArray,
+                   StringArray)
+}
+
+/// Column labels and `Array`s of record batches, concatenated
+pub fn collect_batches<B>(schema: SchemaRef,
+                          batches: B)
+                          -> Result<(Vec<String>, Vec<Array>), ArrowError>
+    where B: Iterator<Item = Result<RecordBatch, ArrowError>>
+{
+    let labels: Vec<String> = schema.fields().iter().map(|x| x.name().clone()).collect();
+    let mut parts: Vec<Vec<Array>> = Vec::with_capacity(labels.len());
+    for field in schema.fields().iter() {
+        let empty = try!(array_from_arrow(field, &new_empty_array(field.data_type())));
+        parts.push(vec![empty]);
+    }
+    for batch in batches {
+        let batch = try!(batch);
+        for ((field, column), part) in schema.fields()
+            .iter()
+            .zip(batch.columns())
+            .zip(parts.iter_mut()) {
+            part.push(try!(array_from_arrow(field, column)));
+        }
+    }
+    Ok((labels, parts.into_iter().map(concat).collect()))
+}
+
+/// /////////////////////////////////////////////////////////////////////////////
+/// DataFrame
+/// /////////////////////////////////////////////////////////////////////////////
+
+impl<'a, I, C> DataFrame<'a, 'a, 'a, I, C>
+    where I: Clone + Eq + Hash,
+          C: Clone + Eq + Hash + ToString,
+          Array: From<Vec<I>>
+{
+    /// Convert to Arrow record batch. If `index` is specified, index is
+    /// written as the first column with the name.
+    pub fn to_record_batch(&self, index: Option<&str>) -> Result<RecordBatch, ArrowError> {
+        let mut fields: Vec<Field> = Vec::with_capacity(self.values.len() + 1);
+        let mut columns: Vec<ArrayRef> = Vec::with_capacity(self.values.len() + 1);
+
+        let mut push = |label: String, array: &Array| {
+            let (column, dtype) = array_to_arrow(array);
+            let mut field = Field::new(label, column.data_type().clone(), true);
+            if let Some(dtype) = dtype {
+                let mut metadata = HashMap::new();
+                metadata.insert(DTYPE_KEY.to_string(), dtype.to_string());
+                field = field.with_metadata(metadata);
+            }
+            fields.push(field);
+            columns.push(column);
+        };
+
+        if let Some(name) = index {
+            push(name.to_string(), &Array::from(self.index.values.clone()));
+        }
+        for (label, array) in self.columns.values.iter().zip(self.values.iter()) {
+            push(label.to_string(), array);
+        }
+        RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)
+    }
+
+    /// Write Arrow IPC file format
+    pub fn write_arrow_file<W: Write>(&self,
+                                      writer: W,
+                                      index: Option<&str>)
+                                      -> Result<(), ArrowError> {
+        let batch = try!(self.to_record_batch(index));
+        let mut writer = try!(FileWriter::try_new(writer, &batch.schema()));
+        try!(writer.write(&batch));
+        writer.finish()
+    }
+
+    /// Write Arrow IPC stream format
+    pub fn write_arrow_stream<W: Write>(&self,
+                                        writer: W,
+                                        index: Option<&str>)
+                                        -> Result<(), ArrowError> {
+        let batch = try!(self.to_record_batch(index));
+        let mut writer = try!(StreamWriter::try_new(writer, &batch.schema()));
+        try!(writer.write(&batch));
+        writer.finish()
+    }
+}
+
+impl<'a, I> DataFrame<'a, 'a, 'a, I, String>
+    where I: Clone + Eq + Hash,
+          Array: From<Vec<I>>,
+          Vec<I>: From<Array>
+{
+    /// Create from Arrow record batch. If `index` is specified, the column
+    /// is used as index. Otherwise row numbers are used.
+    pub fn from_record_batch(batch: &RecordBatch,
+                             index: Option<&str>)
+                             -> Result<Self, ArrowError> {
+        let batches = vec![Ok(batch.clone())];
+        let (labels, arrays) = try!(collect_batches(batch.schema(), batches.into_iter()));
+        build_frame(labels, arrays, 0, index)
+    }
+
+    /// Read Arrow IPC file format, concatenating all record batches
+    pub fn read_arrow_file<R: Read + Seek>(reader: R,
+                                           index: Option<&str>)
+                                           -> Result<Self, ArrowError> {
+        let reader = try!(FileReader::try_new(reader, None));
+        let schema = reader.schema();
+        let (labels, arrays) = try!(collect_batches(schema, reader));
+        build_frame(labels, arrays, 0, index)
+    }
+
+    /// Read Arrow IPC stream format, concatenating all record batches
+    pub fn read_arrow_stream<R: Read>(reader: R,
+                                      index: Option<&str>)
+                                      -> Result<Self, ArrowError> {
+        let reader = try!(StreamReader::try_new(reader, None));
+        let schema = reader.schema();
+        let (labels, arrays) = try!(collect_batches(schema, reader));
+        build_frame(labels, arrays, 0, index)
+    }
+}
+
+/// Create `DataFrame` from concatenated columns, using the `index` column as
+/// index or row numbers starting from `offset` if `None`
+pub fn build_frame<'a, I>(labels: Vec<String>,
+                          mut arrays: Vec<Array>,
+                          offset: usize,
+                          index: Option<&str>)
+                          -> Result<DataFrame<'a, 'a, 'a, I, String>, ArrowError>
+    where I: Clone + Eq + Hash,
+          Array: From<Vec<I>>,
+          Vec<I>: From<Array>
+{
+    let nrows = arrays.first().map_or(0, |x| x.len());
+    let index_array = match index {
+        Some(name) => {
+            match labels.iter().position(|x| x == name) {
+                Some(loc) => arrays.remove(loc),
+                None => return invalid(format!("index column '{}' not found", name)),
+            }
+        }
+        None => <Array as From<Vec<usize>>>::from((offset..offset + nrows).collect()),
+    };
+    let name = index.unwrap_or("row number");
+    let expected = Array::from(Vec::<I>::new()).dtype();
+    if index_array.dtype() != expected {
+        return invalid(format!("index column '{}' has dtype {}, expected {}",
+                               name,
+                               index_array.dtype(),
+                               expected));
+    }
+    let values: Vec<Array> = arrays;
+    let labels: Vec<String> = labels.into_iter()
+        .filter(|x| Some(x.as_str()) != index)
+        .collect();
+    let index_values: Vec<I> = match index_array {
+        ref array if has_null(array) => {
+            return invalid(format!("index column '{}' contains null", name))
+        }
+        array => array.into(),
+    };
+    Ok(DataFrame::from_vec(values, index_values, labels))
+}
+
+fn has_null(array: &Array) -> bool {
+    match *array {
+        Array::Int64Array(ref vals) => vals.has_null(),
+        Array::Int32Array(ref vals) => vals.has_null(),
+        Array::Int16Array(ref vals) => vals.has_null(),
+        Array::Int8Array(ref vals) => vals.has_null(),
+        Array::IsizeArray(ref vals) => vals.has_null(),
+        Array::UInt64Array(ref vals) => vals.has_null(),
+        Array::UInt32Array(ref vals) => vals.has_null(),
+        Array::UInt16Array(ref vals) => vals.has_null(),
+        Array::UInt8Array(ref vals) => vals.has_null(),
+        Array::UsizeArray(ref vals) => vals.has_null(),
+        Array::Float64Array(ref vals) => vals.has_null(),
+        Array::Float32Array(ref vals) => vals.has_null(),
+        Array::BoolArray(ref vals) => vals.has_null(),
+        Array::StringArray(ref vals) => vals.has_null(),
+    }
+}
@@ -0,0 +1,421 @@
+//! Versioned binary columnar format of `DataFrame`
+//!
+//! All integers are little endian.
+//!
+//! ```text
+//! magic        b"BFDF"
+//! version      u32
+//! nrows        u64
+//! index        nrows labels as string
+//! ncols        u64
+//! directory    ncols entries of (label: string, dtype: u8, offset: u64, size: u64)
+//! blocks       a contiguous block per column, starting at its offset
+//! ```
+//!
+//! A string is its byte length as `u64` followed by UTF-8 bytes. A column
+//! block is a null flag `u8`, followed by a bitmap (bit set if null) if the
+//! flag is 1, then values. Numeric values are stored in fixed width, `isize`
+//! and `usize` as 64 bits, `bool` as a byte, and `str` as strings.
+
+use std::hash::Hash;
+use std::io::{self, Read, Write, Seek, SeekFrom};
+use std::mem;
+use std::str::FromStr;
+use std::string::ToString;
+
+#[cfg(feature = "mmap")]
+use memmap2::Mmap;
+#[cfg(feature = "mmap")]
+use std::fs::File;
+#[cfg(feature = "mmap")]
+use std::path::Path;
+
+use nullvec::prelude::{Array, NullVec, Slicer};
+use nullvec::prelude::dev::NullStorable;
+
+use frame::DataFrame;
+
+const MAGIC: &[u8; 4] = b"BFDF";
+const VERSION: u32 = 1;
+
+fn invalid<T>(message: String) -> io::Result<T> {
+    Err(io::Error::new(io::ErrorKind::InvalidData, message))
+}
+
+/// /////////////////////////////////////////////////////////////////////////////
+/// Encoding
+/// /////////////////////////////////////////////////////////////////////////////
+
+fn put_u64(buf: &mut Vec<u8>, value: u64) {
+    buf.extend_from_slice(&value.to_le_bytes());
+}
+
+fn put_str(buf: &mut Vec<u8>, value: &str) {
+    put_u64(buf, value.len() as u64);
+    buf.extend_from_slice(value.as_bytes());
+}
+
+/// Write null flag and bitmap, returning raw values whose nulls are default
+fn put_nulls<T: NullStorable + Clone>(buf: &mut Vec<u8>, values: &NullVec<T>) -> Vec<T> {
+    let mut raw: Vec<T> = Vec::with_capacity(values.len());
+    let mut bitmap: Vec<u8> = vec![0; (values.len() + 7) / 8];
+    for (i, (null, v)) in values.iter_raw().enumerate() {
+        if null {
+            bitmap[i / 8] |= 1 << (i % 8);
+            raw.push(T::default());
+        } else {
+            raw.push(v.clone());
+        }
+    }
+    if values.has_null() {
+        buf.push(1);
+        buf.extend_from_slice(&bitmap);
+    } else {
+        buf.push(0);
+    }
+    raw
+}
+
+macro_rules! encode_fixed {
+    ($buf:expr, $values:expr, $cast:ty) => {{
+        for v in put_nulls($buf, $values).into_iter() {
+            $buf.extend_from_slice(&(v as $cast).to_le_bytes());
+        }
+    }}
+}
+
+/// Return dtype code and encoded block of the `Array`
+fn encode_array(array: &Array) -> (u8, Vec<u8>) {
+    let mut buf: Vec<u8> = vec![];
+    let code = match *array {
+        Array::Int64Array(ref vals) => { encode_fixed!(&mut buf, vals, i64); 0 }
+        Array::Int32Array(ref vals) => { encode_fixed!(&mut buf, vals, i32); 1 }
+        Array::Int16Array(ref vals) => { encode_fixed!(&mut buf, vals, i16); 2 }
+        Array::Int8Array(ref vals) => { encode_fixed!(&mut buf, vals, i8); 3 }
+        Array::IsizeArray(ref vals) => { encode_fixed!(&mut buf, vals, i64); 4 }
+        Array::UInt64Array(ref vals) => { encode_fixed!(&mut buf, vals, u64); 5 }
+        Array::UInt32Array(ref vals) => { encode_fixed!(&mut buf, vals, u32); 6 }
+        Array::UInt16Array(ref vals) => { encode_fixed!(&mut buf, vals, u16); 7 }
+        Array::UInt8Array(ref vals) => { encode_fixed!(&mut buf, vals, u8); 8 }
+        Array::UsizeArray(ref vals) => { encode_fixed!(&mut buf, vals, u64); 9 }
+        Array::Float64Array(ref vals) => { encode_fixed!(&mut buf, vals, f64); 10 }
+        Array::Float32Array(ref vals) => { encode_fixed!(&mut buf, vals, f32); 11 }
+        Array::BoolArray(ref vals) => { encode_fixed!(&mut buf, vals, u8); 12 }
+        Array::StringArray(ref vals) => {
+            for v in put_nulls(&mut buf, vals).iter() {
+                put_str(&mut buf, v);
+            }
+            13
+        }
+    };
+    (code, buf)
+}
+
+/// /////////////////////////////////////////////////////////////////////////////
+/// Decoding
+/// /////////////////////////////////////////////////////////////////////////////
+
+/// Read `len` bytes, which must not exceed `remaining` bytes of the input
+/// so that a corrupted length never allocates beyond the input
+fn get_bytes<R: Read>(reader: &mut R, len: u64, remaining: &mut u64) -> io::Result<Vec<u8>> {
+    if len > *remaining {
+        return invalid(format!("expected {} bytes, but only {} bytes remain", len, *remaining));
+    }
+    *remaining -= len;
+    let mut buf: Vec<u8> = vec![0; len as usize];
+    try!(reader.read_exact(&mut buf));
+    Ok(buf)
+}
+
+fn get_u64<R: Read>(reader: &mut R, remaining: &mut u64) -> io::Result<u64> {
+    let bytes = try!(get_bytes(reader, 8, remaining));
+    let mut buf = [0u8; 8];
+    buf.copy_from_slice(&bytes);
+    Ok(u64::from_le_bytes(buf))
+}
+
+fn get_string<R: Read>(reader: &mut R, remaining: &mut u64) -> io::Result<String> {
+    let len = try!(get_u64(reader, remaining));
+    match String::from_utf8(try!(get_bytes(reader, len, remaining))) {
+        Ok(s) => Ok(s),
+        Err(_) => invalid("string is not valid UTF-8".to_string()),
+    }
+}
+
+/// Check that `count` items of at least `width` bytes fit in `remaining`
+/// bytes, before allocating for them
+fn check_count(count: u64, width: u64, remaining: u64, what: &str) -> io::Result<usize> {
+    match count.checked_mul(width) {
+        Some(n) if n <= remaining => Ok(count as usize),
+        _ => invalid(format!("{} {} exceeds the input", what, count)),
+    }
+}
+
+struct Entry {
+    label: String,
+    dtype: u8,
+    offset: u64,
+    size: u64,
+}
+
+struct Header {
+    nrows: usize,
+    index: Vec<String>,
+    entries: Vec<Entry>,
+}
+
+/// Read header from the start of the input of `len` bytes
+fn read_header<R: Read>(reader: &mut R, len: u64) -> io::Result<Header> {
+    let mut remaining = len;
+    let magic = try!(get_bytes(reader, 4, &mut remaining));
+    if magic != MAGIC {
+        return invalid("not a brassfibre binary file".to_string());
+    }
+    let version = try!(get_bytes(reader, 4, &mut remaining));
+    let version = u32::from_le_bytes([version[0], version[1], version[2], version[3]]);
+    if version != VERSION {
+        return invalid(format!("unsupported binary format version {}", version));
+    }
+
+    // each label has at least its length
+    let nrows = try!(get_u64(reader, &mut remaining));
+    let nrows = try!(check_count(nrows, 8, remaining, "number of rows"));
+    let mut index: Vec<String> = Vec::with_capacity(nrows);
+    for _ in 0..nrows {
+        index.push(try!(get_string(reader, &mut remaining)));
+    }
+    // each entry has at least label length, dtype, offset and size
+    let ncols = try!(get_u64(reader, &mut remaining));
+    let ncols = try!(check_count(ncols, 8 + 1 + 8 + 8, remaining, "number of columns"));
+    let mut entries: Vec<Entry> = Vec::with_capacity(ncols);
+    for _ in 0..ncols {
+        let label = try!(get_string(reader, &mut remaining));
+        let dtype = try!(get_bytes(reader, 1, &mut remaining))[0];
+        let offset = try!(get_u64(reader, &mut remaining));
+        let size = try!(get_u64(reader, &mut remaining));
+        match offset.checked_add(size) {
+            Some(end) if end <= len => {}
+            _ => return invalid(format!("block of column '{}' exceeds the input", label)),
+        }
+        entries.push(Entry {
+            label: label,
+            dtype: dtype,
+            offset: offset,
+            size: size,
+        });
+    }
+    Ok(Header {
+        nrows: nrows,
+        index: index,
+        entries: entries,
+    })
+}
+
+/// Split block into null mask and values
+fn take_nulls(block: &[u8], nrows: usize) -> io::Result<(Option<Vec<bool>>, &[u8])> {
+    match block.first() {
+        Some(&0) => Ok((None, &block[1..])),
+        Some(&1) if block.len() > (nrows + 7) / 8 => {
+            let bitmap = &block[1..1 + (nrows + 7) / 8];
+            let mask = (0..nrows).map(|i| bitmap[i / 8] & (1 << (i % 8)) != 0).collect();
+            Ok((Some(mask), &block[1 + bitmap.len()..]))
+        }
+        _ => invalid("invalid null bitmap".to_string()),
+    }
+}
+
+macro_rules! decode_fixed {
+    ($values:expr, $nrows:expr, $mask:expr, $variant:ident, $stored:ty, $convert:expr) => {{
+        let width = mem::size_of::<$stored>();
+        if $values.len() != $nrows * width {
+            return invalid(format!("expected {} bytes of values, found {}",
+                                   $nrows * width,
+                                   $values.len()));
+        }
+        let decoded = $values.chunks(width)
+            .map(|x| {
+                let mut bytes = [0u8; mem::size_of::<$stored>()];
+                bytes.copy_from_slice(x);
+                $convert(<$stored>::from_le_bytes(bytes))
+            })
+            .collect();
+        Array::$variant(NullVec::with_mask(decoded, $mask))
+    }}
+}
+
+fn decode_strings(mut values: &[u8], nrows: usize) -> io::Result<Vec<String>> {
+    let mut remaining = values.len() as u64;
+    let mut strings: Vec<String> = Vec::with_capacity(nrows);
+    for _ in 0..nrows {
+        strings.push(try!(get_string(&mut values, &mut remaining)));
+    }
+    if !values.is_empty() {
+        return invalid("unexpected bytes after values".to_string());
+    }
+    Ok(strings)
+}
+
+fn decode_array(block: &[u8], dtype: u8, nrows: usize) -> io::Result<Array> {
+    let (mask, values) = try!(take_nulls(block, nrows));
+    let array = match dtype {
+        0 => decode_fixed!(values, nrows, mask, Int64Array, i64, |v| v),
+        1 => decode_fixed!(values, nrows, mask, Int32Array, i32, |v| v),
+        2 => decode_fixed!(values, nrows, mask, Int16Array, i16, |v| v),
+        3 => decode_fixed!(values, nrows, mask, Int8Array, i8, |v| v),
+        4 => decode_fixed!(values, nrows, mask, IsizeArray, i64, |v| v as isize),
+        5 => decode_fixed!(values, nrows, mask, UInt64Array, u64, |v| v),
+        6 => decode_fixed!(values, nrows, mask, UInt32Array, u32, |v| v),
+        7 => decode_fixed!(values, nrows, mask, UInt16Array, u16, |v| v),
+        8 => decode_fixed!(values, nrows, mask, UInt8Array, u8, |v| v),
+        9 => decode_fixed!(values, nrows, mask, UsizeArray, u64, |v| v as usize),
+        10 => decode_fixed!(values, nrows, mask, Float64Array, f64, |v| v),
+        11 => decode_fixed!(values, nrows, mask, Float32Array, f32, |v| v),
+        12 => decode_fixed!(values, nrows, mask, BoolArray, u8, |v| v != 0),
+        13 => Array::StringArray(NullVec::with_mask(try!(decode_strings(values, nrows)), mask)),
+        _ => return invalid(format!("unknown dtype code {}", dtype)),
+    };
+    Ok(array)
+}
+
+/// Entries to be read, keeping the order in the file
+fn select<'h>(header: &'h Header, columns: Option<&[&str]>) -> io::Result<Vec<&'h Entry>> {
+    match columns {
+        None => Ok(header.entries.iter().collect()),
+        Some(columns) => {
+            for label in columns.iter() {
+                if !header.entries.iter().any(|x| x.label == *label) {
+                    return invalid(format!("column '{}' not found", label));
+                }
+            }
+            Ok(header.entries.iter().filter(|x| columns.contains(&x.label.as_str())).collect())
+        }
+    }
+}
+
+fn build_frame<'a, I>(header: &Header,
+                      entries: Vec<&Entry>,
+                      values: Vec<Array>)
+                      -> io::Result<DataFrame<'a, 'a, 'a, I, String>>
+    where I: Clone + Eq + Hash + FromStr
+{
+    let mut index: Vec<I> = Vec::with_capacity(header.nrows);
+    for label in header.index.iter() {
+        match label.parse::<I>() {
+            Ok(v) => index.push(v),
+            Err(_) => return invalid(format!("unable to parse '{}' as index", label)),
+        }
+    }
+    let columns: Vec<String> = entries.iter().map(|x| x.label.clone()).collect();
+    Ok(DataFrame::from_vec(values, index, columns))
+}
+
+/// /////////////////////////////////////////////////////////////////////////////
+/// DataFrame
+/// /////////////////////////////////////////////////////////////////////////////
+
+impl<'a, I, C> DataFrame<'a, 'a, 'a, I, C>
+    where I: Clone + Eq + Hash + ToString,
+          C: Clone + Eq + Hash + ToString
+{
+    /// Write in the binary columnar format. Index and column labels are
+    /// stored as string.
+    pub fn write_binary<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let blocks: Vec<(u8, Vec<u8>)> = self.values.iter().map(|x| encode_array(x)).collect();
+        let labels: Vec<String> = self.columns.values.iter().map(|x| x.to_string()).collect();
+
+        let mut header: Vec<u8> = vec![];
+        header.extend_from_slice(MAGIC);
+        header.extend_from_slice(&VERSION.to_le_bytes());
+        put_u64(&mut header, self.index.values.len() as u64);
+        for label in self.index.values.iter() {
+            put_str(&mut header, &label.to_string());
+        }
+        put_u64(&mut header, labels.len() as u64);
+
+        // directory size is fixed regardless of offsets
+        let directory_len: usize = labels.iter().map(|x| 8 + x.len() + 1 + 8 + 8).sum();
+        let mut offset = (header.len() + directory_len) as u64;
+        for (label, &(code, ref block)) in labels.iter().zip(blocks.iter()) {
+            put_str(&mut header, label);
+            header.push(code);
+            put_u64(&mut header, offset);
+            put_u64(&mut header, block.len() as u64);
+            offset += block.len() as u64;
+        }
+
+        try!(writer.write_all(&header));
+        for &(_, ref block) in blocks.iter() {
+            try!(writer.write_all(block));
+        }
+        writer.flush()
+    }
+}
+
+impl<'a, I> DataFrame<'a, 'a, 'a, I, String>
+    where I: Clone + Eq + Hash + FromStr
+{
+    /// Read all columns written by `write_binary`
+    pub fn read_binary<R: Read + Seek>(reader: R) -> io::Result<Self> {
+        DataFrame::read_binary_impl(reader, None)
+    }
+
+    /// Read specified columns written by `write_binary`, without decoding
+    /// other columns. Columns keep the order in the file.
+    pub fn read_binary_columns<R: Read + Seek>(reader: R, columns: &[&str]) -> io::Result<Self> {
+        DataFrame::read_binary_impl(reader, Some(columns))
+    }
+
+    fn read_binary_impl<R: Read + Seek>(mut reader: R,
+                                        columns: Option<&[&str]>)
+                                        -> io::Result<Self> {
+        let len = try!(reader.seek(SeekFrom::End(0)));
+        try!(reader.seek(SeekFrom::Start(0)));
+        let header = try!(read_header(&mut reader, len));
+        let entries = try!(select(&header, columns));
+        let mut values: Vec<Array> = Vec::with_capacity(entries.len());
+        for entry in entries.iter() {
+            try!(reader.seek(SeekFrom::Start(entry.offset)));
+            // bounds are checked by read_header
+            let mut remaining = entry.size;
+            let block = try!(get_bytes(&mut reader, entry.size, &mut remaining));
+            values.push(try!(decode_array(&block, entry.dtype, header.nrows)));
+        }
+        build_frame(&header, entries, values)
+    }
+
+    /// Read all columns by memory-mapping the file. Only the I/O is mapped:
+    /// blocks of selected columns are decoded (copied) from the mapped memory
+    /// into new arrays, and pages of other columns are not read from the disk.
+    ///
+    /// The file must not be modified while reading.
+    #[cfg(feature = "mmap")]
+    pub fn read_binary_mmap<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        DataFrame::read_binary_mmap_impl(path, None)
+    }
+
+    /// Read specified columns by memory-mapping the file
+    #[cfg(feature = "mmap")]
+    pub fn read_binary_mmap_columns<P: AsRef<Path>>(path: P,
+                                                    columns: &[&str])
+                                                    -> io::Result<Self> {
+        DataFrame::read_binary_mmap_impl(path, Some(columns))
+    }
+
+    #[cfg(feature = "mmap")]
+    fn read_binary_mmap_impl<P: AsRef<Path>>(path: P,
+                                             columns: Option<&[&str]>)
+                                             -> io::Result<Self> {
+        let file = try!(File::open(path));
+        let mmap = try!(unsafe { Mmap::map(&file) });
+        let header = try!(read_header(&mut &mmap[..], mmap.len() as u64));
+        let entries = try!(select(&header, columns));
+        let mut values: Vec<Array> = Vec::with_capacity(entries.len());
+        for entry in entries.iter() {
+            // bounds are checked against the file length by read_header
+            let start = entry.offset as usize;
+            let end = start + entry.size as usize;
+            values.push(try!(decode_array(&mmap[start..end], entry.dtype, header.nrows)));
+        }
+        build_frame(&header, entries, values)
+    }
+}
@@ -0,0 +1,106 @@
+use csv;
+use std::hash::Hash;
+use std::io::Read;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use frame::DataFrame;
+use super::options::CsvReadOptions;
+use super::reader::{self, RecordReader};
+
+/// Iterator reading CSV as `DataFrame`s of at most `chunksize` rows
+///
+/// Dtypes are inferred from the first chunk, and following chunks are
+/// parsed using the same dtypes. Thus a chunk which cannot be parsed as
+/// the first one results in an error.
+pub struct CsvChunks<'a, R, I> {
+    records: RecordReader<csv::Reader<R>>,
+    chunksize: usize,
+    options: CsvReadOptions,
+    offset: usize,
+    done: bool,
+    phantom: PhantomData<(&'a (), I)>,
+}
+
+impl<'a, R, I> CsvChunks<'a, R, I>
+    where R: Read,
+          I: 'a + Clone + Eq + Hash + FromStr
+{
+    fn new(records: RecordReader<csv::Reader<R>>, chunksize: usize, options: &CsvReadOptions) -> Self {
+        assert!(chunksize > 0, "chunksize must be positive");
+        CsvChunks {
+            records: records,
+            chunksize: chunksize,
+            options: options.clone(),
+            offset: 0,
+            done: false,
+            phantom: PhantomData,
+        }
+    }
+
+    fn read_chunk(&mut self) -> Result<Option<DataFrame<'a, 'a, 'a, I, String>>, csv::Error> {
+        let colvecs = try!(self.records.read_fields(Some(self.chunksize), &self.options));
+        let nrows = self.records.nrows() - self.offset;
+        if nrows == 0 {
+            return Ok(None);
+        }
+        let df = try!(reader::build_frame(self.records.columns(),
+                                          colvecs,
+                                          self.offset,
+                                          &self.options));
+        if self.offset == 0 {
+            // fix dtypes inferred from the first chunk
+            for (label, values) in df.columns.iter().zip(df.values.iter()) {
+                if !self.options.parse_dates.contains(label) {
+                    self.options.dtype.entry(label.clone()).or_insert(values.dtype());
+                }
+            }
+        }
+        self.offset += nrows;
+        Ok(Some(df))
+    }
+}
+
+impl<'a, R, I> Iterator for CsvChunks<'a, R, I>
+    where R: Read,
+          I: 'a + Clone + Eq + Hash + FromStr
+{
+    type Item = Result<DataFrame<'a, 'a, 'a, I, String>, csv::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.read_chunk() {
+            Ok(Some(df)) => Some(Ok(df)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+impl<'a, I> DataFrame<'a, 'a, 'a, I, String>
+    where I: Clone + Eq + Hash + FromStr
+{
+    /// Read CSV lazily as `DataFrame`s of at most `chunksize` rows
+    pub fn read_csv_chunked<R: Read>(reader: csv::Reader<R>,
+                                     chunksize: usize)
+                                     -> Result<CsvChunks<'a, R, I>, csv::Error> {
+        DataFrame::read_csv_chunked_with(reader, chunksize, &CsvReadOptions::new())
+    }
+
+    /// Same as `read_csv_chunked`, following passed options
+    pub fn read_csv_chunked_with<R: Read>(reader: csv::Reader<R>,
+                                          chunksize: usize,
+                                          options: &CsvReadOptions)
+                                          -> Result<CsvChunks<'a, R, I>, csv::Error> {
+        let records = try!(RecordReader::new(reader, options));
+        Ok(CsvChunks::new(records, chunksize, options))
+    }
+}
@@ -0,0 +1,137 @@
+use std::cmp;
+use std::io::{self, BufRead, BufReader, Read};
+
+use super::options::CsvReadOptions;
+
+/// Reader removing comments from each line of the input before it is parsed.
+/// The comment character is regarded as a comment only outside quotes, thus
+/// quoted fields (which may span lines) can contain it. Line terminators are
+/// kept, so that comment-only lines are read as blank lines.
+///
+/// `DataFrame::read_csv_with` takes a built `csv::Reader` which has already
+/// removed quotes from fields, thus its input must be wrapped to skip comments.
+/// `DataFrame::read_csv_from` wraps the raw input following options.
+pub struct CommentFilter<R> {
+    reader: BufReader<R>,
+    comment: Option<Vec<u8>>,
+    quote: Option<u8>,
+    escape: Option<u8>,
+    // whether the end of the current line is in a quoted field
+    quoted: bool,
+    line: Vec<u8>,
+    pos: usize,
+}
+
+impl<R: Read> CommentFilter<R> {
+    /// Create with `"` as quote character and no escape character, the same
+    /// as the defaults of `csv::Reader`
+    pub fn new(reader: R, comment: char) -> Self {
+        CommentFilter::with_comment(reader, Some(comment))
+    }
+
+    /// Filter following `comment` in options, passing the input through if
+    /// it is not specified
+    pub fn from_options(reader: R, options: &CsvReadOptions) -> Self {
+        CommentFilter::with_comment(reader, options.comment)
+    }
+
+    fn with_comment(reader: R, comment: Option<char>) -> Self {
+        CommentFilter {
+            reader: BufReader::new(reader),
+            comment: comment.map(|c| c.to_string().into_bytes()),
+            quote: Some(b'"'),
+            escape: None,
+            quoted: false,
+            line: vec![],
+            pos: 0,
+        }
+    }
+
+    pub fn quote(mut self, quote: Option<u8>) -> Self {
+        self.quote = quote;
+        self
+    }
+
+    pub fn escape(mut self, escape: Option<u8>) -> Self {
+        self.escape = escape;
+        self
+    }
+
+    /// Drop the comment from the current line, keeping the line terminator
+    fn strip(&mut self) {
+        let comment = match self.comment {
+            Some(ref comment) => comment,
+            None => return,
+        };
+        let mut i = 0;
+        while i < self.line.len() {
+            let c = self.line[i];
+            if self.escape == Some(c) {
+                i += 2;
+                continue;
+            }
+            if self.quote == Some(c) {
+                // doubled quotes toggle twice, thus are kept as quoted
+                self.quoted = !self.quoted;
+            } else if !self.quoted && self.line[i..].starts_with(comment) {
+                let term = self.line
+                    .iter()
+                    .rev()
+                    .take_while(|&&x| x == b'\n' || x == b'\r')
+                    .count();
+                let end = self.line.len() - term;
+                self.line.drain(i..end);
+                return;
+            }
+            i += 1;
+        }
+    }
+}
+
+impl<R: Read> Read for CommentFilter<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.pos == self.line.len() {
+            self.line.clear();
+            self.pos = 0;
+            if try!(self.reader.read_until(b'\n', &mut self.line)) == 0 {
+                return Ok(0);
+            }
+            self.strip();
+        }
+        let n = cmp::min(buf.len(), self.line.len() - self.pos);
+        buf[..n].copy_from_slice(&self.line[self.pos..self.pos + n]);
+        self.pos += n;
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::io::Read;
+
+    use super::CommentFilter;
+
+    fn read_all(mut filter: CommentFilter<&[u8]>) -> String {
+        let mut res = String::new();
+        filter.read_to_string(&mut res).unwrap();
+        res
+    }
+
+    #[test]
+    fn test_comment_filter() {
+        let data = "# header\r\na,b # x\n\"c#d\",\"e\"\"#\" # y\n\"f\n#g\",h";
+        let res = read_all(CommentFilter::new(data.as_bytes(), '#'));
+        assert_eq!(res, "\r\na,b \n\"c#d\",\"e\"\"#\" \n\"f\n#g\",h");
+
+        let data = "'a\\'#b' #c\n";
+        let filter = CommentFilter::new(data.as_bytes(), '#').quote(Some(b'\'')).escape(Some(b'\\'));
+        let res = read_all(filter);
+        assert_eq!(res, "'a\\'#b' \n");
+
+        // multibyte comment character without quotes
+        let data = "\"a§b\n";
+        let res = read_all(CommentFilter::new(data.as_bytes(), '§').quote(None));
+        assert_eq!(res, "\"a\n");
+    }
+}
@@ -0,0 +1,517 @@
+use serde::Serialize;
+use serde::de::{DeserializeOwned, IntoDeserializer};
+use serde::de::value::F64Deserializer;
+use serde_json;
+use serde_json::{Map, Number, Value};
+use std::collections::HashMap;
+use std::error;
+use std::f64;
+use std::fmt;
+use std::hash::Hash;
+use std::io::Read;
+use std::string::ToString;
+
+use nullvec::prelude::{Array, NullVec, Scalar};
+
+use frame::DataFrame;
+use series::Series;
+use traits::{Slicer, RowIndex};
+
+/// Layout of JSON, named the same as pandas
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum JsonOrient {
+    /// `[{column -> value}, ...]`, index is not written
+    Records,
+    /// `{column -> {index -> value}}`
+    Columns,
+    /// `{index -> {column -> value}}`
+    Index,
+    /// `{"columns": [columns], "index": [index], "data": [[values], ...]}`
+    Split,
+    /// `[[values], ...]`, index and columns are not written
+    Values,
+}
+
+#[derive(Debug)]
+pub enum JsonError {
+    /// Invalid JSON
+    Json(serde_json::Error),
+    /// Valid JSON which cannot be represented in the orient
+    Format(String),
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            JsonError::Json(ref e) => write!(f, "JSON error: {}", e),
+            JsonError::Format(ref msg) => write!(f, "JSON format error: {}", msg),
+        }
+    }
+}
+
+impl error::Error for JsonError {
+    fn description(&self) -> &str {
+        match *self {
+            JsonError::Json(_) => "JSON error",
+            JsonError::Format(_) => "JSON format error",
+        }
+    }
+}
+
+impl From<serde_json::Error> for JsonError {
+    fn from(e: serde_json::Error) -> Self {
+        JsonError::Json(e)
+    }
+}
+
+fn format_error<T>(message: String) -> Result<T, JsonError> {
+    Err(JsonError::Format(message))
+}
+
+/// /////////////////////////////////////////////////////////////////////////////
+/// Conversion between JSON and values
+/// /////////////////////////////////////////////////////////////////////////////
+
+fn scalar_to_value(value: Scalar) -> Value {
+    match value {
+        Scalar::i64(v) => Value::from(v),
+        Scalar::i32(v) => Value::from(v),
+        Scalar::i16(v) => Value::from(v),
+        Scalar::i8(v) => Value::from(v),
+        Scalar::isize(v) => Value::from(v as i64),
+        Scalar::u64(v) => Value::from(v),
+        Scalar::u32(v) => Value::from(v),
+        Scalar::u16(v) => Value::from(v),
+        Scalar::u8(v) => Value::from(v),
+        Scalar::usize(v) => Value::from(v as u64),
+        Scalar::f64(v) => Number::from_f64(v).map_or(Value::Null, Value::Number),
+        Scalar::f32(v) => Number::from_f64(v as f64).map_or(Value::Null, Value::Number),
+        Scalar::bool(v) => Value::Bool(v),
+        Scalar::String(v) => Value::String(v),
+        Scalar::Null => Value::Null,
+    }
+}
+
+/// Convert JSON values to `Array`, in the order of i64, u64, f64, bool and
+/// str. Columns without non-null values are f64.
+fn values_to_array(name: &str, values: Vec<Value>) -> Result<Array, JsonError> {
+    let mask: Vec<bool> = values.iter().map(|x| x.is_null()).collect();
+    let mask = if mask.iter().any(|&x| x) { Some(mask) } else { None };
+
+    let non_null = || values.iter().filter(|x| !x.is_null());
+    if non_null().any(|x| x.is_array() || x.is_object()) {
+        return format_error(format!("column '{}' contains nested value", name));
+    }
+
+    macro_rules! collect {
+        ($f:expr, $variant:ident) => {
+            Array::$variant(NullVec::with_mask(values.iter().map($f).collect(), mask))
+        }
+    }
+
+    let array = if non_null().count() == 0 {
+        collect!(|_| 0., Float64Array)
+    } else if non_null().all(|x| x.is_i64()) {
+        collect!(|x| x.as_i64().unwrap_or_default(), Int64Array)
+    } else if non_null().all(|x| x.is_u64()) {
+        collect!(|x| x.as_u64().unwrap_or_default(), UInt64Array)
+    } else if non_null().all(|x| x.is_number()) {
+        collect!(|x| x.as_f64().unwrap_or_default(), Float64Array)
+    } else if non_null().all(|x| x.is_boolean()) {
+        collect!(|x| x.as_bool().unwrap_or_default(), BoolArray)
+    } else if non_null().all(|x| x.is_string()) {
+        collect!(|x| x.as_str().unwrap_or_default().to_string(), StringArray)
+    } else {
+        return format_error(format!("column '{}' contains mixed types", name));
+    };
+    Ok(array)
+}
+
+/// Object key representing the label
+fn label_to_key<T: Serialize>(label: &T) -> Result<String, JsonError> {
+    match try!(serde_json::to_value(label)) {
+        Value::String(s) => Ok(s),
+        other => Ok(other.to_string()),
+    }
+}
+
+/// Label from object key, which is string or JSON text (like number)
+fn key_to_label<T: DeserializeOwned>(key: String) -> Result<T, JsonError> {
+    match serde_json::from_str(&key) {
+        Ok(label) => Ok(label),
+        Err(_) => Ok(try!(serde_json::from_value(Value::String(key)))),
+    }
+}
+
+fn value_to_label<T: DeserializeOwned>(value: Value) -> Result<T, JsonError> {
+    match value {
+        Value::String(key) => key_to_label(key),
+        other => Ok(try!(serde_json::from_value(other))),
+    }
+}
+
+/// Series value, where null is read as the dtype's own null if it has one
+/// (e.g. `None`), otherwise as NaN (written from NaN). Thus null is still an
+/// error for dtypes which cannot hold either.
+fn value_to_element<T: DeserializeOwned>(value: Value) -> Result<T, JsonError> {
+    match value {
+        Value::Null => {
+            if let Ok(v) = serde_json::from_value(Value::Null) {
+                return Ok(v);
+            }
+            let nan: F64Deserializer<serde_json::Error> = f64::NAN.into_deserializer();
+            Ok(try!(T::deserialize(nan)))
+        }
+        other => Ok(try!(serde_json::from_value(other))),
+    }
+}
+
+fn row_numbers<T: DeserializeOwned>(n: usize) -> Result<Vec<T>, JsonError> {
+    (0..n).map(|i| Ok(try!(serde_json::from_value(Value::from(i))))).collect()
+}
+
+fn into_object(value: Value, what: &str) -> Result<Map<String, Value>, JsonError> {
+    match value {
+        Value::Object(map) => Ok(map),
+        _ => format_error(format!("{} must be an object", what)),
+    }
+}
+
+fn into_array(value: Value, what: &str) -> Result<Vec<Value>, JsonError> {
+    match value {
+        Value::Array(values) => Ok(values),
+        _ => format_error(format!("{} must be an array", what)),
+    }
+}
+
+/// Collect keyed rows to columns, filling missing keys with null
+struct KeyedRows {
+    keys: Vec<String>,
+    locs: HashMap<String, usize>,
+    columns: Vec<Vec<Value>>,
+    nrows: usize,
+}
+
+impl KeyedRows {
+    fn new() -> Self {
+        KeyedRows {
+            keys: vec![],
+            locs: HashMap::new(),
+            columns: vec![],
+            nrows: 0,
+        }
+    }
+
+    fn push(&mut self, row: Map<String, Value>) {
+        for (key, value) in row.into_iter() {
+            let loc = match self.locs.get(&key) {
+                Some(&loc) => loc,
+                None => {
+                    self.locs.insert(key.clone(), self.keys.len());
+                    self.keys.push(key);
+                    self.columns.push(vec![Value::Null; self.nrows]);
+                    self.keys.len() - 1
+                }
+            };
+            self.columns[loc].push(value);
+        }
+        self.nrows += 1;
+        for column in self.columns.iter_mut() {
+            if column.len() < self.nrows {
+                column.push(Value::Null);
+            }
+        }
+    }
+}
+
+fn transpose(rows: Vec<Vec<Value>>, ncols: usize) -> Vec<Vec<Value>> {
+    let mut columns: Vec<Vec<Value>> = vec![Vec::with_capacity(rows.len()); ncols];
+    for row in rows.into_iter() {
+        for (column, value) in columns.iter_mut().zip(row) {
+            column.push(value);
+        }
+    }
+    columns
+}
+
+/// Rows of arrays which must have the same length as columns
+fn array_rows(data: Value, ncols: Option<usize>) -> Result<(Vec<Vec<Value>>, usize), JsonError> {
+    let mut rows: Vec<Vec<Value>> = vec![];
+    for row in try!(into_array(data, "data")).into_iter() {
+        rows.push(try!(into_array(row, "row")));
+    }
+    let ncols = ncols.unwrap_or(rows.first().map_or(0, |x| x.len()));
+    for row in rows.iter() {
+        if row.len() != ncols {
+            return format_error(format!("expected {} values in a row, found {}",
+                                        ncols,
+                                        row.len()));
+        }
+    }
+    Ok((rows, ncols))
+}
+
+/// /////////////////////////////////////////////////////////////////////////////
+/// DataFrame
+/// /////////////////////////////////////////////////////////////////////////////
+
+impl<'a, I, C> DataFrame<'a, 'a, 'a, I, C>
+    where I: Clone + Eq + Hash + Serialize,
+          C: Clone + Eq + Hash + ToString
+{
+    fn json_rows(&self) -> Vec<Vec<Value>> {
+        (0..self.len())
+            .map(|i| self.values.iter().map(|x| scalar_to_value(x.iloc(&i))).collect())
+            .collect()
+    }
+
+    fn json_columns(&self) -> Vec<String> {
+        self.columns.values.iter().map(|x| x.to_string()).collect()
+    }
+
+    fn json_keys(&self) -> Result<Vec<String>, JsonError> {
+        self.index.values.iter().map(label_to_key).collect()
+    }
+
+    /// Convert to JSON value with the orient
+    pub fn to_json_value(&self, orient: JsonOrient) -> Result<Value, JsonError> {
+        let columns = self.json_columns();
+        let rows = self.json_rows();
+
+        let zip_object = |keys: &[String], values: Vec<Value>| -> Value {
+            Value::Object(keys.iter().cloned().zip(values).collect())
+        };
+
+        let value = match orient {
+            JsonOrient::Records => {
+                Value::Array(rows.into_iter().map(|x| zip_object(&columns, x)).collect())
+            }
+            JsonOrient::Columns => {
+                let keys = try!(self.json_keys());
+                let map: Map<String, Value> = columns.iter()
+                    .cloned()
+                    .zip(transpose(rows, columns.len()))
+                    .map(|(c, x)| (c, zip_object(&keys, x)))
+                    .collect();
+                Value::Object(map)
+            }
+            JsonOrient::Index => {
+                let keys = try!(self.json_keys());
+                let map: Map<String, Value> = keys.into_iter()
+                    .zip(rows)
+                    .map(|(k, x)| (k, zip_object(&columns, x)))
+                    .collect();
+                Value::Object(map)
+            }
+            JsonOrient::Split => {
+                let mut map = Map::new();
+                let columns: Vec<Value> = columns.into_iter().map(Value::String).collect();
+                map.insert("columns".to_string(), Value::Array(columns));
+                map.insert("index".to_string(),
+                           try!(serde_json::to_value(&self.index.values)));
+                let data: Vec<Value> = rows.into_iter().map(Value::Array).collect();
+                map.insert("data".to_string(), Value::Array(data));
+                Value::Object(map)
+            }
+            JsonOrient::Values => Value::Array(rows.into_iter().map(Value::Array).collect()),
+        };
+        Ok(value)
+    }
+
+    /// Write JSON with the orient. Null is written as `null`.
+    pub fn to_json(&self, orient: JsonOrient) -> Result<String, JsonError> {
+        let value = try!(self.to_json_value(orient));
+        Ok(try!(serde_json::to_string(&value)))
+    }
+
+    /// Write newline-delimited JSON, a record per line
+    pub fn to_json_lines(&self) -> Result<String, JsonError> {
+        let mut lines = String::new();
+        if let Value::Array(records) = try!(self.to_json_value(JsonOrient::Records)) {
+            for record in records.iter() {
+                lines.push_str(&try!(serde_json::to_string(record)));
+                lines.push('\n');
+            }
+        }
+        Ok(lines)
+    }
+}
+
+impl<'a, I> DataFrame<'a, 'a, 'a, I, String>
+    where I: Clone + Eq + Hash + DeserializeOwned
+{
+    /// Create `DataFrame` from JSON value with the orient. If the orient
+    /// doesn't contain index, row numbers are used as index.
+    pub fn from_json_value(value: Value, orient: JsonOrient) -> Result<Self, JsonError> {
+        let (index, columns, colvecs): (Vec<I>, Vec<String>, Vec<Vec<Value>>) = match orient {
+            JsonOrient::Records => {
+                let mut rows = KeyedRows::new();
+                for record in try!(into_array(value, "records")).into_iter() {
+                    rows.push(try!(into_object(record, "record")));
+                }
+                (try!(row_numbers(rows.nrows)), rows.keys, rows.columns)
+            }
+            JsonOrient::Columns => {
+                // columns are collected as rows keyed by index
+                let mut labels: Vec<String> = vec![];
+                let mut rows = KeyedRows::new();
+                for (label, column) in try!(into_object(value, "columns")).into_iter() {
+                    labels.push(label);
+                    rows.push(try!(into_object(column, "column")));
+                }
+                let mut index: Vec<I> = Vec::with_capacity(rows.keys.len());
+                for key in rows.keys.into_iter() {
+                    index.push(try!(key_to_label(key)));
+                }
+                let colvecs = transpose(rows.columns, labels.len());
+                (index, labels, colvecs)
+            }
+            JsonOrient::Index => {
+                let mut index: Vec<I> = vec![];
+                let mut rows = KeyedRows::new();
+                for (key, row) in try!(into_object(value, "index")).into_iter() {
+                    index.push(try!(key_to_label(key)));
+                    rows.push(try!(into_object(row, "row")));
+                }
+                (index, rows.keys, rows.columns)
+            }
+            JsonOrient::Split => {
+                let mut map = try!(into_object(value, "split"));
+                let mut take = |key: &str| -> Result<Value, JsonError> {
+                    match map.remove(key) {
+                        Some(v) => Ok(v),
+                        None => format_error(format!("split must have '{}'", key)),
+                    }
+                };
+                let mut columns: Vec<String> = vec![];
+                for label in try!(into_array(try!(take("columns")), "columns")).into_iter() {
+                    columns.push(match label {
+                        Value::String(s) => s,
+                        other => other.to_string(),
+                    });
+                }
+                let mut index: Vec<I> = vec![];
+                for label in try!(into_array(try!(take("index")), "index")).into_iter() {
+                    index.push(try!(value_to_label(label)));
+                }
+                let (rows, ncols) = try!(array_rows(try!(take("data")), Some(columns.len())));
+                if rows.len() != index.len() {
+                    return format_error(format!("index has {} labels, but data has {} rows",
+                                                index.len(),
+                                                rows.len()));
+                }
+                (index, columns, transpose(rows, ncols))
+            }
+            JsonOrient::Values => {
+                let (rows, ncols) = try!(array_rows(value, None));
+                let columns: Vec<String> = (0..ncols).map(|x| x.to_string()).collect();
+                (try!(row_numbers(rows.len())), columns, transpose(rows, ncols))
+            }
+        };
+
+        let mut values: Vec<Array> = Vec::with_capacity(colvecs.len());
+        for (label, column) in columns.iter().zip(colvecs) {
+            values.push(try!(values_to_array(label, column)));
+        }
+        Ok(DataFrame::from_vec(values, index, columns))
+    }
+
+    /// Read JSON with the orient
+    pub fn read_json<R: Read>(reader: R, orient: JsonOrient) -> Result<Self, JsonError> {
+        let value: Value = try!(serde_json::from_reader(reader));
+        DataFrame::from_json_value(value, orient)
+    }
+
+    /// Read newline-delimited JSON, a record per line
+    pub fn read_json_lines<R: Read>(reader: R) -> Result<Self, JsonError> {
+        let mut records: Vec<Value> = vec![];
+        for record in serde_json::Deserializer::from_reader(reader).into_iter::<Value>() {
+            records.push(try!(record));
+        }
+        DataFrame::from_json_value(Value::Array(records), JsonOrient::Records)
+    }
+}
+
+/// /////////////////////////////////////////////////////////////////////////////
+/// Series
+/// /////////////////////////////////////////////////////////////////////////////
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+    where V: Clone + Serialize,
+          I: Clone + Eq + Hash + Serialize
+{
+    /// Write JSON with the orient. `Columns` is the same as `Index`, and
+    /// `Records` is the same as `Values`.
+    pub fn to_json(&self, orient: JsonOrient) -> Result<String, JsonError> {
+        let values: Vec<Value> = try!(self.values.iter().map(serde_json::to_value).collect());
+        let value = match orient {
+            JsonOrient::Index | JsonOrient::Columns => {
+                let mut map = Map::new();
+                for (label, value) in self.index.values.iter().zip(values) {
+                    map.insert(try!(label_to_key(label)), value);
+                }
+                Value::Object(map)
+            }
+            JsonOrient::Split => {
+                let mut map = Map::new();
+                map.insert("index".to_string(),
+                           try!(serde_json::to_value(&self.index.values)));
+                map.insert("data".to_string(), Value::Array(values));
+                Value::Object(map)
+            }
+            JsonOrient::Records | JsonOrient::Values => Value::Array(values),
+        };
+        Ok(try!(serde_json::to_string(&value)))
+    }
+}
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+    where V: Clone + DeserializeOwned,
+          I: Clone + Eq + Hash + DeserializeOwned
+{
+    /// Read JSON written by `Series::to_json` with the orient
+    pub fn read_json<R: Read>(reader: R, orient: JsonOrient) -> Result<Self, JsonError> {
+        let value: Value = try!(serde_json::from_reader(reader));
+        let (index, data): (Vec<I>, Vec<Value>) = match orient {
+            JsonOrient::Index | JsonOrient::Columns => {
+                let mut index: Vec<I> = vec![];
+                let mut data: Vec<Value> = vec![];
+                for (key, value) in try!(into_object(value, "index")).into_iter() {
+                    index.push(try!(key_to_label(key)));
+                    data.push(value);
+                }
+                (index, data)
+            }
+            JsonOrient::Split => {
+                let mut map = try!(into_object(value, "split"));
+                let index = match map.remove("index") {
+                    Some(v) => try!(into_array(v, "index")),
+                    None => return format_error("split must have 'index'".to_string()),
+                };
+                let data = match map.remove("data") {
+                    Some(v) => try!(into_array(v, "data")),
+                    None => return format_error("split must have 'data'".to_string()),
+                };
+                if index.len() != data.len() {
+                    return format_error(format!("index has {} labels, but data has {} values",
+                                                index.len(),
+                                                data.len()));
+                }
+                let mut labels: Vec<I> = Vec::with_capacity(index.len());
+                for label in index.into_iter() {
+                    labels.push(try!(value_to_label(label)));
+                }
+                (labels, data)
+            }
+            JsonOrient::Records | JsonOrient::Values => {
+                let data = try!(into_array(value, "values"));
+                (try!(row_numbers(data.len())), data)
+            }
+        };
+        let mut values: Vec<V> = Vec::with_capacity(data.len());
+        for value in data.into_iter() {
+            values.push(try!(value_to_element(value)));
+        }
+        Ok(Series::new(values, index))
+    }
+}
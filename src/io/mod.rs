@@ -1,76 +1,75 @@
 use csv;
 use std::hash::Hash;
 use std::io::{Read, Write};
+use std::str::FromStr;
 use std::string::ToString;
 
-use nullvec::prelude::{Array, Scalar};
+use nullvec::prelude::Scalar;
 
 use frame::DataFrame;
-use indexer::Indexer;
 use traits::{Slicer, RowIndex};
 
-fn default_columns(n: usize) -> Vec<String> {
-    let columns: Vec<usize> = (0..n).collect();
-    columns.into_iter().map(|x| x.to_string()).collect()
-}
+#[cfg(feature = "arrow")]
+mod arrow;
+mod binary;
+mod chunked;
+mod comment;
+#[cfg(feature = "json")]
+mod json;
+mod options;
+#[cfg(feature = "parquet")]
+mod parquet;
+mod parse;
+mod reader;
+mod schema;
+mod text;
+
+pub use self::chunked::CsvChunks;
+pub use self::comment::CommentFilter;
+#[cfg(feature = "json")]
+pub use self::json::{JsonOrient, JsonError};
+pub use self::options::{CsvReadOptions, CsvWriteOptions, FwfFormat, TableFormat};
+#[cfg(feature = "parquet")]
+pub use self::options::ParquetWriteOptions;
+#[cfg(feature = "parquet")]
+pub use self::parquet::ParquetRowGroups;
+pub use self::schema::Schema;
+use self::reader::RecordReader;
 
 impl<'a, I, C> DataFrame<'a, 'a, 'a, I, C>
     where I: Clone + Eq + Hash,
           C: Clone + Eq + Hash
 {
-    pub fn read_csv<R: Read>(mut reader: csv::Reader<R>)
+    pub fn read_csv<R: Read>(reader: csv::Reader<R>)
                              -> Result<DataFrame<'a, 'a, 'a, usize, String>, csv::Error> {
+        DataFrame::<usize, String>::read_csv_with(reader, &CsvReadOptions::new())
+    }
+}
 
-        // headers read 1st row regardless of has_headers property
-        let header: Vec<String> = try!(reader.headers());
-        let columns: Vec<String> = if reader.has_headers {
-            header
-        } else {
-            default_columns(header.len())
-        };
-        let ncols = columns.len();
-
-        let mut records: Vec<Vec<Scalar>> = vec![];
-        for record in reader.decode() {
-            let values: Vec<Scalar> = try!(record);
-            records.push(values);
-        }
-
-        let index: Indexer<usize> = Indexer::<usize>::from_len(records.len());
-
-        // column-wise vec of scalar
-        let mut colvecs: Vec<Vec<Scalar>> = Vec::with_capacity(ncols);
-        for _ in 0..columns.len() {
-            colvecs.push(Vec::with_capacity(records.len()));
-        }
-        for record in records {
-            for (column, val) in colvecs.iter_mut().zip(record) {
-                column.push(val);
-            }
-        }
-        // column-wise vec of Array
-        let mut arrays: Vec<Array> = Vec::with_capacity(ncols);
-        for column in colvecs {
-            let array: Array = column.into();
-            arrays.push(array);
-        }
-
-        // ToDo: compare perf to disable boundary check / avoid clone
-        // let mut arrays: Vec<Array> = Vec::with_capacity(ncols);
-        // for i in 0..ncols {
-        // let mut new_value: Vec<Scalar> = Vec::with_capacity(len);
-        // for j in 0..len {
-        // unsafe {
-        // avoid index boundary check
-        // new_value.push((*records.get_unchecked(j * ncols + i)).clone());
-        // }
-        // }
-        // let array: Array = new_value.into();
-        // arrays.push(array);
-        // }
-        //
+impl<'a, I> DataFrame<'a, 'a, 'a, I, String>
+    where I: Clone + Eq + Hash + FromStr
+{
+    /// Read CSV following passed options. Column dtypes are inferred from all
+    /// rows unless specified. If `index_col` is not specified, row numbers
+    /// are parsed as index.
+    pub fn read_csv_with<R: Read>(reader: csv::Reader<R>,
+                                  options: &CsvReadOptions)
+                                  -> Result<Self, csv::Error> {
+        let mut records = try!(RecordReader::new(reader, options));
+        let colvecs = try!(records.read_fields(None, options));
+        reader::build_frame(records.columns(), colvecs, 0, options)
+    }
 
-        Ok(DataFrame::from_vec(arrays, index, columns))
+    /// Read CSV having headers, delimited by comma and quoted by `"`, from
+    /// the raw input following passed options. Unlike
+    /// `DataFrame::read_csv_with`, `comment` is supported as the input is
+    /// wrapped by `CommentFilter` before parsing.
+    pub fn read_csv_from<R: Read>(reader: R, options: &CsvReadOptions) -> Result<Self, csv::Error> {
+        let reader = csv::Reader::from_reader(CommentFilter::from_options(reader, options))
+            .has_headers(true);
+        let mut records = try!(RecordReader::from_csv(reader));
+        let colvecs = try!(records.read_fields(None, options));
+        reader::build_frame(records.columns(), colvecs, 0, options)
     }
 }
 
@@ -78,29 +77,101 @@ impl<'a, I, C> DataFrame<'a, 'a, 'a, I, C>
     where I: Clone + Eq + Hash,
           C: Clone + Eq + Hash + ToString
 {
+    /// Write columns with header, without index
     pub fn write_csv<W: Write>(&self, writer: &mut csv::Writer<W>) -> Result<(), csv::Error> {
+        let options = CsvWriteOptions::new().index(false);
+        self.write_records(writer, None, &options)
+    }
+
+    fn write_records<W: Write>(&self,
+                               writer: &mut csv::Writer<W>,
+                               index: Option<Vec<String>>,
+                               options: &CsvWriteOptions)
+                               -> Result<(), csv::Error> {
+        let labels: Vec<String> = self.columns.values.iter().map(|x| x.to_string()).collect();
 
-        // output columns
-        let mut columns: Vec<Scalar> = Vec::with_capacity(self.values.len() + 1);
-        // pad
-        // columns.push(Scalar::String("".to_string()));
-        for i in self.columns.values.iter() {
-            let s: Scalar = i.to_string().into();
-            columns.push(s);
+        // locations of output columns
+        let locs: Vec<usize> = match options.columns {
+            Some(ref columns) => {
+                let mut locs: Vec<usize> = Vec::with_capacity(columns.len());
+                for label in columns.iter() {
+                    match labels.iter().position(|x| x == label) {
+                        Some(loc) => locs.push(loc),
+                        None => {
+                            return Err(csv::Error::Encode(format!("column '{}' not found",
+                                                                  label)))
+                        }
+                    }
+                }
+                locs
+            }
+            None => (0..labels.len()).collect(),
+        };
+
+        if options.header {
+            let mut header: Vec<&str> = Vec::with_capacity(locs.len() + 1);
+            if index.is_some() {
+                header.push(options.index_label.as_ref().map_or("", |x| x.as_str()));
+            }
+            for &loc in locs.iter() {
+                header.push(&labels[loc]);
+            }
+            try!(writer.write(header.into_iter()));
         }
-        try!(writer.encode(columns));
 
         for i in 0..self.len() {
-            let mut row: Vec<Scalar> = Vec::with_capacity(self.values.len() + 1);
+            let mut row: Vec<String> = Vec::with_capacity(locs.len() + 1);
+            if let Some(ref index) = index {
+                row.push(index[i].clone());
+            }
+            for &loc in locs.iter() {
+                row.push(format_scalar(self.values[loc].iloc(&i), options));
+            }
+            try!(writer.write(row.into_iter()));
+        }
+        Ok(())
+    }
+}
 
-            // let s: Scalar = self.index.values[i].clone().into();
-            // row.push(s);
+impl<'a, I, C> DataFrame<'a, 'a, 'a, I, C>
+    where I: Clone + Eq + Hash + ToString,
+          C: Clone + Eq + Hash + ToString
+{
+    /// Write CSV following passed options. Written index can be read by
+    /// `DataFrame::read_csv_with` specifying `index_col`.
+    pub fn write_csv_with<W: Write>(&self,
+                                    writer: W,
+                                    options: &CsvWriteOptions)
+                                    -> Result<(), csv::Error> {
+        let mut writer = csv::Writer::from_writer(writer)
+            .delimiter(options.delimiter)
+            .quote_style(options.quoting);
+        let index = if options.index {
+            Some(self.index.values.iter().map(|x| x.to_string()).collect())
+        } else {
+            None
+        };
+        try!(self.write_records(&mut writer, index, options));
+        writer.flush()
+    }
+}
 
-            for col in self.values.iter() {
-                row.push(col.iloc(&i));
+/// Format a value as CSV field. Floats are written the same as `csv` encoder
+/// unless `float_format` is specified.
+fn format_scalar(value: Scalar, options: &CsvWriteOptions) -> String {
+    let format_float = |v: f64| -> String {
+        match options.float_format {
+            Some(precision) => format!("{:.*}", precision, v),
+            None => {
+                let s: String = format!("{:.10}", v).trim_end_matches('0').into();
+                if s.ends_with('.') { s + "0" } else { s }
             }
-            try!(writer.encode(row));
         }
-        Ok(())
+    };
+    match value {
+        Scalar::Null => options.na_rep.clone(),
+        Scalar::f64(v) => format_float(v),
+        Scalar::f32(v) => format_float(v as f64),
+        v => v.to_string(),
     }
 }
@@ -0,0 +1,352 @@
+use csv::QuoteStyle;
+#[cfg(feature = "parquet")]
+use parquet::basic::Compression;
+use std::collections::HashMap;
+
+use super::schema::Schema;
+
+/// Options for `DataFrame::read_csv_with`
+///
+/// Columns are specified by header label, or by position as string (like
+/// `"0"`) if the reader has no headers.
+#[derive(Clone, Debug)]
+pub struct CsvReadOptions {
+    /// Column to be used as index
+    pub index_col: Option<String>,
+    /// Columns to read, all columns if `None`
+    pub usecols: Option<Vec<String>>,
+    /// Explicit dtype per column, named the same as `Array::dtype`
+    pub dtype: HashMap<String, String>,
+    /// Strings regarded as null
+    pub na_values: Vec<String>,
+    /// Whether empty fields are regarded as null
+    pub empty_as_null: bool,
+    /// Number of data rows to skip after the header
+    pub skiprows: usize,
+    /// Maximum number of data rows to read
+    pub nrows: Option<usize>,
+    /// Character starting a comment outside quotes, which continues to the
+    /// end of the line. Not supported by readers taking a built
+    /// `csv::Reader`, use `DataFrame::read_csv_from` or wrap the input by
+    /// `CommentFilter` instead.
+    pub comment: Option<char>,
+    /// Thousands separator in numeric fields
+    pub thousands: Option<char>,
+    /// Decimal point in numeric fields
+    pub decimal: char,
+    /// Strings regarded as `true` in addition to "true"
+    pub true_values: Vec<String>,
+    /// Strings regarded as `false` in addition to "false"
+    pub false_values: Vec<String>,
+    /// Columns to be parsed as ISO 8601 datetime, stored as epoch nanoseconds
+    pub parse_dates: Vec<String>,
+    /// Number of leading rows used to infer dtypes, all rows if `None`
+    pub infer_rows: Option<usize>,
+}
+
+impl CsvReadOptions {
+    pub fn new() -> Self {
+        CsvReadOptions {
+            index_col: None,
+            usecols: None,
+            dtype: HashMap::new(),
+            na_values: vec![],
+            empty_as_null: true,
+            skiprows: 0,
+            nrows: None,
+            comment: None,
+            thousands: None,
+            decimal: '.',
+            true_values: vec![],
+            false_values: vec![],
+            parse_dates: vec![],
+            infer_rows: None,
+        }
+    }
+
+    pub fn index_col(mut self, column: &str) -> Self {
+        self.index_col = Some(column.to_string());
+        self
+    }
+
+    pub fn usecols(mut self, columns: &[&str]) -> Self {
+        self.usecols = Some(to_strings(columns));
+        self
+    }
+
+    pub fn dtype(mut self, column: &str, dtype: &str) -> Self {
+        self.dtype.insert(column.to_string(), dtype.to_string());
+        self
+    }
+
+    pub fn na_values(mut self, values: &[&str]) -> Self {
+        self.na_values = to_strings(values);
+        self
+    }
+
+    pub fn empty_as_null(mut self, empty_as_null: bool) -> Self {
+        self.empty_as_null = empty_as_null;
+        self
+    }
+
+    pub fn skiprows(mut self, n: usize) -> Self {
+        self.skiprows = n;
+        self
+    }
+
+    pub fn nrows(mut self, n: usize) -> Self {
+        self.nrows = Some(n);
+        self
+    }
+
+    pub fn comment(mut self, c: char) -> Self {
+        self.comment = Some(c);
+        self
+    }
+
+    pub fn thousands(mut self, c: char) -> Self {
+        self.thousands = Some(c);
+        self
+    }
+
+    pub fn decimal(mut self, c: char) -> Self {
+        self.decimal = c;
+        self
+    }
+
+    pub fn true_values(mut self, values: &[&str]) -> Self {
+        self.true_values = to_strings(values);
+        self
+    }
+
+    pub fn false_values(mut self, values: &[&str]) -> Self {
+        self.false_values = to_strings(values);
+        self
+    }
+
+    pub fn parse_dates(mut self, columns: &[&str]) -> Self {
+        self.parse_dates = to_strings(columns);
+        self
+    }
+
+    pub fn infer_rows(mut self, n: usize) -> Self {
+        self.infer_rows = Some(n);
+        self
+    }
+
+    /// Use dtypes of the `Schema`, overriding ones already specified
+    pub fn schema(mut self, schema: &Schema) -> Self {
+        for (column, dtype) in schema.columns.iter().zip(schema.dtypes.iter()) {
+            self.dtype.insert(column.clone(), dtype.clone());
+        }
+        self
+    }
+}
+
+fn to_strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|x| x.to_string()).collect()
+}
+
+/// Options for `DataFrame::write_csv_with`
+#[derive(Clone)]
+pub struct CsvWriteOptions {
+    /// Whether to write index as the first column
+    pub index: bool,
+    /// Header label of the index column, empty if `None`
+    pub index_label: Option<String>,
+    /// Whether to write the header row
+    pub header: bool,
+    /// String representing null
+    pub na_rep: String,
+    /// Number of digits after the decimal point of float values. Floats are
+    /// written with minimal digits (up to 10) if `None`
+    pub float_format: Option<usize>,
+    /// Columns to write in the order, all columns if `None`
+    pub columns: Option<Vec<String>>,
+    /// Field delimiter
+    pub delimiter: u8,
+    /// Quoting style of fields
+    pub quoting: QuoteStyle,
+}
+
+impl CsvWriteOptions {
+    pub fn new() -> Self {
+        CsvWriteOptions {
+            index: true,
+            index_label: None,
+            header: true,
+            na_rep: "".to_string(),
+            float_format: None,
+            columns: None,
+            delimiter: b',',
+            quoting: QuoteStyle::Necessary,
+        }
+    }
+
+    pub fn index(mut self, index: bool) -> Self {
+        self.index = index;
+        self
+    }
+
+    pub fn index_label(mut self, label: &str) -> Self {
+        self.index_label = Some(label.to_string());
+        self
+    }
+
+    pub fn header(mut self, header: bool) -> Self {
+        self.header = header;
+        self
+    }
+
+    pub fn na_rep(mut self, rep: &str) -> Self {
+        self.na_rep = rep.to_string();
+        self
+    }
+
+    pub fn float_format(mut self, precision: usize) -> Self {
+        self.float_format = Some(precision);
+        self
+    }
+
+    pub fn columns(mut self, columns: &[&str]) -> Self {
+        self.columns = Some(to_strings(columns));
+        self
+    }
+
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    pub fn quoting(mut self, quoting: QuoteStyle) -> Self {
+        self.quoting = quoting;
+        self
+    }
+}
+
+/// Column layout of fixed-width text for `DataFrame::read_fwf`
+#[derive(Clone, Debug)]
+pub struct FwfFormat {
+    /// Half-open ranges of character positions of each column
+    pub colspecs: Vec<(usize, usize)>,
+    /// Whether the 1st line contains column labels
+    pub has_headers: bool,
+}
+
+impl FwfFormat {
+    pub fn colspecs(colspecs: Vec<(usize, usize)>) -> Self {
+        for &(start, end) in colspecs.iter() {
+            assert!(start < end, "colspec ({}, {}) is empty", start, end);
+        }
+        FwfFormat {
+            colspecs: colspecs,
+            has_headers: true,
+        }
+    }
+
+    /// Create from widths of contiguous columns
+    pub fn widths(widths: Vec<usize>) -> Self {
+        let mut colspecs: Vec<(usize, usize)> = Vec::with_capacity(widths.len());
+        let mut start = 0;
+        for width in widths.into_iter() {
+            colspecs.push((start, start + width));
+            start += width;
+        }
+        FwfFormat::colspecs(colspecs)
+    }
+
+    pub fn has_headers(mut self, has_headers: bool) -> Self {
+        self.has_headers = has_headers;
+        self
+    }
+}
+
+/// Tokenizing rule of delimited text for `DataFrame::read_table`
+#[derive(Clone, Debug)]
+pub struct TableFormat {
+    /// Field delimiter, runs of whitespace if `None`
+    pub delimiter: Option<u8>,
+    /// Quote character, no quoting if `None`
+    pub quote: Option<u8>,
+    /// Escape character. Quotes are escaped by doubling if `None`
+    pub escape: Option<u8>,
+    /// Whether the 1st record contains column labels
+    pub has_headers: bool,
+}
+
+impl TableFormat {
+    pub fn new() -> Self {
+        TableFormat {
+            delimiter: Some(b'\t'),
+            quote: Some(b'"'),
+            escape: None,
+            has_headers: true,
+        }
+    }
+
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = Some(delimiter);
+        self
+    }
+
+    /// Split fields by runs of whitespace, ignoring leading and trailing ones
+    pub fn whitespace(mut self) -> Self {
+        self.delimiter = None;
+        self
+    }
+
+    pub fn quote(mut self, quote: Option<u8>) -> Self {
+        self.quote = quote;
+        self
+    }
+
+    pub fn escape(mut self, escape: u8) -> Self {
+        self.escape = Some(escape);
+        self
+    }
+
+    pub fn has_headers(mut self, has_headers: bool) -> Self {
+        self.has_headers = has_headers;
+        self
+    }
+}
+
+/// Options for `DataFrame::write_parquet`
+#[cfg(feature = "parquet")]
+#[derive(Clone, Debug)]
+pub struct ParquetWriteOptions {
+    /// Compression codec of column chunks
+    pub compression: Compression,
+    /// Name of the column to write index, which is restored as index by
+    /// `DataFrame::read_parquet`. Index is not written if `None`
+    pub index: Option<String>,
+    /// Maximum number of rows in a row group
+    pub row_group_size: Option<usize>,
+}
+
+#[cfg(feature = "parquet")]
+impl ParquetWriteOptions {
+    pub fn new() -> Self {
+        ParquetWriteOptions {
+            compression: Compression::SNAPPY,
+            index: None,
+            row_group_size: None,
+        }
+    }
+
+    pub fn compression(mut self, compression: Compression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    pub fn index(mut self, name: &str) -> Self {
+        self.index = Some(name.to_string());
+        self
+    }
+
+    pub fn row_group_size(mut self, size: usize) -> Self {
+        assert!(size > 0, "row_group_size must be positive");
+        self.row_group_size = Some(size);
+        self
+    }
+}
@@ -0,0 +1,207 @@
+use arrow_array::{RecordBatch, RecordBatchReader};
+use parquet::arrow::{ArrowWriter, ProjectionMask};
+use parquet::arrow::arrow_reader::{ArrowReaderMetadata, ArrowReaderOptions,
+                                   ParquetRecordBatchReaderBuilder};
+use parquet::errors::ParquetError;
+use parquet::file::properties::WriterProperties;
+use std::collections::HashMap;
+use std::fs::File;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::path::Path;
+use std::string::ToString;
+use std::sync::Arc;
+
+use nullvec::prelude::Array;
+
+use frame::DataFrame;
+use super::arrow::{build_frame, collect_batches};
+use super::options::ParquetWriteOptions;
+
+/// Schema metadata key storing the name of index column
+const INDEX_KEY: &str = "brassfibre:index";
+
+/// Opened Parquet file with projection of columns to read
+struct ParquetSource {
+    file: File,
+    metadata: ArrowReaderMetadata,
+    projection: ProjectionMask,
+    index: Option<String>,
+}
+
+impl ParquetSource {
+    /// Open Parquet file. Index column is always read if exists.
+    fn open<P: AsRef<Path>>(path: P, columns: Option<&[&str]>) -> Result<Self, ParquetError> {
+        let file = try!(File::open(path));
+        let metadata = try!(ArrowReaderMetadata::load(&file, ArrowReaderOptions::new()));
+        let index = metadata.schema().metadata().get(INDEX_KEY).cloned();
+
+        let projection = match columns {
+            None => ProjectionMask::all(),
+            Some(columns) => {
+                let labels: Vec<&str> = metadata.schema()
+                    .fields()
+                    .iter()
+                    .map(|x| x.name().as_str())
+                    .collect();
+                for column in columns.iter() {
+                    if !labels.contains(column) {
+                        return Err(ParquetError::General(format!("column '{}' not found",
+                                                                 column)));
+                    }
+                }
+                let index_label = index.as_deref();
+                let roots: Vec<usize> = labels.iter()
+                    .enumerate()
+                    .filter(|&(_, x)| columns.contains(x) || index_label == Some(*x))
+                    .map(|(i, _)| i)
+                    .collect();
+                ProjectionMask::roots(metadata.metadata().file_metadata().schema_descr(), roots)
+            }
+        };
+        Ok(ParquetSource {
+            file: file,
+            metadata: metadata,
+            projection: projection,
+            index: index,
+        })
+    }
+
+    fn num_row_groups(&self) -> usize {
+        self.metadata.metadata().num_row_groups()
+    }
+
+    /// Number of rows in the row group, from metadata
+    fn num_rows(&self, row_group: usize) -> usize {
+        self.metadata.metadata().row_group(row_group).num_rows() as usize
+    }
+
+    /// Read row groups as `DataFrame`, numbering rows from `offset` if the
+    /// file has no index
+    fn read<'a, I>(&self,
+                   row_groups: Vec<usize>,
+                   offset: usize)
+                   -> Result<DataFrame<'a, 'a, 'a, I, String>, ParquetError>
+        where I: Clone + Eq + Hash,
+              Array: From<Vec<I>>,
+              Vec<I>: From<Array>
+    {
+        let file = try!(self.file.try_clone());
+        let builder = ParquetRecordBatchReaderBuilder::new_with_metadata(file,
+                                                                          self.metadata.clone());
+        let reader = try!(builder.with_projection(self.projection.clone())
+            .with_row_groups(row_groups)
+            .build());
+        let schema = reader.schema();
+        let (labels, arrays) = try!(collect_batches(schema, reader));
+        let df = try!(build_frame(labels, arrays, offset, self.index.as_deref()));
+        Ok(df)
+    }
+}
+
+/// Iterator reading Parquet row groups as `DataFrame`s
+pub struct ParquetRowGroups<'a, I> {
+    source: ParquetSource,
+    current: usize,
+    // number of rows in the row groups already read
+    offset: usize,
+    phantom: PhantomData<(&'a (), I)>,
+}
+
+impl<'a, I> ParquetRowGroups<'a, I> {
+    /// Number of row groups in the file
+    pub fn num_row_groups(&self) -> usize {
+        self.source.num_row_groups()
+    }
+}
+
+impl<'a, I> Iterator for ParquetRowGroups<'a, I>
+    where I: 'a + Clone + Eq + Hash,
+          Array: From<Vec<I>>,
+          Vec<I>: From<Array>
+{
+    type Item = Result<DataFrame<'a, 'a, 'a, I, String>, ParquetError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current >= self.source.num_row_groups() {
+            return None;
+        }
+        let res = self.source.read(vec![self.current], self.offset);
+        self.offset += self.source.num_rows(self.current);
+        self.current += 1;
+        Some(res)
+    }
+}
+
+/// /////////////////////////////////////////////////////////////////////////////
+/// DataFrame
+/// /////////////////////////////////////////////////////////////////////////////
+
+impl<'a, I, C> DataFrame<'a, 'a, 'a, I, C>
+    where I: Clone + Eq + Hash,
+          C: Clone + Eq + Hash + ToString,
+          Array: From<Vec<I>>
+{
+    /// Write Parquet file. Dtypes and null values are restored by
+    /// `DataFrame::read_parquet`.
+    pub fn write_parquet<P: AsRef<Path>>(&self,
+                                         path: P,
+                                         options: &ParquetWriteOptions)
+                                         -> Result<(), ParquetError> {
+        let index = options.index.as_deref();
+        let batch = try!(self.to_record_batch(index));
+        let batch = match index {
+            Some(name) => {
+                let mut metadata = HashMap::new();
+                metadata.insert(INDEX_KEY.to_string(), name.to_string());
+                let schema = batch.schema().as_ref().clone().with_metadata(metadata);
+                try!(RecordBatch::try_new(Arc::new(schema), batch.columns().to_vec()))
+            }
+            None => batch,
+        };
+
+        let mut properties = WriterProperties::builder().set_compression(options.compression);
+        if let Some(size) = options.row_group_size {
+            properties = properties.set_max_row_group_size(size);
+        }
+        let file = try!(File::create(path));
+        let mut writer = try!(ArrowWriter::try_new(file,
+                                                   batch.schema(),
+                                                   Some(properties.build())));
+        try!(writer.write(&batch));
+        try!(writer.close());
+        Ok(())
+    }
+}
+
+impl<'a, I> DataFrame<'a, 'a, 'a, I, String>
+    where I: 'a + Clone + Eq + Hash,
+          Array: From<Vec<I>>,
+          Vec<I>: From<Array>
+{
+    /// Read Parquet file, only the specified columns if `columns` is not
+    /// `None`. Index written by `DataFrame::write_parquet` is restored,
+    /// otherwise row numbers are used as index.
+    pub fn read_parquet<P: AsRef<Path>>(path: P,
+                                        columns: Option<&[&str]>)
+                                        -> Result<Self, ParquetError> {
+        let source = try!(ParquetSource::open(path, columns));
+        let row_groups: Vec<usize> = (0..source.num_row_groups()).collect();
+        source.read(row_groups, 0)
+    }
+
+    /// Read Parquet file per row group. Index is restored the same as
+    /// `DataFrame::read_parquet`, and row numbers continue across row groups.
+    pub fn read_parquet_row_groups<P: AsRef<Path>>(path: P,
+                                                   columns: Option<&[&str]>)
+                                                   -> Result<ParquetRowGroups<'a, I>,
+                                                             ParquetError> {
+        let source = try!(ParquetSource::open(path, columns));
+        Ok(ParquetRowGroups {
+            source: source,
+            current: 0,
+            offset: 0,
+            phantom: PhantomData,
+        })
+    }
+}
@@ -0,0 +1,278 @@
+use csv;
+use std::borrow::Cow;
+
+use nullvec::prelude::{Array, NullVec};
+use nullvec::prelude::dev::NullStorable;
+
+use datetime::Datetime;
+use super::options::CsvReadOptions;
+
+/// Field read from CSV, `None` if null
+pub type Field = Option<String>;
+
+pub fn decode_error(message: String) -> csv::Error {
+    csv::Error::Decode(message)
+}
+
+/// Convert raw string to `Field`, regarding `na_values` (and empty string
+/// if `empty_as_null`) as null
+pub fn to_field(value: String, options: &CsvReadOptions) -> Field {
+    if (options.empty_as_null && value.is_empty()) || options.na_values.contains(&value) {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+/// Trim surrounding whitespaces, remove thousands separators and replace
+/// decimal point with "."
+fn normalize_number<'a>(value: &'a str, options: &CsvReadOptions) -> Cow<'a, str> {
+    let value = value.trim();
+    if options.thousands.is_none() && options.decimal == '.' {
+        return Cow::Borrowed(value);
+    }
+    let normalized: String = value.chars()
+        .filter(|&c| Some(c) != options.thousands)
+        .map(|c| if c == options.decimal { '.' } else { c })
+        .collect();
+    Cow::Owned(normalized)
+}
+
+fn parse_bool(value: &str, options: &CsvReadOptions) -> Option<bool> {
+    if value == "true" || options.true_values.iter().any(|x| x == value) {
+        Some(true)
+    } else if value == "false" || options.false_values.iter().any(|x| x == value) {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// Parse non-null fields with `func`, returning the first field which fails
+fn parse_fields<T, F>(fields: &[Field], func: F) -> Result<NullVec<T>, String>
+    where T: NullStorable,
+          F: Fn(&str) -> Option<T>
+{
+    let mut values: Vec<T> = Vec::with_capacity(fields.len());
+    let mut mask: Vec<bool> = Vec::with_capacity(fields.len());
+    let mut has_null = false;
+
+    for field in fields.iter() {
+        match *field {
+            Some(ref v) => {
+                match func(v) {
+                    Some(parsed) => values.push(parsed),
+                    None => return Err(v.clone()),
+                }
+                mask.push(false);
+            }
+            None => {
+                values.push(T::default());
+                mask.push(true);
+                has_null = true;
+            }
+        }
+    }
+    let mask = if has_null { Some(mask) } else { None };
+    Ok(NullVec::with_mask(values, mask))
+}
+
+macro_rules! parse_number {
+    ($fields:expr, $options:expr, $t:ident, $variant:ident) => {
+        parse_fields($fields, |v| normalize_number(v, $options).parse::<$t>().ok())
+            .map(Array::$variant)
+    }
+}
+
+/// Parse fields as the dtype named the same as `Array::dtype`
+pub fn parse_as(name: &str,
+                fields: &[Field],
+                dtype: &str,
+                options: &CsvReadOptions)
+                -> Result<Array, csv::Error> {
+    let parsed = match dtype {
+        "i64" => parse_number!(fields, options, i64, Int64Array),
+        "i32" => parse_number!(fields, options, i32, Int32Array),
+        "i16" => parse_number!(fields, options, i16, Int16Array),
+        "i8" => parse_number!(fields, options, i8, Int8Array),
+        "isize" => parse_number!(fields, options, isize, IsizeArray),
+        "u64" => parse_number!(fields, options, u64, UInt64Array),
+        "u32" => parse_number!(fields, options, u32, UInt32Array),
+        "u16" => parse_number!(fields, options, u16, UInt16Array),
+        "u8" => parse_number!(fields, options, u8, UInt8Array),
+        "usize" => parse_number!(fields, options, usize, UsizeArray),
+        "f64" => parse_number!(fields, options, f64, Float64Array),
+        "f32" => parse_number!(fields, options, f32, Float32Array),
+        "bool" => parse_fields(fields, |v| parse_bool(v, options)).map(Array::BoolArray),
+        "str" => parse_fields(fields, |v| Some(v.to_string())).map(Array::StringArray),
+        _ => {
+            return Err(decode_error(format!("unknown dtype '{}' for column '{}'", dtype, name)))
+        }
+    };
+    parsed.map_err(|v| {
+        decode_error(format!("unable to parse '{}' in column '{}' as {}", v, name, dtype))
+    })
+}
+
+/// Parse fields as ISO 8601 datetime, stored as epoch nanoseconds
+fn parse_dates(name: &str, fields: &[Field]) -> Result<Array, csv::Error> {
+    parse_fields(fields, |v| v.parse::<Datetime>().ok().map(|x| x.nanos))
+        .map(Array::Int64Array)
+        .map_err(|v| {
+            decode_error(format!("unable to parse '{}' in column '{}' as datetime", v, name))
+        })
+}
+
+/// Kind of a single non-null field
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    Int,
+    Float,
+    Bool,
+    Str,
+}
+
+impl Kind {
+    fn of(value: &str, options: &CsvReadOptions) -> Kind {
+        let number = normalize_number(value, options);
+        if number.parse::<i64>().is_ok() {
+            Kind::Int
+        } else if number.parse::<f64>().is_ok() {
+            Kind::Float
+        } else if parse_bool(value, options).is_some() {
+            Kind::Bool
+        } else {
+            Kind::Str
+        }
+    }
+
+    /// Kind which can represent both kinds
+    fn merge(self, other: Kind) -> Kind {
+        match (self, other) {
+            (x, y) if x == y => x,
+            (Kind::Int, Kind::Float) |
+            (Kind::Float, Kind::Int) => Kind::Float,
+            _ => Kind::Str,
+        }
+    }
+
+    fn dtype(&self) -> &'static str {
+        match *self {
+            Kind::Int => "i64",
+            Kind::Float => "f64",
+            Kind::Bool => "bool",
+            Kind::Str => "str",
+        }
+    }
+}
+
+/// Infer the narrowest dtype which can represent all non-null fields, in the
+/// order of i64, f64, bool and str. Columns without non-null fields are f64.
+pub fn infer_dtype(fields: &[Field], options: &CsvReadOptions) -> &'static str {
+    let mut kind: Option<Kind> = None;
+    for field in fields.iter() {
+        if let Some(ref v) = *field {
+            let current = Kind::of(v, options);
+            let merged = kind.map_or(current, |k| k.merge(current));
+            if merged == Kind::Str {
+                return Kind::Str.dtype();
+            }
+            kind = Some(merged);
+        }
+    }
+    kind.map_or("f64", |k| k.dtype())
+}
+
+/// Infer dtype from leading `infer_rows` fields, then parse all fields
+fn infer(name: &str, fields: &[Field], options: &CsvReadOptions) -> Result<Array, csv::Error> {
+    let sample = match options.infer_rows {
+        Some(n) if n < fields.len() => &fields[..n],
+        _ => fields,
+    };
+    parse_as(name, fields, infer_dtype(sample, options), options)
+}
+
+/// Convert fields of the column to `Array` following options
+pub fn parse_column(name: &str,
+                    fields: &[Field],
+                    options: &CsvReadOptions)
+                    -> Result<Array, csv::Error> {
+    if options.parse_dates.iter().any(|x| x == name) {
+        parse_dates(name, fields)
+    } else if let Some(dtype) = options.dtype.get(name) {
+        parse_as(name, fields, dtype, options)
+    } else {
+        infer(name, fields, options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use nullvec::prelude::{Array, NullVec};
+
+    use super::{Field, infer_dtype, parse_column};
+    use super::super::options::CsvReadOptions;
+
+    fn fields(values: &[&str]) -> Vec<Field> {
+        values.iter().map(|&x| if x == "NA" { None } else { Some(x.to_string()) }).collect()
+    }
+
+    #[test]
+    fn test_infer() {
+        let options = CsvReadOptions::new();
+        let res = parse_column("a", &fields(&["1", "NA", "3"]), &options).unwrap();
+        let exp = NullVec::with_mask(vec![1i64, 0, 3], Some(vec![false, true, false]));
+        assert_eq!(res, Array::Int64Array(exp));
+
+        let res = parse_column("a", &fields(&["1", "2.5"]), &options).unwrap();
+        assert_eq!(res, Array::new(vec![1., 2.5]));
+
+        let res = parse_column("a", &fields(&["true", "false"]), &options).unwrap();
+        assert_eq!(res, Array::new(vec![true, false]));
+
+        let res = parse_column("a", &fields(&["1", "x"]), &options).unwrap();
+        assert_eq!(res, Array::new(vec!["1".to_string(), "x".to_string()]));
+
+        let res = parse_column("a", &fields(&["NA", "NA"]), &options).unwrap();
+        assert_eq!(res.dtype(), "f64");
+    }
+
+    #[test]
+    fn test_infer_dtype() {
+        let options = CsvReadOptions::new();
+        assert_eq!(infer_dtype(&fields(&["1", "NA", "2"]), &options), "i64");
+        assert_eq!(infer_dtype(&fields(&["1", "2.5", "NA"]), &options), "f64");
+        assert_eq!(infer_dtype(&fields(&["true", "NA"]), &options), "bool");
+        assert_eq!(infer_dtype(&fields(&["1", "true"]), &options), "str");
+        assert_eq!(infer_dtype(&fields(&["1.5", "x", "2"]), &options), "str");
+        assert_eq!(infer_dtype(&fields(&["NA"]), &options), "f64");
+
+        let options = CsvReadOptions::new().true_values(&["Y"]).false_values(&["N"]);
+        assert_eq!(infer_dtype(&fields(&["Y", "N"]), &options), "bool");
+    }
+
+    #[test]
+    fn test_number_separators() {
+        let options = CsvReadOptions::new().thousands('.').decimal(',');
+        let res = parse_column("a", &fields(&["1.234,5", "6,25"]), &options).unwrap();
+        assert_eq!(res, Array::new(vec![1234.5, 6.25]));
+    }
+
+    #[test]
+    fn test_explicit_dtype() {
+        let options = CsvReadOptions::new().dtype("a", "u8").dtype("b", "f32");
+        let res = parse_column("a", &fields(&["1", "2"]), &options).unwrap();
+        assert_eq!(res, Array::new(vec![1u8, 2]));
+
+        let res = parse_column("b", &fields(&["1", "2"]), &options).unwrap();
+        assert_eq!(res, Array::new(vec![1f32, 2.]));
+
+        let err = parse_column("a", &fields(&["1", "300"]), &options).unwrap_err();
+        assert_eq!(err.to_string(), "CSV decode error: unable to parse '300' in column 'a' as u8");
+
+        let options = CsvReadOptions::new().dtype("a", "float");
+        let err = parse_column("a", &fields(&["1"]), &options).unwrap_err();
+        assert_eq!(err.to_string(), "CSV decode error: unknown dtype 'float' for column 'a'");
+    }
+}
@@ -0,0 +1,258 @@
+use csv;
+use std::hash::Hash;
+use std::io::Read;
+use std::str::FromStr;
+
+use nullvec::prelude::Array;
+
+use algos::parallel;
+use frame::DataFrame;
+use super::options::CsvReadOptions;
+use super::parse::{self, Field};
+
+fn default_columns(n: usize) -> Vec<String> {
+    let columns: Vec<usize> = (0..n).collect();
+    columns.into_iter().map(|x| x.to_string()).collect()
+}
+
+/// Source of raw records, each of which is a vector of fields
+pub trait Records {
+    fn next_record(&mut self) -> Option<Result<Vec<String>, csv::Error>>;
+}
+
+impl<R: Read> Records for csv::Reader<R> {
+    fn next_record(&mut self) -> Option<Result<Vec<String>, csv::Error>> {
+        let mut record: Vec<String> = vec![];
+        loop {
+            match self.next_str() {
+                csv::NextField::Data(field) => record.push(field.to_string()),
+                csv::NextField::EndOfRecord => return Some(Ok(record)),
+                csv::NextField::EndOfCsv => {
+                    return if record.is_empty() { None } else { Some(Ok(record)) };
+                }
+                csv::NextField::Error(err) => return Some(Err(err)),
+            }
+        }
+    }
+}
+
+/// Stream records into column-wise fields, applying row level options
+/// (`skiprows`, `nrows` and `na_values`). `comment` is applied to the input
+/// before parsing by `CommentFilter`.
+pub struct RecordReader<S> {
+    source: S,
+    columns: Vec<String>,
+    // 1st row consumed by reading headers, which is data if no headers
+    first: Option<Vec<String>>,
+    skipped: usize,
+    nrows: usize,
+}
+
+impl<R: Read> RecordReader<csv::Reader<R>> {
+    /// Create from a built CSV reader, which cannot tell quoted comment
+    /// characters. Thus `comment` is rejected and the input should be wrapped
+    /// by `CommentFilter` instead.
+    pub fn new(reader: csv::Reader<R>, options: &CsvReadOptions) -> Result<Self, csv::Error> {
+        if let Some(c) = options.comment {
+            return Err(parse::decode_error(format!("comment '{}' must be applied to the input \
+                                                    of csv::Reader by CommentFilter",
+                                                   c)));
+        }
+        RecordReader::from_csv(reader)
+    }
+
+    /// Create from a CSV reader whose input has been filtered by
+    /// `CommentFilter` if needed
+    pub fn from_csv(mut reader: csv::Reader<R>) -> Result<Self, csv::Error> {
+        // headers read 1st row regardless of has_headers property
+        let header: Vec<String> = try!(reader.headers());
+        let (columns, first) = if reader.has_headers {
+            (header, None)
+        } else if header.is_empty() {
+            (vec![], None)
+        } else {
+            (default_columns(header.len()), Some(header))
+        };
+        Ok(RecordReader {
+            source: reader,
+            columns: columns,
+            first: first,
+            skipped: 0,
+            nrows: 0,
+        })
+    }
+}
+
+impl<S: Records> RecordReader<S> {
+    /// Create from records other than CSV. The 1st record is used as
+    /// column labels if `has_headers`.
+    pub fn from_records(mut source: S, has_headers: bool) -> Result<Self, csv::Error> {
+        let header: Vec<String> = match source.next_record() {
+            Some(record) => try!(record),
+            None => vec![],
+        };
+        let (columns, first) = if has_headers {
+            (header, None)
+        } else if header.is_empty() {
+            (vec![], None)
+        } else {
+            (default_columns(header.len()), Some(header))
+        };
+        Ok(RecordReader {
+            source: source,
+            columns: columns,
+            first: first,
+            skipped: 0,
+            nrows: 0,
+        })
+    }
+
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    /// Number of data rows read so far
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    fn next_record(&mut self) -> Option<Result<Vec<String>, csv::Error>> {
+        if let Some(first) = self.first.take() {
+            return Some(Ok(first));
+        }
+        self.source.next_record()
+    }
+
+    /// Read at most `limit` rows, pushing fields directly to each column
+    pub fn read_fields(&mut self,
+                       limit: Option<usize>,
+                       options: &CsvReadOptions)
+                       -> Result<Vec<Vec<Field>>, csv::Error> {
+        let ncols = self.columns.len();
+        let mut colvecs: Vec<Vec<Field>> = vec![vec![]; ncols];
+        let mut read = 0;
+
+        while limit.map_or(true, |n| read < n) && options.nrows.map_or(true, |n| self.nrows < n) {
+            let record: Vec<String> = match self.next_record() {
+                Some(record) => try!(record),
+                None => break,
+            };
+            if self.skipped < options.skiprows {
+                self.skipped += 1;
+                continue;
+            }
+            if record.len() > ncols {
+                return Err(parse::decode_error(format!("expected {} fields, found {}",
+                                                       ncols,
+                                                       record.len())));
+            }
+            // missing fields (e.g. truncated by comment) are regarded as null
+            let mut fields = record.into_iter();
+            for column in colvecs.iter_mut() {
+                column.push(fields.next().and_then(|x| parse::to_field(x, options)));
+            }
+            read += 1;
+            self.nrows += 1;
+        }
+        Ok(colvecs)
+    }
+}
+
+/// Location of the column, error if not found
+pub fn position(columns: &[String], label: &String) -> Result<usize, csv::Error> {
+    columns.iter()
+        .position(|x| x == label)
+        .ok_or(parse::decode_error(format!("column '{}' not found", label)))
+}
+
+/// Flags whether each column is read as values, following `usecols` and
+/// `index_col`. Columns keep the order in the file.
+pub fn targets(columns: &[String], options: &CsvReadOptions) -> Result<Vec<bool>, csv::Error> {
+    let mut targets: Vec<bool> = match options.usecols {
+        Some(ref usecols) => {
+            let mut targets = vec![false; columns.len()];
+            for label in usecols.iter() {
+                targets[try!(position(columns, label))] = true;
+            }
+            targets
+        }
+        None => vec![true; columns.len()],
+    };
+    if let Some(ref label) = options.index_col {
+        targets[try!(position(columns, label))] = false;
+    }
+    Ok(targets)
+}
+
+/// Build `DataFrame` from column-wise fields. If `index_col` is not
+/// specified, row numbers starting from `offset` are parsed as index.
+pub fn build_frame<'a, I>(columns: &[String],
+                          colvecs: Vec<Vec<Field>>,
+                          offset: usize,
+                          options: &CsvReadOptions)
+                          -> Result<DataFrame<'a, 'a, 'a, I, String>, csv::Error>
+    where I: Clone + Eq + Hash + FromStr
+{
+    let ncols = columns.len();
+    let nrows = colvecs.first().map_or(0, |x| x.len());
+
+    let index: Vec<I> = match options.index_col {
+        Some(ref label) => {
+            let loc = try!(position(columns, label));
+            let mut index: Vec<I> = Vec::with_capacity(nrows);
+            for field in colvecs[loc].iter() {
+                let value = match *field {
+                    Some(ref v) => v,
+                    None => {
+                        return Err(parse::decode_error(format!("index column '{}' contains \
+                                                                null",
+                                                               label)))
+                    }
+                };
+                match value.parse::<I>() {
+                    Ok(v) => index.push(v),
+                    Err(_) => {
+                        return Err(parse::decode_error(format!("unable to parse '{}' in \
+                                                                column '{}' as index",
+                                                               value,
+                                                               label)))
+                    }
+                }
+            }
+            index
+        }
+        None => {
+            let mut index: Vec<I> = Vec::with_capacity(nrows);
+            for i in offset..(offset + nrows) {
+                match i.to_string().parse::<I>() {
+                    Ok(v) => index.push(v),
+                    Err(_) => {
+                        return Err(parse::decode_error(format!("unable to parse row \
+                                                                number {} as index",
+                                                               i)))
+                    }
+                }
+            }
+            index
+        }
+    };
+
+    let targets = try!(targets(columns, options));
+    let mut selected: Vec<(&String, Vec<Field>)> = Vec::with_capacity(ncols);
+    for ((label, column), target) in columns.iter().zip(colvecs).zip(targets) {
+        if target {
+            selected.push((label, column));
+        }
+    }
+    let new_columns: Vec<String> = selected.iter().map(|&(x, _)| x.clone()).collect();
+
+    // column-wise vec of Array
+    let arrays: Vec<Result<Array, csv::Error>> =
+        parallel::into_map(selected,
+                           |(label, column)| parse::parse_column(label, &column, options));
+    let mut new_values: Vec<Array> = Vec::with_capacity(arrays.len());
+    for array in arrays {
+        new_values.push(try!(array));
+    }
+    Ok(DataFrame::from_vec(new_values, index, new_columns))
+}
@@ -0,0 +1,86 @@
+use csv;
+use std::io::Read;
+
+use super::options::CsvReadOptions;
+use super::parse;
+use super::reader::{self, RecordReader};
+
+/// Column labels and dtypes of CSV, named the same as `Array::dtype`
+///
+/// Inferred by `Schema::from_csv`, and can be modified and passed to
+/// `CsvReadOptions::schema` to read CSV with the dtypes.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Schema {
+    pub columns: Vec<String>,
+    pub dtypes: Vec<String>,
+    /// Whether each column contains null
+    pub nullable: Vec<bool>,
+}
+
+impl Schema {
+    pub fn new(columns: Vec<String>, dtypes: Vec<String>) -> Self {
+        assert!(columns.len() == dtypes.len(),
+                "columns and dtypes must be the same length");
+        let len = columns.len();
+        Schema {
+            columns: columns,
+            dtypes: dtypes,
+            nullable: vec![true; len],
+        }
+    }
+
+    /// Infer the schema of columns to be read by `DataFrame::read_csv_with`,
+    /// using leading `infer_rows` rows. Explicit `dtype` in options is used
+    /// as it is, and `parse_dates` columns are `i64`.
+    pub fn from_csv<R: Read>(reader: csv::Reader<R>,
+                             options: &CsvReadOptions)
+                             -> Result<Schema, csv::Error> {
+        let mut records = try!(RecordReader::new(reader, options));
+        let colvecs = try!(records.read_fields(options.infer_rows, options));
+        let targets = try!(reader::targets(records.columns(), options));
+
+        let mut columns: Vec<String> = vec![];
+        let mut dtypes: Vec<String> = vec![];
+        let mut nullable: Vec<bool> = vec![];
+        for ((label, fields), target) in records.columns().iter().zip(colvecs).zip(targets) {
+            if !target {
+                continue;
+            }
+            let dtype = if options.parse_dates.contains(label) {
+                "i64".to_string()
+            } else {
+                match options.dtype.get(label) {
+                    Some(dtype) => dtype.clone(),
+                    None => parse::infer_dtype(&fields, options).to_string(),
+                }
+            };
+            columns.push(label.clone());
+            dtypes.push(dtype);
+            nullable.push(fields.iter().any(|x| x.is_none()));
+        }
+        Ok(Schema {
+            columns: columns,
+            dtypes: dtypes,
+            nullable: nullable,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Return dtype of the column, `None` if the column doesn't exist
+    pub fn get_dtype(&self, column: &str) -> Option<&str> {
+        self.columns
+            .iter()
+            .position(|x| x == column)
+            .map(|i| self.dtypes[i].as_str())
+    }
+
+    /// Override dtype of the column, error if the column doesn't exist
+    pub fn dtype(mut self, column: &str, dtype: &str) -> Result<Self, csv::Error> {
+        let i = try!(reader::position(&self.columns, &column.to_string()));
+        self.dtypes[i] = dtype.to_string();
+        Ok(self)
+    }
+}
@@ -0,0 +1,226 @@
+use csv;
+use std::hash::Hash;
+use std::io::{BufRead, BufReader, Lines, Read};
+use std::str::FromStr;
+
+use frame::DataFrame;
+use super::comment::CommentFilter;
+use super::options::{CsvReadOptions, FwfFormat, TableFormat};
+use super::parse;
+use super::reader::{self, RecordReader, Records};
+
+/// Records of text lines, skipping blank lines
+struct LineRecords<R, F> {
+    lines: Lines<BufReader<R>>,
+    split: F,
+}
+
+impl<R, F> LineRecords<R, F>
+    where R: Read,
+          F: FnMut(&str) -> Result<Vec<String>, csv::Error>
+{
+    fn new(reader: R, split: F) -> Self {
+        LineRecords {
+            lines: BufReader::new(reader).lines(),
+            split: split,
+        }
+    }
+}
+
+impl<R, F> Records for LineRecords<R, F>
+    where R: Read,
+          F: FnMut(&str) -> Result<Vec<String>, csv::Error>
+{
+    fn next_record(&mut self) -> Option<Result<Vec<String>, csv::Error>> {
+        for line in self.lines.by_ref() {
+            let line = match line {
+                Ok(line) => line,
+                Err(err) => return Some(Err(csv::Error::Io(err))),
+            };
+            let line = line.trim_end_matches('\r');
+            if line.trim().is_empty() {
+                continue;
+            }
+            return Some((self.split)(line));
+        }
+        None
+    }
+}
+
+/// Split a line by character positions, trimming whitespace around fields.
+/// Fields out of the line are empty.
+fn split_fixed(line: &str, colspecs: &[(usize, usize)]) -> Vec<String> {
+    let chars: Vec<char> = line.chars().collect();
+    colspecs.iter()
+        .map(|&(start, end)| {
+            let start = start.min(chars.len());
+            let end = end.min(chars.len());
+            let field: String = chars[start..end].iter().collect();
+            field.trim().to_string()
+        })
+        .collect()
+}
+
+/// Split a line by delimiter (or whitespace), following quote and escape
+/// characters
+fn split_delimited(line: &str, format: &TableFormat) -> Result<Vec<String>, csv::Error> {
+    let is_delimiter = |c: char| match format.delimiter {
+        Some(d) => c == d as char,
+        None => c.is_whitespace(),
+    };
+    let is_quote = |c: char| format.quote.map_or(false, |q| c == q as char);
+    let is_escape = |c: char| format.escape.map_or(false, |e| c == e as char);
+
+    let mut fields: Vec<String> = vec![];
+    let mut field = String::new();
+    // whether a field is started, used to collapse runs of whitespace
+    let mut started = false;
+    let mut quoted = false;
+
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        if quoted {
+            if is_escape(c) && chars.peek().is_some() {
+                field.push(chars.next().unwrap());
+            } else if is_quote(c) {
+                if format.escape.is_none() && chars.peek().map_or(false, |&x| is_quote(x)) {
+                    field.push(chars.next().unwrap());
+                } else {
+                    quoted = false;
+                }
+            } else {
+                field.push(c);
+            }
+        } else if is_delimiter(c) {
+            if format.delimiter.is_some() || started {
+                fields.push(field.clone());
+                field.clear();
+                started = false;
+            }
+        } else if is_quote(c) {
+            quoted = true;
+            started = true;
+        } else if is_escape(c) && chars.peek().is_some() {
+            field.push(chars.next().unwrap());
+            started = true;
+        } else {
+            field.push(c);
+            started = true;
+        }
+    }
+    if quoted {
+        return Err(parse::decode_error(format!("unterminated quote in line '{}'", line)));
+    }
+    if format.delimiter.is_some() || started {
+        fields.push(field);
+    }
+    Ok(fields)
+}
+
+fn read_records<'a, I, S>(records: S,
+                          has_headers: bool,
+                          options: &CsvReadOptions)
+                          -> Result<DataFrame<'a, 'a, 'a, I, String>, csv::Error>
+    where I: Clone + Eq + Hash + FromStr,
+          S: Records
+{
+    let mut records = try!(RecordReader::from_records(records, has_headers));
+    let colvecs = try!(records.read_fields(None, options));
+    reader::build_frame(records.columns(), colvecs, 0, options)
+}
+
+impl<'a, I, C> DataFrame<'a, 'a, 'a, I, C>
+    where I: Clone + Eq + Hash,
+          C: Clone + Eq + Hash
+{
+    pub fn read_fwf<R: Read>(reader: R,
+                             format: &FwfFormat)
+                             -> Result<DataFrame<'a, 'a, 'a, usize, String>, csv::Error> {
+        DataFrame::<usize, String>::read_fwf_with(reader, format, &CsvReadOptions::new())
+    }
+
+    pub fn read_table<R: Read>(reader: R,
+                               format: &TableFormat)
+                               -> Result<DataFrame<'a, 'a, 'a, usize, String>, csv::Error> {
+        DataFrame::<usize, String>::read_table_with(reader, format, &CsvReadOptions::new())
+    }
+}
+
+impl<'a, I> DataFrame<'a, 'a, 'a, I, String>
+    where I: Clone + Eq + Hash + FromStr
+{
+    /// Read fixed-width text following passed options, the same as
+    /// `DataFrame::read_csv_with`. Blank lines are skipped.
+    pub fn read_fwf_with<R: Read>(reader: R,
+                                  format: &FwfFormat,
+                                  options: &CsvReadOptions)
+                                  -> Result<Self, csv::Error> {
+        let reader = CommentFilter::from_options(reader, options).quote(None);
+        let records = LineRecords::new(reader, |line: &str| {
+            Ok(split_fixed(line, &format.colspecs))
+        });
+        read_records(records, format.has_headers, options)
+    }
+
+    /// Read delimited text following passed options, the same as
+    /// `DataFrame::read_csv_with`. Records are read by CSV reader if both
+    /// delimiter and quote are specified, otherwise each line is a record.
+    pub fn read_table_with<R: Read>(reader: R,
+                                    format: &TableFormat,
+                                    options: &CsvReadOptions)
+                                    -> Result<Self, csv::Error> {
+        // comment is regarded only outside quotes
+        let reader = CommentFilter::from_options(reader, options)
+            .quote(format.quote)
+            .escape(format.escape);
+        match (format.delimiter, format.quote) {
+            (Some(delimiter), Some(quote)) => {
+                let reader = csv::Reader::from_reader(reader)
+                    .delimiter(delimiter)
+                    .quote(quote)
+                    .escape(format.escape)
+                    .double_quote(format.escape.is_none())
+                    .flexible(true);
+                read_records(reader, format.has_headers, options)
+            }
+            _ => {
+                let records = LineRecords::new(reader, |line: &str| split_delimited(line, format));
+                read_records(records, format.has_headers, options)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{split_fixed, split_delimited};
+    use io::TableFormat;
+
+    #[test]
+    fn test_split_fixed() {
+        let colspecs = vec![(0, 3), (3, 8), (10, 12)];
+        assert_eq!(split_fixed("ab 12.5 xyz", &colspecs),
+                   vec!["ab".to_string(), "12.5".to_string(), "z".to_string()]);
+        assert_eq!(split_fixed("é  1", &colspecs),
+                   vec!["é".to_string(), "1".to_string(), "".to_string()]);
+    }
+
+    #[test]
+    fn test_split_delimited() {
+        let format = TableFormat::new().whitespace();
+        assert_eq!(split_delimited("  a   \"b c\"\t\"\" d ", &format).unwrap(),
+                   vec!["a".to_string(), "b c".to_string(), "".to_string(), "d".to_string()]);
+
+        let format = TableFormat::new().delimiter(b'|').quote(Some(b'\'')).escape(b'\\');
+        assert_eq!(split_delimited("'a|\\'b'||c\\|d", &format).unwrap(),
+                   vec!["a|'b".to_string(), "".to_string(), "c|d".to_string()]);
+
+        let format = TableFormat::new().quote(None);
+        assert_eq!(split_delimited("\"a\"\t b", &format).unwrap(),
+                   vec!["\"a\"".to_string(), " b".to_string()]);
+
+        let format = TableFormat::new().whitespace();
+        assert!(split_delimited("a \"b", &format).is_err());
+    }
+}
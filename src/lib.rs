@@ -1,17 +1,35 @@
 extern crate csv;
 extern crate num;
-// extern crate rayon;
+#[cfg(feature = "parallel")]
+extern crate rayon;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "json")]
+extern crate serde_json;
+#[cfg(feature = "mmap")]
+extern crate memmap2;
+#[cfg(feature = "arrow")]
+extern crate arrow_array;
+#[cfg(feature = "arrow")]
+extern crate arrow_ipc;
+#[cfg(feature = "arrow")]
+extern crate arrow_schema;
+#[cfg(feature = "parquet")]
+extern crate parquet;
 extern crate nullvec;
 
+#[macro_use]
+mod macros;
+
 mod algos;
+mod datetime;
 mod formatting;
 mod frame;
 mod groupby;
 mod indexer;
 mod io;
-#[macro_use]
-mod macros;
 mod series;
 mod traits;
+mod window;
 
 pub mod prelude;
@@ -7,3 +7,138 @@ macro_rules! array {
     });
     ($($e:expr),+,) => (array!($($e),+))
 }
+
+/// Implement aggregation traits on window types whose `kernel` supports
+/// `sum`, `count`, `min` and `max`. `$roll` applies a kernel function to
+/// the windowed data.
+macro_rules! impl_window_basic_aggregation {
+    ($window:ident, $data:ty, $roll:path, $kept:ty, $counted:ty,
+     [$($gen:tt)*], [$($bound:tt)*]) => {
+        impl<'a, $($gen)*> BasicAggregation<'a> for $window<'a, $data>
+            where $($bound)*
+        {
+            type Kept = $kept;
+            type Counted = $counted;
+
+            fn sum(&'a self) -> Self::Kept {
+                $roll(self.data, |x| self.kernel().sum(x))
+            }
+
+            fn count(&'a self) -> Self::Counted {
+                $roll(self.data, |x| self.kernel().count(x))
+            }
+        }
+
+        impl<'a, $($gen)*> ComparisonAggregation<'a> for $window<'a, $data>
+            where $($bound)*
+        {
+            type Kept = $kept;
+
+            fn min(&'a self) -> Self::Kept {
+                $roll(self.data, |x| self.kernel().min(x))
+            }
+
+            fn max(&'a self) -> Self::Kept {
+                $roll(self.data, |x| self.kernel().max(x))
+            }
+        }
+    }
+}
+
+/// Implement `NumericAggregation` on window types whose `kernel` supports
+/// `mean`, `var` and `std`
+macro_rules! impl_window_numeric_aggregation {
+    ($window:ident, $data:ty, $roll:path, $coerced:ty,
+     [$($gen:tt)*], [$($bound:tt)*]) => {
+        impl<'a, $($gen)*> NumericAggregation<'a> for $window<'a, $data>
+            where $($bound)*
+        {
+            type Coerced = $coerced;
+
+            fn mean(&'a self) -> Self::Coerced {
+                $roll(self.data, |x| self.kernel().mean(x))
+            }
+
+            fn var(&'a self) -> Self::Coerced {
+                $roll(self.data, |x| self.kernel().var(x, 0))
+            }
+
+            fn unbiased_var(&'a self) -> Self::Coerced {
+                $roll(self.data, |x| self.kernel().var(x, 1))
+            }
+
+            fn std(&'a self) -> Self::Coerced {
+                $roll(self.data, |x| self.kernel().std(x, 0))
+            }
+
+            fn unbiased_std(&'a self) -> Self::Coerced {
+                $roll(self.data, |x| self.kernel().std(x, 1))
+            }
+        }
+    }
+}
+
+/// Apply generic function to `NullVec` contained in `Array`, and wrap the
+/// result with the same variant
+macro_rules! map_array {
+    ($values:expr, $func:ident $(, $arg:expr)*) => {
+        match $values {
+            &Array::Int64Array(ref vals) => Array::Int64Array($func(vals $(, $arg)*)),
+            &Array::Int32Array(ref vals) => Array::Int32Array($func(vals $(, $arg)*)),
+            &Array::Int16Array(ref vals) => Array::Int16Array($func(vals $(, $arg)*)),
+            &Array::Int8Array(ref vals) => Array::Int8Array($func(vals $(, $arg)*)),
+            &Array::IsizeArray(ref vals) => Array::IsizeArray($func(vals $(, $arg)*)),
+            &Array::UInt64Array(ref vals) => Array::UInt64Array($func(vals $(, $arg)*)),
+            &Array::UInt32Array(ref vals) => Array::UInt32Array($func(vals $(, $arg)*)),
+            &Array::UInt16Array(ref vals) => Array::UInt16Array($func(vals $(, $arg)*)),
+            &Array::UInt8Array(ref vals) => Array::UInt8Array($func(vals $(, $arg)*)),
+            &Array::UsizeArray(ref vals) => Array::UsizeArray($func(vals $(, $arg)*)),
+            &Array::Float64Array(ref vals) => Array::Float64Array($func(vals $(, $arg)*)),
+            &Array::Float32Array(ref vals) => Array::Float32Array($func(vals $(, $arg)*)),
+            &Array::BoolArray(ref vals) => Array::BoolArray($func(vals $(, $arg)*)),
+            &Array::StringArray(ref vals) => Array::StringArray($func(vals $(, $arg)*)),
+        }
+    }
+}
+
+/// Same as `map_array`, only for numeric variants
+macro_rules! map_numeric_array {
+    ($values:expr, $func:ident $(, $arg:expr)*) => {
+        match $values {
+            &Array::Int64Array(ref vals) => Array::Int64Array($func(vals $(, $arg)*)),
+            &Array::Int32Array(ref vals) => Array::Int32Array($func(vals $(, $arg)*)),
+            &Array::Int16Array(ref vals) => Array::Int16Array($func(vals $(, $arg)*)),
+            &Array::Int8Array(ref vals) => Array::Int8Array($func(vals $(, $arg)*)),
+            &Array::IsizeArray(ref vals) => Array::IsizeArray($func(vals $(, $arg)*)),
+            &Array::UInt64Array(ref vals) => Array::UInt64Array($func(vals $(, $arg)*)),
+            &Array::UInt32Array(ref vals) => Array::UInt32Array($func(vals $(, $arg)*)),
+            &Array::UInt16Array(ref vals) => Array::UInt16Array($func(vals $(, $arg)*)),
+            &Array::UInt8Array(ref vals) => Array::UInt8Array($func(vals $(, $arg)*)),
+            &Array::UsizeArray(ref vals) => Array::UsizeArray($func(vals $(, $arg)*)),
+            &Array::Float64Array(ref vals) => Array::Float64Array($func(vals $(, $arg)*)),
+            &Array::Float32Array(ref vals) => Array::Float32Array($func(vals $(, $arg)*)),
+            _ => panic!("{} is not numeric", $values.dtype()),
+        }
+    }
+}
+
+/// Reduce non-null values of numeric `Array` to `Scalar` keeping its dtype
+macro_rules! reduce_numeric_array {
+    ($values:expr, $func:path) => {
+        match $values {
+            &Array::Int64Array(ref vals) => Scalar::from($func(&vals.not_null_values())),
+            &Array::Int32Array(ref vals) => Scalar::from($func(&vals.not_null_values())),
+            &Array::Int16Array(ref vals) => Scalar::from($func(&vals.not_null_values())),
+            &Array::Int8Array(ref vals) => Scalar::from($func(&vals.not_null_values())),
+            &Array::IsizeArray(ref vals) => Scalar::from($func(&vals.not_null_values())),
+            &Array::UInt64Array(ref vals) => Scalar::from($func(&vals.not_null_values())),
+            &Array::UInt32Array(ref vals) => Scalar::from($func(&vals.not_null_values())),
+            &Array::UInt16Array(ref vals) => Scalar::from($func(&vals.not_null_values())),
+            &Array::UInt8Array(ref vals) => Scalar::from($func(&vals.not_null_values())),
+            &Array::UsizeArray(ref vals) => Scalar::from($func(&vals.not_null_values())),
+            &Array::Float64Array(ref vals) => Scalar::from($func(&vals.not_null_values())),
+            &Array::Float32Array(ref vals) => Scalar::from($func(&vals.not_null_values())),
+            _ => panic!("{} is not numeric", $values.dtype()),
+        }
+    }
+}
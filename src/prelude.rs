@@ -6,8 +6,23 @@ pub use nullvec::prelude::BasicAggregation as NBasicAggregation;
 pub use nullvec::prelude::NumericAggregation as NNumericAggregation;
 pub use nullvec::prelude::ComparisonAggregation as NComparisonAggregation;
 
+pub use datetime::{Datetime, DatetimeParseError, Frequency};
 pub use frame::DataFrame;
-pub use indexer::Indexer;
+pub use indexer::{Indexer, DatetimeIndex};
+pub use io::{CsvReadOptions, CsvWriteOptions, CsvChunks, CommentFilter, Schema, FwfFormat,
+             TableFormat};
+#[cfg(feature = "json")]
+pub use io::{JsonOrient, JsonError};
+#[cfg(feature = "parquet")]
+pub use io::{ParquetWriteOptions, ParquetRowGroups};
 pub use series::Series;
+pub use algos::correlation::CorrMethod;
+pub use algos::interpolate::{Interpolation, LimitDirection};
+pub use algos::kernels::{Vectorized, Primitive};
+pub use algos::missing::DropHow;
+pub use algos::quantile::QuantileInterpolation;
+pub use algos::rank::{RankMethod, NaOption};
+pub use window::{Rolling, Expanding, Ewm, Decay};
 pub use traits::{Slicer, IndexerIndex, RowIndex, ColIndex, Append, Concatenation, Join, Apply,
-                 BasicAggregation, NumericAggregation, ComparisonAggregation, Description};
+                 BasicAggregation, NumericAggregation, HigherOrderAggregation, ComparisonAggregation,
+                 Description};
@@ -1,15 +1,20 @@
-use num::{Zero, ToPrimitive};
+use num::{Zero, One, ToPrimitive};
 use std::hash::Hash;
-use std::ops::{Add, Sub, Div};
+use std::ops::{Add, Sub, Mul, Div};
+
+use nullvec::prelude::Array;
 
 use super::Series;
-use algos::computation::{Aggregation, NanMinMax};
-use algos::counter::Counter;
-use traits::{Apply, BasicAggregation, NumericAggregation, ComparisonAggregation, Description};
+use algos::computation::{Aggregation, NanMinMax, Summation, Product};
+use algos::counter::{Counter, array_mode, array_nunique};
+use algos::quantile::{quantiles, describe, describe_percentiles, describe_labels, percentile_labels,
+                      QuantileInterpolation};
+use traits::{Apply, BasicAggregation, NumericAggregation, HigherOrderAggregation,
+             ComparisonAggregation, Description};
 
 
 impl<'v, 'i, V, I> BasicAggregation<'i> for Series<'v, 'i, V, I>
-    where V: Clone + Zero + Add,
+    where V: Clone + Summation<V>,
           I: Clone + Eq + Hash
 {
     type Kept = V;
@@ -52,6 +57,42 @@ impl<'v, 'i, V, I> NumericAggregation<'i> for Series<'v, 'i, V, I>
     }
 }
 
+impl<'v, 'i, V, I> HigherOrderAggregation<'i> for Series<'v, 'i, V, I>
+    where V: Clone + Zero + One + Add + Sub + Mul + Div + ToPrimitive,
+          I: Clone + Eq + Hash
+{
+    type Kept = V;
+    type Coerced = f64;
+
+    fn prod(&'i self) -> Self::Kept {
+        self.apply(&Aggregation::vec_prod)
+    }
+
+    fn skew(&'i self) -> Self::Coerced {
+        self.apply(&Aggregation::vec_skew)
+    }
+
+    fn unbiased_skew(&'i self) -> Self::Coerced {
+        self.apply(&Aggregation::vec_unbiased_skew)
+    }
+
+    fn kurt(&'i self) -> Self::Coerced {
+        self.apply(&Aggregation::vec_kurt)
+    }
+
+    fn unbiased_kurt(&'i self) -> Self::Coerced {
+        self.apply(&Aggregation::vec_unbiased_kurt)
+    }
+
+    fn sem(&'i self) -> Self::Coerced {
+        self.apply(&Aggregation::vec_sem)
+    }
+
+    fn unbiased_sem(&'i self) -> Self::Coerced {
+        self.apply(&Aggregation::vec_unbiased_sem)
+    }
+}
+
 impl<'v, 'i, V, I> ComparisonAggregation<'i> for Series<'v, 'i, V, I>
     where V: Clone + NanMinMax<V>,
           I: Clone + Eq + Hash
@@ -68,20 +109,66 @@ impl<'v, 'i, V, I> ComparisonAggregation<'i> for Series<'v, 'i, V, I>
 }
 
 impl<'v, 'i, V, I> Description<'i> for Series<'v, 'i, V, I>
-    where V: Clone + Zero + Add + Sub + Div + ToPrimitive + NanMinMax<V>,
+    where V: Clone + Zero + Add + Sub + Div + ToPrimitive + NanMinMax<V> + Summation<V>,
           I: Clone + Eq + Hash
 {
-    type Described = Series<'i, 'i, f64, &'i str>;
+    type Described = Series<'i, 'i, f64, String>;
 
-    fn describe(&'i self) -> Self::Described {
-        let new_index: Vec<&str> = vec!["count", "mean", "std", "min", "max"];
-        let count: f64 = Aggregation::vec_count(&self.values) as f64;
+    fn describe_percentiles(&'i self, percentiles: &[f64]) -> Self::Described {
+        let percentiles = describe_percentiles(percentiles);
+        let new_values = describe(&self.to_f64_values(), &percentiles);
+        Series::new(new_values, describe_labels(&percentiles))
+    }
+}
 
-        let min = ToPrimitive::to_f64(&self.min()).unwrap();
-        let max = ToPrimitive::to_f64(&self.max()).unwrap();
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+    where V: Clone + Summation<V>,
+          I: Clone + Eq + Hash
+{
+    /// Return the sum, `None` if integer sum overflows
+    pub fn checked_sum(&self) -> Option<V> {
+        self.apply(&Aggregation::vec_checked_sum)
+    }
+}
 
-        let new_values: Vec<f64> = vec![count, self.mean(), self.std(), min, max];
-        Series::new(new_values, new_index)
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+    where V: Clone + Product<V>,
+          I: Clone + Eq + Hash
+{
+    /// Return the product, `None` if integer product overflows
+    pub fn checked_prod(&self) -> Option<V> {
+        self.apply(&Aggregation::vec_checked_prod)
+    }
+}
+
+// Quantile
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+    where V: Clone + ToPrimitive,
+          I: Clone + Eq + Hash
+{
+    fn to_f64_values(&self) -> Vec<f64> {
+        self.values.iter().map(|x| x.to_f64().unwrap()).collect()
+    }
+
+    /// Return the median, NaN is ignored
+    pub fn median(&self) -> f64 {
+        self.quantile(0.5, QuantileInterpolation::Linear)
+    }
+
+    /// Return the value at quantile `q` (between 0 and 1), NaN is ignored.
+    /// Return NaN if `q` is out of range.
+    pub fn quantile(&self, q: f64, interpolation: QuantileInterpolation) -> f64 {
+        quantiles(&self.to_f64_values(), &[q], interpolation)[0]
+    }
+
+    /// Return values at each quantile, labeled as percentile like "25%"
+    pub fn quantiles<'a>(&self,
+                         qs: &[f64],
+                         interpolation: QuantileInterpolation)
+                         -> Series<'a, 'a, f64, String> {
+        let new_values = quantiles(&self.to_f64_values(), qs, interpolation);
+        Series::new(new_values, percentile_labels(qs))
     }
 }
 
@@ -97,3 +184,22 @@ impl<'v, 'i, V, I> Series<'v, 'i, V, I>
         Series::new(counts, keys)
     }
 }
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+    where V: Clone,
+          I: Clone + Eq + Hash,
+          Array: From<Vec<V>>
+{
+    /// Return the most frequent values in ascending order, NaN is ignored
+    pub fn mode<'a>(&self) -> Series<'a, 'a, V, usize>
+        where Vec<V>: From<Array>
+    {
+        let modes = array_mode(&Array::from(self.values.to_vec()));
+        Series::<V, usize>::from_vec(modes.into())
+    }
+
+    /// Return the number of distinct values, NaN is ignored
+    pub fn nunique(&self) -> usize {
+        array_nunique(&Array::from(self.values.to_vec()))
+    }
+}
@@ -0,0 +1,72 @@
+use std::hash::Hash;
+
+use num::ToPrimitive;
+
+use super::Series;
+use algos::correlation::{corr, cov, CorrMethod};
+use traits::IndexerIndex;
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+    where V: Clone + ToPrimitive,
+          I: Clone + Eq + Hash
+{
+    /// Pair values having the same label as f64, labels which only exist
+    /// in either Series are skipped
+    fn align_f64<W>(&self, other: &Series<W, I>) -> (Vec<f64>, Vec<f64>)
+        where W: Clone + ToPrimitive
+    {
+        let mut x: Vec<f64> = Vec::with_capacity(self.values.len());
+        let mut y: Vec<f64> = Vec::with_capacity(self.values.len());
+        for (label, value) in self.index.iter().zip(self.values.iter()) {
+            if other.index.contains(label) {
+                let loc = other.index.get_loc(label);
+                x.push(value.to_f64().unwrap());
+                y.push(other.values[loc].to_f64().unwrap());
+            }
+        }
+        (x, y)
+    }
+
+    /// Compute correlation with other Series aligned by index, using
+    /// pairs where both are not null
+    pub fn corr<W>(&self, other: &Series<W, I>, method: CorrMethod) -> f64
+        where W: Clone + ToPrimitive
+    {
+        let (x, y) = self.align_f64(other);
+        corr(&x, &y, method)
+    }
+
+    /// Compute unbiased covariance with other Series aligned by index,
+    /// using pairs where both are not null
+    pub fn cov<W>(&self, other: &Series<W, I>) -> f64
+        where W: Clone + ToPrimitive
+    {
+        let (x, y) = self.align_f64(other);
+        cov(&x, &y, 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::f64;
+
+    use super::super::Series;
+    use algos::correlation::CorrMethod;
+
+    #[test]
+    fn test_corr_aligned() {
+        let s1 = Series::new(vec![1., 2., 3., 4.], vec!["a", "b", "c", "d"]);
+        let s2 = Series::new(vec![8, 6, 2, 100], vec!["d", "c", "a", "x"]);
+        // pairs are (1, 2), (3, 6), (4, 8)
+        assert!((s1.corr(&s2, CorrMethod::Pearson) - 1.).abs() < 1e-10);
+        assert!((s1.cov(&s2) - 14. / 3.).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_corr_null() {
+        let s1 = Series::new(vec![1., f64::NAN, 3., 2.], vec![1, 2, 3, 4]);
+        let s2 = Series::new(vec![3., 2., 1., f64::NAN], vec![1, 2, 3, 4]);
+        assert!((s1.corr(&s2, CorrMethod::Spearman) - -1.).abs() < 1e-10);
+    }
+}
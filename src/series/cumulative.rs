@@ -0,0 +1,105 @@
+use std::borrow::{Borrow, Cow};
+use std::hash::Hash;
+use std::ops::{Add, Mul};
+
+use nullvec::prelude::dev::NullStorable;
+
+use super::Series;
+use algos::cumulative::{cumulate, cumcount};
+use groupby::GroupBy;
+
+/// Cumulate values of each group, null values are detected by `NullStorable`
+fn cumulate_values<V, F>(values: &[V], groups: &[&Vec<usize>], skipna: bool, func: F) -> Vec<V>
+    where V: Clone + NullStorable,
+          F: Fn(&V, &V) -> V
+{
+    let raw: Vec<(bool, V)> = values.iter().map(|v| (v.is_null(), v.clone())).collect();
+    cumulate(&raw, groups, skipna, func).into_iter().map(|(_, v)| v).collect()
+}
+
+fn min_value<V: Clone + PartialOrd>(a: &V, b: &V) -> V {
+    if b < a { b.clone() } else { a.clone() }
+}
+
+fn max_value<V: Clone + PartialOrd>(a: &V, b: &V) -> V {
+    if b > a { b.clone() } else { a.clone() }
+}
+
+/// /////////////////////////////////////////////////////////////////////////////
+/// Cumulative aggregation
+/// /////////////////////////////////////////////////////////////////////////////
+
+macro_rules! define_cumulative_op {
+    ($m:ident, $bound:path, $func:expr) => {
+        impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+            where V: Clone + NullStorable + $bound,
+                  I: Clone + Eq + Hash
+        {
+            /// If `skipna` is false, all values after the first null are null
+            pub fn $m<'a>(&'a self, skipna: bool) -> Series<'a, 'a, V, I> {
+                let all: Vec<usize> = (0..self.values.len()).collect();
+                let new_values = cumulate_values(&self.values, &[&all], skipna, $func);
+                Series::from_cow(Cow::Owned(new_values), Cow::Borrowed(self.index.borrow()))
+            }
+        }
+
+        impl<'b, 'v, 'i, V, I, G> GroupBy<'b, Series<'v, 'i, V, I>, G>
+            where V: Clone + NullStorable + $bound,
+                  I: Clone + Eq + Hash,
+                  G: Clone + Eq + Hash + Ord
+        {
+            /// Cumulate within each group, keeping original row order
+            pub fn $m<'a>(&'a self, skipna: bool) -> Series<'a, 'a, V, I> {
+                let new_values = cumulate_values(&self.data.values, &self.locs(), skipna, $func);
+                Series::from_cow(Cow::Owned(new_values),
+                                 Cow::Borrowed(self.data.index.borrow()))
+            }
+        }
+    }
+}
+
+define_cumulative_op!(cumsum, Add<Output = V>, |a: &V, b: &V| a.clone() + b.clone());
+define_cumulative_op!(cumprod, Mul<Output = V>, |a: &V, b: &V| a.clone() * b.clone());
+define_cumulative_op!(cummin, PartialOrd, min_value);
+define_cumulative_op!(cummax, PartialOrd, max_value);
+
+impl<'b, 'v, 'i, V, I, G> GroupBy<'b, Series<'v, 'i, V, I>, G>
+    where V: Clone,
+          I: Clone + Eq + Hash,
+          G: Clone + Eq + Hash + Ord
+{
+    /// Number each row within its group, starting from 0
+    pub fn cumcount<'a>(&'a self) -> Series<'a, 'a, usize, I> {
+        let new_values = cumcount(self.data.values.len(), &self.locs());
+        Series::from_cow(Cow::Owned(new_values), Cow::Borrowed(self.data.index.borrow()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::f64;
+
+    use super::super::Series;
+
+    #[test]
+    fn test_cumsum() {
+        let s = Series::new(vec![1, 2, 3], vec!["a", "b", "c"]);
+        assert_eq!(s.cumsum(true), Series::new(vec![1, 3, 6], vec!["a", "b", "c"]));
+        assert_eq!(s.cumprod(true), Series::new(vec![1, 2, 6], vec!["a", "b", "c"]));
+    }
+
+    #[test]
+    fn test_cumsum_nan() {
+        let s = Series::new(vec![1., f64::NAN, 3.], vec!["a", "b", "c"]);
+        let res = s.cumsum(true);
+        assert_eq!(res.values[0], 1.);
+        assert!(res.values[1].is_nan());
+        assert_eq!(res.values[2], 4.);
+
+        let res = s.cumsum(false);
+        assert_eq!(res.values[0], 1.);
+        assert!(res.values[1].is_nan());
+        assert!(res.values[2].is_nan());
+    }
+}
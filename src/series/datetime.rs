@@ -0,0 +1,114 @@
+use std::borrow::{Borrow, Cow};
+use std::hash::Hash;
+
+use nullvec::prelude::dev::algos::Indexing;
+
+use super::Series;
+use datetime::{Datetime, Frequency};
+use groupby::GroupBy;
+use traits::Slicer;
+
+/// /////////////////////////////////////////////////////////////////////////////
+/// Field accessors
+/// /////////////////////////////////////////////////////////////////////////////
+
+macro_rules! define_field_accessor {
+    ($m:ident, $t:ident) => {
+        impl<'v, 'i, I> Series<'v, 'i, Datetime, I>
+            where I: Clone + Eq + Hash
+        {
+            pub fn $m<'a>(&'a self) -> Series<'a, 'a, $t, I> {
+                let new_values: Vec<$t> = self.values.iter().map(|x| x.$m()).collect();
+                Series::from_cow(Cow::Owned(new_values), Cow::Borrowed(self.index.borrow()))
+            }
+        }
+    }
+}
+
+define_field_accessor!(year, i32);
+define_field_accessor!(month, u32);
+define_field_accessor!(day, u32);
+define_field_accessor!(hour, u32);
+define_field_accessor!(minute, u32);
+define_field_accessor!(second, u32);
+define_field_accessor!(weekday, u32);
+
+/// /////////////////////////////////////////////////////////////////////////////
+/// Range slicing
+/// /////////////////////////////////////////////////////////////////////////////
+
+impl<'v, 'i, V> Series<'v, 'i, V, Datetime>
+    where V: Clone
+{
+    /// Slice using time bounds of the index (inclusive)
+    pub fn time_slice(&self, start: &Datetime, end: &Datetime) -> Self {
+        let locs = self.index.time_locs(start, end);
+        let new_values = Indexing::reindex(&self.values, &locs);
+        Series::new(new_values, self.index.ilocs(&locs))
+    }
+}
+
+/// /////////////////////////////////////////////////////////////////////////////
+/// Shift
+/// /////////////////////////////////////////////////////////////////////////////
+
+impl<'v, 'i, V> Series<'v, 'i, V, Datetime>
+    where V: Clone
+{
+    /// Move index labels by given number of periods, values are unchanged
+    pub fn tshift<'a>(&'a self, periods: i64, freq: Frequency) -> Series<'a, 'a, V, Datetime> {
+        Series::from_cow(Cow::Borrowed(self.values.borrow()),
+                         Cow::Owned(self.index.tshift(periods, freq)))
+    }
+}
+
+/// /////////////////////////////////////////////////////////////////////////////
+/// Resampling
+/// /////////////////////////////////////////////////////////////////////////////
+
+impl<'v, 'i, V> Series<'v, 'i, V, Datetime>
+    where V: Clone
+{
+    /// Group values into time bins, labeled by the start of each bin.
+    /// Bins which contain no value are not included.
+    pub fn resample(&self, freq: Frequency) -> GroupBy<Series<V, Datetime>, Datetime> {
+        let bins = freq.bins(&self.index.values);
+        GroupBy::new(&self, bins)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::super::Series;
+    use datetime::Datetime;
+    use indexer::DatetimeIndex;
+
+    #[test]
+    fn test_series_field_accessors() {
+        let values = vec![Datetime::from_ymd_hms(2016, 12, 31, 23, 0, 0),
+                          Datetime::from_ymd_hms(2017, 1, 1, 1, 0, 0)];
+        let s = Series::new(values, vec!["a", "b"]);
+
+        assert_eq!(s.year(), Series::new(vec![2016, 2017], vec!["a", "b"]));
+        assert_eq!(s.month(), Series::new(vec![12, 1], vec!["a", "b"]));
+        assert_eq!(s.day(), Series::new(vec![31, 1], vec!["a", "b"]));
+        assert_eq!(s.hour(), Series::new(vec![23, 1], vec!["a", "b"]));
+        // Saturday, Sunday
+        assert_eq!(s.weekday(), Series::new(vec![5, 6], vec!["a", "b"]));
+    }
+
+    #[test]
+    fn test_series_time_slice() {
+        let idx = DatetimeIndex::parse(&vec!["2017-01-01 10:00", "2017-01-01 11:00",
+                                             "2017-01-01 12:00"],
+                                       "%Y-%m-%d %H:%M")
+            .unwrap();
+        let s = Series::new(vec![1, 2, 3], idx.clone());
+
+        let res = s.time_slice(&Datetime::from_ymd_hms(2017, 1, 1, 10, 30, 0),
+                               &Datetime::from_ymd_hms(2017, 1, 1, 12, 0, 0));
+        let exp = Series::new(vec![2, 3], vec![idx[1], idx[2]]);
+        assert_eq!(res, exp);
+    }
+}
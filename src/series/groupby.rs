@@ -1,14 +1,24 @@
 
-use num::{Zero, ToPrimitive};
+use num::{Zero, One, ToPrimitive};
 use std::cmp::Ord;
-use std::ops::{Add, Sub, Div};
+use std::ops::{Add, Sub, Mul, Div};
 use std::hash::Hash;
 
+use nullvec::prelude::Array;
+use nullvec::prelude::dev::NullStorable;
+#[cfg(feature = "parallel")]
+use nullvec::prelude::dev::algos::Indexing;
+
 use super::Series;
-use algos::computation::NanMinMax;
+use algos::computation::{Aggregation, NanMinMax, Summation};
 use algos::grouper::Grouper;
+#[cfg(feature = "parallel")]
+use algos::parallel;
+use algos::quantile::{describe_percentiles, describe_labels, QuantileInterpolation};
+use frame::DataFrame;
 use groupby::GroupBy;
-use traits::{Apply, BasicAggregation, NumericAggregation, ComparisonAggregation};
+use traits::{Apply, BasicAggregation, NumericAggregation, HigherOrderAggregation,
+             ComparisonAggregation, Description};
 
 /// /////////////////////////////////////////////////////////////////////////////
 /// Apply
@@ -39,12 +49,36 @@ impl<'v, 'i, V, I, G, W> Apply<'i, W> for GroupBy<'i, Series<'v, 'i, V, I>, G>
     }
 }
 
+#[cfg(feature = "parallel")]
+impl<'v, 'i, V, I, G> GroupBy<'i, Series<'v, 'i, V, I>, G>
+    where V: 'v + Clone + Send + Sync,
+          I: Clone + Eq + Hash + Send + Sync,
+          G: 'i + Clone + Eq + Hash + Ord
+{
+    /// Apply passed function to each group, same as `apply` but groups are
+    /// processed across threads
+    pub fn par_apply<W, F>(&'i self, func: F) -> Series<'i, 'i, W, G>
+        where W: 'i + Clone + Send,
+              F: Fn(&Series<'v, 'i, V, I>) -> W + Sync + Send
+    {
+        let values: &[V] = &self.data.values;
+        let index: &[I] = &self.data.index.values;
+
+        let new_values: Vec<W> = parallel::map(&self.locs(), |locs| {
+            let s = Series::new(Indexing::reindex(values, locs),
+                                Indexing::reindex(index, locs));
+            func(&s)
+        });
+        Series::new(new_values, self.groups())
+    }
+}
+
 /// /////////////////////////////////////////////////////////////////////////////
 /// Aggregation
 /// /////////////////////////////////////////////////////////////////////////////
 
 impl<'v, 'i, V, I, G> BasicAggregation<'i> for GroupBy<'i, Series<'v, 'i, V, I>, G>
-    where V: Clone + Zero + Add,
+    where V: Clone + Summation<V>,
           I: Clone + Eq + Hash,
           G: 'i + Clone + Eq + Hash + Ord
 {
@@ -92,6 +126,45 @@ impl<'v, 'i, V, I, G> NumericAggregation<'i> for GroupBy<'i, Series<'v, 'i, V, I
     }
 }
 
+impl<'v, 'i, V, I, G> HigherOrderAggregation<'i> for GroupBy<'i, Series<'v, 'i, V, I>, G>
+    where V: Clone + Zero + One + Add + Sub + Mul + Div + ToPrimitive,
+          I: Clone + Eq + Hash,
+          G: 'i + Clone + Eq + Hash + Ord
+{
+    // result can have different lifetime
+    // ToDo: use 'n lifetime for value
+    type Kept = Series<'i, 'i, V, G>;
+    type Coerced = Series<'i, 'i, f64, G>;
+
+    fn prod(&'i self) -> Self::Kept {
+        self.apply(&|x: &Series<V, I>| x.prod())
+    }
+
+    fn skew(&'i self) -> Self::Coerced {
+        self.apply(&|x: &Series<V, I>| x.skew())
+    }
+
+    fn unbiased_skew(&'i self) -> Self::Coerced {
+        self.apply(&|x: &Series<V, I>| x.unbiased_skew())
+    }
+
+    fn kurt(&'i self) -> Self::Coerced {
+        self.apply(&|x: &Series<V, I>| x.kurt())
+    }
+
+    fn unbiased_kurt(&'i self) -> Self::Coerced {
+        self.apply(&|x: &Series<V, I>| x.unbiased_kurt())
+    }
+
+    fn sem(&'i self) -> Self::Coerced {
+        self.apply(&|x: &Series<V, I>| x.sem())
+    }
+
+    fn unbiased_sem(&'i self) -> Self::Coerced {
+        self.apply(&|x: &Series<V, I>| x.unbiased_sem())
+    }
+}
+
 impl<'v, 'i, V, I, G> ComparisonAggregation<'i> for GroupBy<'i, Series<'v, 'i, V, I>, G>
     where V: Clone + NanMinMax<V>,
           I: Clone + Eq + Hash,
@@ -110,6 +183,145 @@ impl<'v, 'i, V, I, G> ComparisonAggregation<'i> for GroupBy<'i, Series<'v, 'i, V
     }
 }
 
+impl<'v, 'i, V, I, G> Description<'i> for GroupBy<'i, Series<'v, 'i, V, I>, G>
+    where V: Clone + Zero + Add + Sub + Div + ToPrimitive + NanMinMax<V> + Summation<V>,
+          I: Clone + Eq + Hash,
+          G: 'i + Clone + Eq + Hash + Ord
+{
+    type Described = DataFrame<'i, 'i, 'i, G, String>;
+
+    /// Describe each group as a row
+    fn describe_percentiles(&'i self, percentiles: &[f64]) -> Self::Described {
+        let groups = self.groups();
+        let described: Vec<Vec<f64>> = groups.iter()
+            .map(|g| self.get_group(g).describe_percentiles(percentiles).values.into_owned())
+            .collect();
+
+        let new_columns = describe_labels(&describe_percentiles(percentiles));
+        let new_values: Vec<Array> = (0..new_columns.len())
+            .map(|i| {
+                let column: Vec<f64> = described.iter().map(|d| d[i]).collect();
+                column.into()
+            })
+            .collect();
+        DataFrame::from_vec(new_values, groups, new_columns)
+    }
+}
+
+impl<'v, 'i, V, I, G> GroupBy<'i, Series<'v, 'i, V, I>, G>
+    where V: Clone + ToPrimitive,
+          I: Clone + Eq + Hash,
+          G: 'i + Clone + Eq + Hash + Ord
+{
+    /// Return the median of each group
+    pub fn median(&'i self) -> Series<'i, 'i, f64, G> {
+        self.apply(&|x: &Series<V, I>| x.median())
+    }
+
+    /// Return the value at quantile `q` (between 0 and 1) of each group
+    pub fn quantile(&'i self,
+                    q: f64,
+                    interpolation: QuantileInterpolation)
+                    -> Series<'i, 'i, f64, G> {
+        self.apply(&|x: &Series<V, I>| x.quantile(q, interpolation))
+    }
+}
+
+impl<'v, 'i, V, I, G> GroupBy<'i, Series<'v, 'i, V, I>, G>
+    where V: Clone,
+          I: Clone + Eq + Hash,
+          G: 'i + Clone + Eq + Hash + Ord,
+          Array: From<Vec<V>>,
+          Vec<V>: From<Array>
+{
+    /// Return the number of distinct values of each group, NaN is ignored
+    pub fn nunique(&'i self) -> Series<'i, 'i, usize, G> {
+        self.apply(&|x: &Series<V, I>| x.nunique())
+    }
+
+    /// Return the most frequent value of each group, the smallest one on ties.
+    /// `None` if all values are NaN.
+    pub fn mode(&'i self) -> Series<'i, 'i, Option<V>, G> {
+        self.apply(&|x: &Series<V, I>| x.mode().values.first().cloned())
+    }
+}
+
+impl<'v, 'i, V, I, G> GroupBy<'i, Series<'v, 'i, V, I>, G>
+    where V: Clone + PartialOrd + NullStorable,
+          I: 'i + Clone + Eq + Hash,
+          G: 'i + Clone + Eq + Hash + Ord
+{
+    /// Return location of the minimum value of each group, within the group.
+    /// `None` if all values are null.
+    pub fn argmin(&'i self) -> Series<'i, 'i, Option<usize>, G> {
+        self.apply(&|x: &Series<V, I>| x.argmin())
+    }
+
+    /// Return location of the maximum value of each group, within the group.
+    /// `None` if all values are null.
+    pub fn argmax(&'i self) -> Series<'i, 'i, Option<usize>, G> {
+        self.apply(&|x: &Series<V, I>| x.argmax())
+    }
+
+    /// Return label of the minimum value of each group.
+    /// `None` if all values are null.
+    pub fn idxmin(&'i self) -> Series<'i, 'i, Option<I>, G> {
+        self.apply(&|x: &Series<V, I>| x.idxmin())
+    }
+
+    /// Return label of the maximum value of each group.
+    /// `None` if all values are null.
+    pub fn idxmax(&'i self) -> Series<'i, 'i, Option<I>, G> {
+        self.apply(&|x: &Series<V, I>| x.idxmax())
+    }
+}
+
+/// /////////////////////////////////////////////////////////////////////////////
+/// Selection
+/// /////////////////////////////////////////////////////////////////////////////
+
+impl<'v, 'i, V, I, G> GroupBy<'i, Series<'v, 'i, V, I>, G>
+    where V: Clone,
+          I: Clone + Eq + Hash,
+          G: 'i + Clone + Eq + Hash + Ord
+{
+    /// Return the first value of each group
+    pub fn first(&'i self) -> Series<'i, 'i, V, G> {
+        self.apply(&|x: &Series<V, I>| x.values[0].clone())
+    }
+
+    /// Return the last value of each group
+    pub fn last(&'i self) -> Series<'i, 'i, V, G> {
+        self.apply(&|x: &Series<V, I>| x.values[x.values.len() - 1].clone())
+    }
+}
+
+impl<'v, 'i, V, I, G> GroupBy<'i, Series<'v, 'i, V, I>, G>
+    where V: Clone + NanMinMax<V>,
+          Vec<V>: Into<Array>,
+          I: Clone + Eq + Hash,
+          G: 'i + Clone + Eq + Hash + Ord
+{
+    /// Return open (first), high, low and close (last) of each group
+    pub fn ohlc(&'i self) -> DataFrame<'i, 'i, 'i, G, &'static str> {
+        let groups = self.groups();
+        let mut open: Vec<V> = Vec::with_capacity(groups.len());
+        let mut high: Vec<V> = Vec::with_capacity(groups.len());
+        let mut low: Vec<V> = Vec::with_capacity(groups.len());
+        let mut close: Vec<V> = Vec::with_capacity(groups.len());
+
+        for g in groups.iter() {
+            let s = self.get_group(g);
+            open.push(s.values[0].clone());
+            high.push(Aggregation::vec_max(&s.values));
+            low.push(Aggregation::vec_min(&s.values));
+            close.push(s.values[s.values.len() - 1].clone());
+        }
+        let new_values: Vec<Array> = vec![open.into(), high.into(), low.into(), close.into()];
+        DataFrame::from_vec(new_values, groups, vec!["open", "high", "low", "close"])
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
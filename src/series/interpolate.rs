@@ -0,0 +1,72 @@
+use std::borrow::{Borrow, Cow};
+use std::hash::Hash;
+
+use num::ToPrimitive;
+
+use super::Series;
+use algos::interpolate::{interpolate, positions, Interpolation, LimitDirection};
+use traits::Slicer;
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+    where V: Clone + ToPrimitive,
+          I: Clone + Eq + Hash
+{
+    /// Fill NaN by interpolating values, treating them as equally spaced
+    pub fn interpolate<'a>(&'a self,
+                           method: Interpolation,
+                           limit: Option<usize>,
+                           direction: LimitDirection)
+                           -> Series<'a, 'a, f64, I> {
+        let xs = positions(self.index.len());
+        self.interpolate_at(&xs, method, limit, direction)
+    }
+
+    fn interpolate_at<'a>(&'a self,
+                          xs: &[f64],
+                          method: Interpolation,
+                          limit: Option<usize>,
+                          direction: LimitDirection)
+                          -> Series<'a, 'a, f64, I> {
+        let values: Vec<f64> = self.values.iter().map(|x| x.to_f64().unwrap()).collect();
+        let new_values = interpolate(&values, xs, method, limit, direction);
+        Series::from_cow(Cow::Owned(new_values), Cow::Borrowed(self.index.borrow()))
+    }
+}
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+    where V: Clone + ToPrimitive,
+          I: Clone + Eq + Hash + ToPrimitive
+{
+    /// Fill NaN by linear interpolation, using numeric or time index labels
+    /// as spacing
+    pub fn interpolate_index<'a>(&'a self,
+                                 limit: Option<usize>,
+                                 direction: LimitDirection)
+                                 -> Series<'a, 'a, f64, I> {
+        let xs: Vec<f64> = self.index.iter().map(|x| x.to_f64().unwrap()).collect();
+        self.interpolate_at(&xs, Interpolation::Linear, limit, direction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::f64;
+
+    use super::super::Series;
+    use algos::interpolate::{Interpolation, LimitDirection};
+
+    #[test]
+    fn test_interpolate() {
+        let s = Series::new(vec![1., f64::NAN, f64::NAN, 4.], vec!["a", "b", "c", "d"]);
+        let res = s.interpolate(Interpolation::Linear, None, LimitDirection::Forward);
+        assert_eq!(res, Series::new(vec![1., 2., 3., 4.], vec!["a", "b", "c", "d"]));
+    }
+
+    #[test]
+    fn test_interpolate_index() {
+        let s = Series::new(vec![0., f64::NAN, 10.], vec![0, 2, 10]);
+        let res = s.interpolate_index(None, LimitDirection::Forward);
+        assert_eq!(res, Series::new(vec![0., 2., 10.], vec![0, 2, 10]));
+    }
+}
@@ -0,0 +1,98 @@
+use std::borrow::{Borrow, Cow};
+use std::hash::Hash;
+
+use nullvec::prelude::dev::NullStorable;
+use nullvec::prelude::dev::algos::Indexing;
+
+use super::Series;
+use algos::missing::{fill_forward, fill_backward};
+use traits::Slicer;
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+    where V: Clone + NullStorable,
+          I: Clone + Eq + Hash
+{
+    /// Pairs of null flag and value
+    fn raw_values(&self) -> Vec<(bool, V)> {
+        self.values.iter().map(|v| (v.is_null(), v.clone())).collect()
+    }
+
+    pub fn isnull<'a>(&'a self) -> Series<'a, 'a, bool, I> {
+        let new_values: Vec<bool> = self.values.iter().map(|v| v.is_null()).collect();
+        Series::from_cow(Cow::Owned(new_values), Cow::Borrowed(self.index.borrow()))
+    }
+
+    pub fn notnull<'a>(&'a self) -> Series<'a, 'a, bool, I> {
+        let new_values: Vec<bool> = self.values.iter().map(|v| !v.is_null()).collect();
+        Series::from_cow(Cow::Owned(new_values), Cow::Borrowed(self.index.borrow()))
+    }
+
+    /// Replace null values with given value
+    pub fn fillna<'a>(&'a self, value: V) -> Series<'a, 'a, V, I> {
+        let new_values: Vec<V> = self.values
+            .iter()
+            .map(|v| if v.is_null() { value.clone() } else { v.clone() })
+            .collect();
+        Series::from_cow(Cow::Owned(new_values), Cow::Borrowed(self.index.borrow()))
+    }
+
+    /// Propagate last valid value forward, filling at most `limit` consecutive nulls
+    pub fn ffill<'a>(&'a self, limit: Option<usize>) -> Series<'a, 'a, V, I> {
+        let new_values: Vec<V> = fill_forward(&self.raw_values(), limit)
+            .into_iter()
+            .map(|(_, v)| v)
+            .collect();
+        Series::from_cow(Cow::Owned(new_values), Cow::Borrowed(self.index.borrow()))
+    }
+
+    /// Propagate next valid value backward, filling at most `limit` consecutive nulls
+    pub fn bfill<'a>(&'a self, limit: Option<usize>) -> Series<'a, 'a, V, I> {
+        let new_values: Vec<V> = fill_backward(&self.raw_values(), limit)
+            .into_iter()
+            .map(|(_, v)| v)
+            .collect();
+        Series::from_cow(Cow::Owned(new_values), Cow::Borrowed(self.index.borrow()))
+    }
+
+    /// Remove null values with their labels
+    pub fn dropna(&self) -> Self {
+        let flags: Vec<bool> = self.values.iter().map(|v| !v.is_null()).collect();
+        let new_values: Vec<V> = Indexing::blocs(&self.values, &flags);
+        Series::new(new_values, self.index.blocs(&flags))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::f64;
+
+    use super::super::Series;
+
+    #[test]
+    fn test_isnull() {
+        let s = Series::new(vec![1., f64::NAN, 3.], vec!["a", "b", "c"]);
+        assert_eq!(s.isnull(), Series::new(vec![false, true, false], vec!["a", "b", "c"]));
+        assert_eq!(s.notnull(), Series::new(vec![true, false, true], vec!["a", "b", "c"]));
+    }
+
+    #[test]
+    fn test_fillna() {
+        let s = Series::new(vec![f64::NAN, 2., f64::NAN, f64::NAN, 5.], vec![1, 2, 3, 4, 5]);
+        assert_eq!(s.fillna(0.), Series::new(vec![0., 2., 0., 0., 5.], vec![1, 2, 3, 4, 5]));
+
+        let res = s.ffill(Some(1));
+        assert!(res.values[0].is_nan());
+        assert_eq!(res.values[1..3], [2., 2.]);
+        assert!(res.values[3].is_nan());
+
+        let res = s.bfill(None);
+        assert_eq!(res, Series::new(vec![2., 2., 5., 5., 5.], vec![1, 2, 3, 4, 5]));
+    }
+
+    #[test]
+    fn test_dropna() {
+        let s = Series::new(vec![f64::NAN, 2., f64::NAN, 4.], vec![1, 2, 3, 4]);
+        assert_eq!(s.dropna(), Series::new(vec![2., 4.], vec![2, 4]));
+    }
+}
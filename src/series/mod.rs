@@ -4,6 +4,9 @@ use std::iter::FromIterator;
 use std::slice;
 use std::vec;
 
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
 use nullvec::prelude::dev::algos::Indexing;
 use indexer::Indexer;
 use groupby::GroupBy;
@@ -11,12 +14,23 @@ use traits::{Slicer, IndexerIndex, RowIndex, Append, Apply};
 
 mod aggregation;
 mod convert;
+mod correlation;
+mod cumulative;
+mod datetime;
 mod formatting;
 mod groupby;
+mod interpolate;
+mod missing;
 mod ops;
+mod rank;
+#[cfg(feature = "serde")]
+mod serialize;
+mod shift;
 mod sort;
+mod window;
 
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct Series<'v, 'i, V, I>
     where V: 'v + Clone,
           I: 'i + Clone + Hash
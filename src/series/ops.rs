@@ -3,10 +3,10 @@ use std::hash::Hash;
 use std::ops::{Add, Mul, Sub, Div, Rem, BitAnd, BitOr, BitXor};
 
 use super::Series;
-use nullvec::prelude::dev::algos::Elemwise;
+use algos::kernels::Vectorized;
 
 macro_rules! define_numeric_op {
-    ($t:ident, $m:ident) => {
+    ($t:ident, $m:ident, $scalar:ident) => {
 
         // Broadcast
         impl<'v, 'i, V, I, O> $t<V> for Series<'v, 'i, V, I>
@@ -17,8 +17,7 @@ macro_rules! define_numeric_op {
             type Output = Series<'v, 'i, O, I>;
             fn $m(self, _rhs: V) -> Self::Output {
                 // binary ops doesn't require value's ownership
-                let new_values: Vec<O> = Elemwise::broadcast_ro(self.values.as_ref(),
-                                                                _rhs, |x, y| x.$m(y));
+                let new_values: Vec<O> = Vectorized::$scalar(self.values.as_ref(), _rhs);
                 // self is moved, pass index to new instance
                 Series::from_cow(Cow::Owned(new_values), self.index)
             }
@@ -31,8 +30,8 @@ macro_rules! define_numeric_op {
 
             type Output = Series<'v, 'i, O, I>;
             fn $m(self, _rhs: &'r V) -> Self::Output {
-                let new_values: Vec<O> = Elemwise::broadcast_rr(self.values.as_ref(),
-                                                                _rhs, |x, y| x.$m(y));
+                let new_values: Vec<O> = Vectorized::$scalar(self.values.as_ref(),
+                                                             _rhs.clone());
                 Series::from_cow(Cow::Owned(new_values), self.index)
             }
         }
@@ -44,8 +43,7 @@ macro_rules! define_numeric_op {
 
             type Output = Series<'l, 'l, O, I>;
             fn $m(self, _rhs: V) -> Self::Output {
-                let new_values: Vec<O> = Elemwise::broadcast_ro(self.values.as_ref(),
-                                                                _rhs, |x, y| x.$m(y));
+                let new_values: Vec<O> = Vectorized::$scalar(self.values.as_ref(), _rhs);
                 Series::from_cow(Cow::Owned(new_values),
                                  Cow::Borrowed(self.index.borrow()))
             }
@@ -58,8 +56,8 @@ macro_rules! define_numeric_op {
 
             type Output = Series<'l, 'l, O, I>;
             fn $m(self, _rhs: &'r V) -> Self::Output {
-                let new_values: Vec<O> = Elemwise::broadcast_rr(self.values.as_ref(),
-                                                                _rhs, |x, y| x.$m(y));
+                let new_values: Vec<O> = Vectorized::$scalar(self.values.as_ref(),
+                                                             _rhs.clone());
                 Series::from_cow(Cow::Owned(new_values),
                                  Cow::Borrowed(self.index.borrow()))
             }
@@ -74,9 +72,8 @@ macro_rules! define_numeric_op {
             type Output = Series<'lv, 'li, O, I>;
             fn $m(self, _rhs: Series<V, I>) -> Self::Output {
                 self.assert_binop(&_rhs);
-                let new_values: Vec<O> = Elemwise::elemwise_oo(self.values.into_owned(),
-                                                               _rhs.values.into_owned(),
-                                                               |x, y| x.$m(y));
+                let new_values: Vec<O> = Vectorized::$m(self.values.as_ref(),
+                                                        _rhs.values.as_ref());
                 Series::from_cow(Cow::Owned(new_values), self.index)
             }
         }
@@ -90,9 +87,8 @@ macro_rules! define_numeric_op {
             type Output = Series<'lv, 'li, O, I>;
             fn $m(self, _rhs: &'r Series<V, I>) -> Self::Output {
                 self.assert_binop(&_rhs);
-                let new_values: Vec<O> = Elemwise::elemwise_or(self.values.into_owned(),
-                                                               &_rhs.values.as_ref(),
-                                                               |x, y| x.$m(y));
+                let new_values: Vec<O> = Vectorized::$m(self.values.as_ref(),
+                                                        _rhs.values.as_ref());
                 Series::from_cow(Cow::Owned(new_values), self.index)
             }
         }
@@ -108,9 +104,8 @@ macro_rules! define_numeric_op {
                 self.assert_binop(&_rhs);
 
                 // ToDo: match with Cow::Owned / Borrowed
-                let new_values: Vec<O> = Elemwise::elemwise_ro(&self.values.as_ref(),
-                                                               _rhs.values.into_owned(),
-                                                               |x, y| x.$m(y));
+                let new_values: Vec<O> = Vectorized::$m(self.values.as_ref(),
+                                                        _rhs.values.as_ref());
                 Series::from_cow(Cow::Owned(new_values),
                                  Cow::Borrowed(self.index.borrow()))
             }
@@ -126,9 +121,8 @@ macro_rules! define_numeric_op {
             type Output = Series<'l, 'l, O, I>;
             fn $m(self, _rhs: &'r Series<V, I>) -> Self::Output {
                 self.assert_binop(&_rhs);
-                let new_values: Vec<O> = Elemwise::elemwise_rr(&self.values,
-                                                               &_rhs.values,
-                                                               |x, y| x.$m(y));
+                let new_values: Vec<O> = Vectorized::$m(self.values.as_ref(),
+                                                        _rhs.values.as_ref());
                 Series::from_cow(Cow::Owned(new_values),
                                  Cow::Borrowed(self.index.borrow()))
             }
@@ -136,18 +130,20 @@ macro_rules! define_numeric_op {
     }
 }
 
-define_numeric_op!(Add, add);
-define_numeric_op!(Mul, mul);
-define_numeric_op!(Sub, sub);
-define_numeric_op!(Div, div);
-define_numeric_op!(Rem, rem);
-define_numeric_op!(BitAnd, bitand);
-define_numeric_op!(BitOr, bitor);
-define_numeric_op!(BitXor, bitxor);
+define_numeric_op!(Add, add, add_scalar);
+define_numeric_op!(Mul, mul, mul_scalar);
+define_numeric_op!(Sub, sub, sub_scalar);
+define_numeric_op!(Div, div, div_scalar);
+define_numeric_op!(Rem, rem, rem_scalar);
+define_numeric_op!(BitAnd, bitand, bitand_scalar);
+define_numeric_op!(BitOr, bitor, bitor_scalar);
+define_numeric_op!(BitXor, bitxor, bitxor_scalar);
 
 #[cfg(test)]
 mod tests {
 
+    use nullvec::prelude::Nullable;
+
     use super::super::Series;
 
     #[test]
@@ -417,4 +413,27 @@ mod tests {
         let exp = Series::<bool, i64>::new(vec![true, true, false], vec![10, 20, 30]);
         assert_eq!(result, exp);
     }
+
+    #[test]
+    fn test_series_ops_nullable() {
+        let s = Series::<Nullable<i64>, i64>::new(vec![Nullable::new(4),
+                                                       Nullable::Null,
+                                                       Nullable::new(6)],
+                                                  vec![10, 20, 30]);
+        let r = Series::<Nullable<i64>, i64>::new(vec![Nullable::new(2),
+                                                       Nullable::new(0),
+                                                       Nullable::Null],
+                                                  vec![10, 20, 30]);
+        // null divisors never raise
+        let result = &s / &r;
+        assert_eq!(result.values.to_vec(),
+                   vec![Nullable::new(2), Nullable::Null, Nullable::Null]);
+
+        let result = &s + Nullable::new(1);
+        assert_eq!(result.values.to_vec(),
+                   vec![Nullable::new(5), Nullable::Null, Nullable::new(7)]);
+
+        let result = s - Nullable::Null;
+        assert_eq!(result.values.to_vec(), vec![Nullable::Null; 3]);
+    }
 }
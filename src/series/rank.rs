@@ -0,0 +1,114 @@
+use std::borrow::{Borrow, Cow};
+use std::f64;
+use std::hash::Hash;
+
+use nullvec::prelude::dev::NullStorable;
+use nullvec::prelude::dev::algos::Indexing;
+
+use super::Series;
+use algos::rank::{rank, argmin, argmax, RankMethod, NaOption};
+use groupby::GroupBy;
+use traits::Slicer;
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+    where V: Clone + PartialOrd + NullStorable,
+          I: Clone + Eq + Hash
+{
+    fn null_flags(&self) -> Vec<bool> {
+        self.values.iter().map(|v| v.is_null()).collect()
+    }
+
+    /// Compute rank of each value starting from 1
+    pub fn rank<'a>(&'a self,
+                    method: RankMethod,
+                    ascending: bool,
+                    na_option: NaOption)
+                    -> Series<'a, 'a, f64, I> {
+        let new_values = rank(&self.values, &self.null_flags(), method, ascending, na_option);
+        Series::from_cow(Cow::Owned(new_values), Cow::Borrowed(self.index.borrow()))
+    }
+
+    /// Return location of the minimum value, nulls are skipped.
+    /// `None` if all values are null.
+    pub fn argmin(&self) -> Option<usize> {
+        argmin(&self.values, &self.null_flags())
+    }
+
+    /// Return location of the maximum value, nulls are skipped.
+    /// `None` if all values are null.
+    pub fn argmax(&self) -> Option<usize> {
+        argmax(&self.values, &self.null_flags())
+    }
+
+    /// Return label of the minimum value, nulls are skipped.
+    /// `None` if all values are null.
+    pub fn idxmin(&self) -> Option<I> {
+        self.argmin().map(|x| self.index.iloc(&x))
+    }
+
+    /// Return label of the maximum value, nulls are skipped.
+    /// `None` if all values are null.
+    pub fn idxmax(&self) -> Option<I> {
+        self.argmax().map(|x| self.index.iloc(&x))
+    }
+}
+
+impl<'b, 'v, 'i, V, I, G> GroupBy<'b, Series<'v, 'i, V, I>, G>
+    where V: Clone + PartialOrd + NullStorable,
+          I: Clone + Eq + Hash,
+          G: Clone + Eq + Hash + Ord
+{
+    /// Compute rank of each value within its group, keeping original order
+    pub fn rank<'a>(&'a self,
+                    method: RankMethod,
+                    ascending: bool,
+                    na_option: NaOption)
+                    -> Series<'a, 'a, f64, I> {
+        let mut new_values: Vec<f64> = vec![f64::NAN; self.data.values.len()];
+        for locs in self.locs() {
+            let values: Vec<V> = Indexing::reindex(&self.data.values, locs);
+            let nulls: Vec<bool> = values.iter().map(|v| v.is_null()).collect();
+            let ranked = rank(&values, &nulls, method, ascending, na_option);
+            for (&loc, r) in locs.iter().zip(ranked.into_iter()) {
+                new_values[loc] = r;
+            }
+        }
+        Series::from_cow(Cow::Owned(new_values),
+                         Cow::Borrowed(self.data.index.borrow()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::f64;
+
+    use super::super::Series;
+    use algos::rank::{RankMethod, NaOption};
+
+    #[test]
+    fn test_rank() {
+        let s = Series::new(vec![2., f64::NAN, 1., 2.], vec!["a", "b", "c", "d"]);
+        let res = s.rank(RankMethod::Average, true, NaOption::Bottom);
+        assert_eq!(res, Series::new(vec![2.5, 4., 1., 2.5], vec!["a", "b", "c", "d"]));
+
+        let res = s.rank(RankMethod::Dense, false, NaOption::Top);
+        assert_eq!(res, Series::new(vec![2., 1., 3., 2.], vec!["a", "b", "c", "d"]));
+    }
+
+    #[test]
+    fn test_argmin_argmax() {
+        let s = Series::new(vec![f64::NAN, 3., 1., 5.], vec!["a", "b", "c", "d"]);
+        assert_eq!(s.argmin(), Some(2));
+        assert_eq!(s.argmax(), Some(3));
+        assert_eq!(s.idxmin(), Some("c"));
+        assert_eq!(s.idxmax(), Some("d"));
+    }
+
+    #[test]
+    fn test_argmin_all_null() {
+        let s = Series::new(vec![f64::NAN, f64::NAN], vec!["a", "b"]);
+        assert_eq!(s.argmin(), None);
+        assert_eq!(s.idxmax(), None);
+    }
+}
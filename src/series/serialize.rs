@@ -0,0 +1,111 @@
+//! Deserialize `Series`, validating lengths and reading null as `None` for
+//! optional values or NaN for float values
+
+use serde::{Deserialize, Deserializer};
+use serde::de::{Error, IntoDeserializer, Visitor};
+use serde::de::value::{F64Deserializer, UnitDeserializer};
+use std::borrow::Cow;
+use std::f64;
+use std::fmt;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+use indexer::Indexer;
+use super::Series;
+
+/// Values where null (e.g. JSON `null` serialized from NaN) is read as the
+/// dtype's own null if it has one (e.g. `None`), otherwise as NaN. Thus null
+/// is still an error for dtypes which cannot hold either. Binary formats keep
+/// NaN as it is.
+fn deserialize_values<'de, D, V>(deserializer: D) -> Result<Vec<V>, D::Error>
+    where D: Deserializer<'de>,
+          V: Deserialize<'de>
+{
+    if !deserializer.is_human_readable() {
+        return Vec::deserialize(deserializer);
+    }
+    let values: Vec<NullAsNan<V>> = try!(Vec::deserialize(deserializer));
+    Ok(values.into_iter().map(|x| x.0).collect())
+}
+
+/// Single value read by `deserialize_values`
+struct NullAsNan<V>(V);
+
+impl<'de, V> Deserialize<'de> for NullAsNan<V>
+    where V: Deserialize<'de>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: Deserializer<'de>
+    {
+        deserializer.deserialize_option(NullAsNanVisitor(PhantomData))
+    }
+}
+
+struct NullAsNanVisitor<V>(PhantomData<V>);
+
+impl<'de, V> NullAsNanVisitor<V>
+    where V: Deserialize<'de>
+{
+    fn null<E: Error>(self) -> Result<NullAsNan<V>, E> {
+        let unit: UnitDeserializer<E> = ().into_deserializer();
+        if let Ok(v) = V::deserialize(unit) {
+            return Ok(NullAsNan(v));
+        }
+        let nan: F64Deserializer<E> = f64::NAN.into_deserializer();
+        Ok(NullAsNan(try!(V::deserialize(nan))))
+    }
+}
+
+impl<'de, V> Visitor<'de> for NullAsNanVisitor<V>
+    where V: Deserialize<'de>
+{
+    type Value = NullAsNan<V>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a value or null")
+    }
+
+    fn visit_none<E: Error>(self) -> Result<Self::Value, E> {
+        self.null()
+    }
+
+    fn visit_unit<E: Error>(self) -> Result<Self::Value, E> {
+        self.null()
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where D: Deserializer<'de>
+    {
+        Ok(NullAsNan(try!(V::deserialize(deserializer))))
+    }
+}
+
+/// Same fields as `Series`, to be validated before building it
+#[derive(Deserialize)]
+#[serde(rename = "Series", bound(deserialize = "V: Deserialize<'de>, I: Deserialize<'de>"))]
+struct RawSeries<V, I: Clone + Hash> {
+    #[serde(deserialize_with = "deserialize_values")]
+    values: Vec<V>,
+    index: Indexer<I>,
+}
+
+impl<'de, 'v, 'i, V, I> Deserialize<'de> for Series<'v, 'i, V, I>
+    where V: Clone + Deserialize<'de>,
+          I: Clone + Hash + Deserialize<'de>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: Deserializer<'de>
+    {
+        let raw: RawSeries<V, I> = try!(RawSeries::deserialize(deserializer));
+        if raw.values.len() != raw.index.values.len() {
+            return Err(D::Error::custom(format!("values length {} doesn't match index \
+                                                 length {}",
+                                                raw.values.len(),
+                                                raw.index.values.len())));
+        }
+        Ok(Series {
+            values: Cow::Owned(raw.values),
+            index: Cow::Owned(raw.index),
+        })
+    }
+}
@@ -0,0 +1,86 @@
+use std::borrow::{Borrow, Cow};
+use std::hash::Hash;
+
+use num::ToPrimitive;
+use nullvec::prelude::Nullable;
+use nullvec::prelude::dev::NullStorable;
+
+use super::Series;
+use algos::shift::{shift_locs, diff, pct_change};
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+    where V: Clone,
+          I: Clone + Eq + Hash
+{
+    /// Shift values by given periods keeping the index. Positive periods
+    /// move values forward, and locations shifted out of range are null.
+    pub fn shift<'a>(&'a self, periods: isize) -> Series<'a, 'a, Nullable<V>, I>
+        where V: NullStorable
+    {
+        let new_values: Vec<Nullable<V>> = shift_locs(self.values.len(), periods)
+            .into_iter()
+            .map(|loc| match loc {
+                Some(j) => Nullable::new(self.values[j].clone()),
+                None => Nullable::Null,
+            })
+            .collect();
+        Series::from_cow(Cow::Owned(new_values), Cow::Borrowed(self.index.borrow()))
+    }
+
+    /// Difference from the value `periods` before, NaN if out of range.
+    /// Computed as `f64` so that integer values can't overflow.
+    pub fn diff<'a>(&'a self, periods: isize) -> Series<'a, 'a, f64, I>
+        where V: ToPrimitive
+    {
+        let values: Vec<f64> = self.values.iter().map(|x| x.to_f64().unwrap()).collect();
+        Series::from_cow(Cow::Owned(diff(&values, periods)),
+                         Cow::Borrowed(self.index.borrow()))
+    }
+
+    /// Percentage change from the value `periods` before, NaN if out of range
+    pub fn pct_change<'a>(&'a self, periods: isize) -> Series<'a, 'a, f64, I>
+        where V: ToPrimitive
+    {
+        let values: Vec<f64> = self.values.iter().map(|x| x.to_f64().unwrap()).collect();
+        Series::from_cow(Cow::Owned(pct_change(&values, periods)),
+                         Cow::Borrowed(self.index.borrow()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use nullvec::prelude::Nullable;
+
+    use super::super::Series;
+
+    #[test]
+    fn test_shift() {
+        let s = Series::new(vec![1, 2, 3], vec!["a", "b", "c"]);
+        let res = s.shift(1);
+        assert_eq!(res.values.to_vec(),
+                   vec![Nullable::Null, Nullable::new(1), Nullable::new(2)]);
+        assert_eq!(res.index.values, vec!["a", "b", "c"]);
+
+        let res = s.shift(-2);
+        assert_eq!(res.values.to_vec(),
+                   vec![Nullable::new(3), Nullable::Null, Nullable::Null]);
+    }
+
+    #[test]
+    fn test_diff() {
+        let s = Series::new(vec![1, 4, 9], vec!["a", "b", "c"]);
+        let res = s.diff(1);
+        assert!(res.values[0].is_nan());
+        assert_eq!(&res.values[1..], &[3., 5.]);
+
+        // unsigned and extreme values don't overflow
+        let s = Series::new(vec![3u8, 1, 255], vec!["a", "b", "c"]);
+        let res = s.diff(1);
+        assert_eq!(&res.values[1..], &[-2., 254.]);
+
+        let s = Series::new(vec![i64::min_value(), i64::max_value()], vec!["a", "b"]);
+        let res = s.diff(-1);
+        assert_eq!(res.values[0], i64::min_value() as f64 - i64::max_value() as f64);
+    }
+}
@@ -0,0 +1,130 @@
+use std::borrow::{Borrow, Cow};
+use std::hash::Hash;
+
+use num::ToPrimitive;
+
+use super::Series;
+use algos::window::apply_by_groups;
+use groupby::GroupBy;
+use traits::{BasicAggregation, NumericAggregation, ComparisonAggregation};
+use window::{Rolling, Expanding, Ewm, Decay};
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+    where V: Clone,
+          I: Clone + Eq + Hash
+{
+    pub fn rolling(&self, window: usize) -> Rolling<Series<V, I>> {
+        Rolling::new(&self, window)
+    }
+
+    pub fn expanding(&self) -> Expanding<Series<V, I>> {
+        Expanding::new(&self)
+    }
+
+    pub fn ewm(&self, decay: Decay) -> Ewm<Series<V, I>> {
+        Ewm::new(&self, decay)
+    }
+}
+
+impl<'b, 'v, 'i, V, I, G> GroupBy<'b, Series<'v, 'i, V, I>, G>
+    where V: Clone,
+          I: Clone + Eq + Hash,
+          G: Clone + Eq + Hash + Ord
+{
+    pub fn rolling(&self, window: usize) -> Rolling<GroupBy<'b, Series<'v, 'i, V, I>, G>> {
+        Rolling::new(&self, window)
+    }
+
+    pub fn expanding(&self) -> Expanding<GroupBy<'b, Series<'v, 'i, V, I>, G>> {
+        Expanding::new(&self)
+    }
+
+    pub fn ewm(&self, decay: Decay) -> Ewm<GroupBy<'b, Series<'v, 'i, V, I>, G>> {
+        Ewm::new(&self, decay)
+    }
+}
+
+/// /////////////////////////////////////////////////////////////////////////////
+/// Aggregation
+/// /////////////////////////////////////////////////////////////////////////////
+
+fn to_f64_vec<V: ToPrimitive>(values: &[V]) -> Vec<f64> {
+    values.iter().map(|x| x.to_f64().unwrap()).collect()
+}
+
+/// Apply passed kernel to values converted to f64
+fn roll<'a, 'v, 'i, V, I, W, F>(data: &'a Series<'v, 'i, V, I>, func: F) -> Series<'a, 'a, W, I>
+    where V: Clone + ToPrimitive,
+          I: Clone + Eq + Hash,
+          W: Clone,
+          F: Fn(&[f64]) -> Vec<W>
+{
+    let new_values = func(&to_f64_vec(&data.values));
+    Series::from_cow(Cow::Owned(new_values), Cow::Borrowed(data.index.borrow()))
+}
+
+/// Apply passed kernel to each group, keeping original row order
+fn roll_groups<'a, 'b, 'v, 'i, V, I, G, W, F>(data: &'a GroupBy<'b, Series<'v, 'i, V, I>, G>,
+                                              func: F)
+                                              -> Series<'a, 'a, W, I>
+    where V: Clone + ToPrimitive,
+          I: Clone + Eq + Hash,
+          G: Clone + Eq + Hash + Ord,
+          W: Clone,
+          F: Fn(&[f64]) -> Vec<W>
+{
+    let locs = data.locs();
+    let new_values = apply_by_groups(&to_f64_vec(&data.data.values), &locs, func);
+    Series::from_cow(Cow::Owned(new_values), Cow::Borrowed(data.data.index.borrow()))
+}
+
+impl_window_basic_aggregation!(Rolling, Series<'v, 'i, V, I>, roll,
+                               Series<'a, 'a, f64, I>, Series<'a, 'a, usize, I>,
+                               ['v, 'i, V, I],
+                               [V: Clone + ToPrimitive, I: Clone + Eq + Hash]);
+impl_window_numeric_aggregation!(Rolling, Series<'v, 'i, V, I>, roll,
+                                 Series<'a, 'a, f64, I>,
+                                 ['v, 'i, V, I],
+                                 [V: Clone + ToPrimitive, I: Clone + Eq + Hash]);
+
+impl_window_basic_aggregation!(Expanding, Series<'v, 'i, V, I>, roll,
+                               Series<'a, 'a, f64, I>, Series<'a, 'a, usize, I>,
+                               ['v, 'i, V, I],
+                               [V: Clone + ToPrimitive, I: Clone + Eq + Hash]);
+impl_window_numeric_aggregation!(Expanding, Series<'v, 'i, V, I>, roll,
+                                 Series<'a, 'a, f64, I>,
+                                 ['v, 'i, V, I],
+                                 [V: Clone + ToPrimitive, I: Clone + Eq + Hash]);
+
+impl_window_numeric_aggregation!(Ewm, Series<'v, 'i, V, I>, roll,
+                                 Series<'a, 'a, f64, I>,
+                                 ['v, 'i, V, I],
+                                 [V: Clone + ToPrimitive, I: Clone + Eq + Hash]);
+
+impl_window_basic_aggregation!(Rolling, GroupBy<'b, Series<'v, 'i, V, I>, G>, roll_groups,
+                               Series<'a, 'a, f64, I>, Series<'a, 'a, usize, I>,
+                               ['b, 'v, 'i, V, I, G],
+                               [V: Clone + ToPrimitive, I: Clone + Eq + Hash,
+                                G: Clone + Eq + Hash + Ord]);
+impl_window_numeric_aggregation!(Rolling, GroupBy<'b, Series<'v, 'i, V, I>, G>, roll_groups,
+                                 Series<'a, 'a, f64, I>,
+                                 ['b, 'v, 'i, V, I, G],
+                                 [V: Clone + ToPrimitive, I: Clone + Eq + Hash,
+                                  G: Clone + Eq + Hash + Ord]);
+
+impl_window_basic_aggregation!(Expanding, GroupBy<'b, Series<'v, 'i, V, I>, G>, roll_groups,
+                               Series<'a, 'a, f64, I>, Series<'a, 'a, usize, I>,
+                               ['b, 'v, 'i, V, I, G],
+                               [V: Clone + ToPrimitive, I: Clone + Eq + Hash,
+                                G: Clone + Eq + Hash + Ord]);
+impl_window_numeric_aggregation!(Expanding, GroupBy<'b, Series<'v, 'i, V, I>, G>, roll_groups,
+                                 Series<'a, 'a, f64, I>,
+                                 ['b, 'v, 'i, V, I, G],
+                                 [V: Clone + ToPrimitive, I: Clone + Eq + Hash,
+                                  G: Clone + Eq + Hash + Ord]);
+
+impl_window_numeric_aggregation!(Ewm, GroupBy<'b, Series<'v, 'i, V, I>, G>, roll_groups,
+                                 Series<'a, 'a, f64, I>,
+                                 ['b, 'v, 'i, V, I, G],
+                                 [V: Clone + ToPrimitive, I: Clone + Eq + Hash,
+                                  G: Clone + Eq + Hash + Ord]);
@@ -1,5 +1,7 @@
 //! Common Traits
 
+use algos::quantile::DEFAULT_PERCENTILES;
+
 /// Indexing methods for non-labeled Array / Indexer
 pub use nullvec::prelude::Slicer;
 
@@ -130,6 +132,23 @@ pub trait NumericAggregation<'s> {
     fn unbiased_std(&'s self) -> Self::Coerced;
 }
 
+pub trait HigherOrderAggregation<'s> {
+    // result which can keep current dtype
+    type Kept;
+    // result which is coerced (to f64 or its container)
+    type Coerced;
+
+    fn prod(&'s self) -> Self::Kept;
+    fn skew(&'s self) -> Self::Coerced;
+    fn unbiased_skew(&'s self) -> Self::Coerced;
+    // excess kurtosis
+    fn kurt(&'s self) -> Self::Coerced;
+    fn unbiased_kurt(&'s self) -> Self::Coerced;
+    // standard error of the mean
+    fn sem(&'s self) -> Self::Coerced;
+    fn unbiased_sem(&'s self) -> Self::Coerced;
+}
+
 pub trait ComparisonAggregation<'s> {
     type Kept;
 
@@ -141,5 +160,11 @@ pub trait Description<'s>
     : BasicAggregation<'s> + NumericAggregation<'s> + ComparisonAggregation<'s> {
     type Described;
 
-    fn describe(&'s self) -> Self::Described;
+    /// Describe with 25%, 50% and 75% percentiles
+    fn describe(&'s self) -> Self::Described {
+        self.describe_percentiles(&DEFAULT_PERCENTILES)
+    }
+
+    /// Describe with given percentiles, 50% is always included
+    fn describe_percentiles(&'s self, percentiles: &[f64]) -> Self::Described;
 }
@@ -0,0 +1,157 @@
+use algos::window::{RollingWindow, ExpandingWindow, EwmWindow};
+
+pub struct Rolling<'a, D: 'a> {
+    /// Moving window over rows
+    /// D: data to be aggregated
+    pub data: &'a D,
+    pub window: usize,
+    /// Minimum number of not null observations to produce a value
+    pub min_periods: usize,
+    /// Label the result at the center of the window rather than the right edge
+    pub center: bool,
+}
+
+impl<'a, D> Rolling<'a, D> {
+    pub fn new(data: &'a D, window: usize) -> Self {
+        assert!(window > 0, "window must be positive");
+        Rolling {
+            data: data,
+            window: window,
+            min_periods: window,
+            center: false,
+        }
+    }
+
+    pub fn min_periods(mut self, min_periods: usize) -> Self {
+        self.min_periods = min_periods;
+        self
+    }
+
+    pub fn center(mut self, center: bool) -> Self {
+        self.center = center;
+        self
+    }
+
+    pub fn kernel(&self) -> RollingWindow {
+        RollingWindow::new(self.window, self.min_periods, self.center)
+    }
+}
+
+pub struct Expanding<'a, D: 'a> {
+    /// Window which contains all rows up to current one
+    /// D: data to be aggregated
+    pub data: &'a D,
+    /// Minimum number of not null observations to produce a value
+    pub min_periods: usize,
+}
+
+impl<'a, D> Expanding<'a, D> {
+    pub fn new(data: &'a D) -> Self {
+        Expanding {
+            data: data,
+            min_periods: 1,
+        }
+    }
+
+    pub fn min_periods(mut self, min_periods: usize) -> Self {
+        self.min_periods = min_periods;
+        self
+    }
+
+    pub fn kernel(&self) -> ExpandingWindow {
+        ExpandingWindow::new(self.min_periods)
+    }
+}
+
+/// Decay parameter of exponentially weighted window, converted to alpha
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Decay {
+    /// Center of mass, alpha = 1 / (1 + com)
+    Com(f64),
+    /// alpha = 2 / (span + 1)
+    Span(f64),
+    /// alpha = 1 - exp(ln(0.5) / halflife)
+    Halflife(f64),
+    Alpha(f64),
+}
+
+impl Decay {
+    pub fn alpha(&self) -> f64 {
+        match *self {
+            Decay::Com(com) => {
+                assert!(com >= 0., "com must be >= 0");
+                1. / (1. + com)
+            }
+            Decay::Span(span) => {
+                assert!(span >= 1., "span must be >= 1");
+                2. / (span + 1.)
+            }
+            Decay::Halflife(halflife) => {
+                assert!(halflife > 0., "halflife must be > 0");
+                1. - (0.5f64.ln() / halflife).exp()
+            }
+            Decay::Alpha(alpha) => {
+                assert!(alpha > 0. && alpha <= 1., "alpha must be in (0, 1]");
+                alpha
+            }
+        }
+    }
+}
+
+pub struct Ewm<'a, D: 'a> {
+    /// Exponentially weighted window
+    /// D: data to be aggregated
+    pub data: &'a D,
+    pub alpha: f64,
+    /// Divide by decaying adjustment factor in beginning periods
+    pub adjust: bool,
+    /// Ignore nulls when calculating weights
+    pub ignore_na: bool,
+    /// Minimum number of not null observations to produce a value
+    pub min_periods: usize,
+}
+
+impl<'a, D> Ewm<'a, D> {
+    pub fn new(data: &'a D, decay: Decay) -> Self {
+        Ewm {
+            data: data,
+            alpha: decay.alpha(),
+            adjust: true,
+            ignore_na: false,
+            min_periods: 0,
+        }
+    }
+
+    pub fn adjust(mut self, adjust: bool) -> Self {
+        self.adjust = adjust;
+        self
+    }
+
+    pub fn ignore_na(mut self, ignore_na: bool) -> Self {
+        self.ignore_na = ignore_na;
+        self
+    }
+
+    pub fn min_periods(mut self, min_periods: usize) -> Self {
+        self.min_periods = min_periods;
+        self
+    }
+
+    pub fn kernel(&self) -> EwmWindow {
+        EwmWindow::new(self.alpha, self.adjust, self.ignore_na, self.min_periods)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::Decay;
+
+    #[test]
+    fn test_decay_alpha() {
+        assert_eq!(Decay::Com(1.).alpha(), 0.5);
+        assert_eq!(Decay::Span(3.).alpha(), 0.5);
+        assert!((Decay::Halflife(1.).alpha() - 0.5).abs() < 1e-12);
+        assert_eq!(Decay::Alpha(0.3).alpha(), 0.3);
+    }
+}
@@ -0,0 +1,26 @@
+use brassfibre::prelude::*;
+
+/// `DataFrame` having a column of each dtype, some of them containing null
+pub fn sample() -> DataFrame<'static, 'static, 'static, i64, String> {
+    let values = vec![Array::Int64Array(NullVec::with_mask(vec![1, 0, 3],
+                                                           Some(vec![false, true, false]))),
+                      array![1i32, 2, 3],
+                      array![1i16, 2, 3],
+                      array![1i8, 2, 3],
+                      array![1isize, 2, 3],
+                      array![1u64, 2, 3],
+                      array![1u32, 2, 3],
+                      array![1u16, 2, 3],
+                      array![1u8, 2, 3],
+                      array![1usize, 2, 3],
+                      Array::Float64Array(NullVec::with_mask(vec![1.5, 2.5, 0.],
+                                                             Some(vec![false, false, true]))),
+                      array![1.5f32, 2.5, 3.5],
+                      array![true, false, true],
+                      Array::StringArray(NullVec::with_mask(vec!["".to_string(),
+                                                                "é".to_string(),
+                                                                "xyz".to_string()],
+                                                            Some(vec![true, false, false])))];
+    let columns: Vec<String> = (0..values.len()).map(|x| format!("c{}", x)).collect();
+    DataFrame::from_vec(values, vec![10, 20, 30], columns)
+}
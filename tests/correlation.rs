@@ -0,0 +1,47 @@
+#[macro_use]
+extern crate brassfibre;
+use brassfibre::prelude::*;
+
+use std::f64;
+
+fn assert_array_close(res: Array, exp: Vec<f64>) {
+    let res: Vec<f64> = match res {
+        Array::Float64Array(vals) => vals.into(),
+        _ => panic!("not f64"),
+    };
+    assert_eq!(res.len(), exp.len());
+    for (r, e) in res.iter().zip(exp.iter()) {
+        assert!((r - e).abs() < 1e-10, "{:?} != {:?}", res, exp);
+    }
+}
+
+#[test]
+fn test_series_corr_cov() {
+    let s1 = Series::new(vec![1., 2., 3., 4., 5.], vec![1, 2, 3, 4, 5]);
+    let s2 = Series::new(vec![7, 8, 7, 6, 5], vec![5, 4, 3, 2, 1]);
+
+    assert!((s1.corr(&s2, CorrMethod::Pearson) - 0.8320502943378437).abs() < 1e-10);
+    assert!((s1.corr(&s2, CorrMethod::Spearman) - 0.8207826816681233).abs() < 1e-10);
+    assert!((s1.corr(&s2, CorrMethod::Kendall) - 0.7378647873726218).abs() < 1e-10);
+    assert!((s1.cov(&s2) - 1.5).abs() < 1e-10);
+}
+
+#[test]
+fn test_frame_corr_cov() {
+    let values = vec![array![1i64, 2, 3, 4],
+                      array!["a".to_string(), "b".to_string(), "c".to_string(), "d".to_string()],
+                      array![2., 4., f64::NAN, 8.],
+                      array![4., 3., 2., 1.]];
+    let df = DataFrame::from_vec(values, vec![1, 2, 3, 4], vec!["A", "B", "C", "D"]);
+
+    let res = df.corr(CorrMethod::Pearson);
+    assert_eq!(res.index.values, vec!["A", "C", "D"]);
+    assert_eq!(res.columns.values, vec!["A", "C", "D"]);
+    assert_array_close(res.get(&"A"), vec![1., 1., -1.]);
+    assert_array_close(res.get(&"C"), vec![1., 1., -1.]);
+    assert_array_close(res.get(&"D"), vec![-1., -1., 1.]);
+
+    let res = df.cov();
+    assert_array_close(res.get(&"A"), vec![5. / 3., 14. / 3., -5. / 3.]);
+    assert_array_close(res.get(&"C"), vec![14. / 3., 28. / 3., -14. / 3.]);
+}
@@ -0,0 +1,80 @@
+#[macro_use]
+extern crate brassfibre;
+use brassfibre::prelude::*;
+use std::f64;
+
+#[test]
+fn test_series_cumulative() {
+    let s: Series<i64, &str> = Series::new(vec![3, 1, 4, 2], vec!["a", "b", "c", "d"]);
+    assert_eq!(s.cumsum(true), Series::new(vec![3, 4, 8, 10], vec!["a", "b", "c", "d"]));
+    assert_eq!(s.cumprod(true), Series::new(vec![3, 3, 12, 24], vec!["a", "b", "c", "d"]));
+    assert_eq!(s.cummin(true), Series::new(vec![3, 1, 1, 1], vec!["a", "b", "c", "d"]));
+    assert_eq!(s.cummax(true), Series::new(vec![3, 3, 4, 4], vec!["a", "b", "c", "d"]));
+}
+
+#[test]
+fn test_series_cummax_nan() {
+    let s: Series<f64, usize> = Series::<f64, usize>::from_vec(vec![1., f64::NAN, 3., 2.]);
+    let res = s.cummax(true);
+    assert_eq!(res.values[0], 1.);
+    assert!(res.values[1].is_nan());
+    assert_eq!(&res.values[2..], &[3., 3.]);
+
+    let res = s.cummax(false);
+    assert_eq!(res.values[0], 1.);
+    assert!(res.values[1..].iter().all(|x| x.is_nan()));
+}
+
+#[test]
+fn test_series_groupby_cumulative() {
+    let s: Series<i64, &str> = Series::new(vec![1, 10, 2, 20, 3],
+                                           vec!["a", "b", "c", "d", "e"]);
+    let sg = s.groupby(vec!["x", "y", "x", "y", "x"]);
+    assert_eq!(sg.cumsum(true),
+               Series::new(vec![1, 10, 3, 30, 6], vec!["a", "b", "c", "d", "e"]));
+    assert_eq!(sg.cumcount(),
+               Series::new(vec![0, 0, 1, 1, 2], vec!["a", "b", "c", "d", "e"]));
+}
+
+#[test]
+fn test_frame_cumulative() {
+    let values = vec![Array::Int64Array(NullVec::with_mask(vec![1, 2, 3, 4],
+                                                           Some(vec![false, true, false,
+                                                                     false]))),
+                      array![1.5, 2., 0.5, 3.],
+                      array!["a".to_string(), "b".to_string(), "c".to_string(),
+                             "d".to_string()]];
+    let df: DataFrame<usize, &str> = DataFrame::from_vec(values, vec![0, 1, 2, 3],
+                                                         vec!["X", "Y", "Z"]);
+    let res = df.cumsum(true);
+    let exp_values = vec![Array::Int64Array(NullVec::with_mask(vec![1, 2, 4, 8],
+                                                               Some(vec![false, true, false,
+                                                                         false]))),
+                          array![1.5, 3.5, 4., 7.]];
+    let exp: DataFrame<usize, &str> = DataFrame::from_vec(exp_values, vec![0, 1, 2, 3],
+                                                          vec!["X", "Y"]);
+    assert_eq!(res, exp);
+
+    let res = df.cummin(false);
+    let exp_values = vec![Array::Int64Array(NullVec::with_mask(vec![1, 2, 2, 2],
+                                                               Some(vec![false, true, true,
+                                                                         true]))),
+                          array![1.5, 1.5, 0.5, 0.5]];
+    let exp: DataFrame<usize, &str> = DataFrame::from_vec(exp_values, vec![0, 1, 2, 3],
+                                                          vec!["X", "Y"]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_frame_groupby_cumulative() {
+    let values = vec![array![1, 10, 2, 20], array![1., 2., 3., 4.]];
+    let df: DataFrame<usize, &str> = DataFrame::from_vec(values, vec![0, 1, 2, 3],
+                                                         vec!["X", "Y"]);
+    let dg = df.groupby(vec![1, 2, 1, 2]);
+    let exp: DataFrame<usize, &str> = DataFrame::from_vec(vec![array![1, 10, 2, 200],
+                                                               array![1., 2., 3., 8.]],
+                                                          vec![0, 1, 2, 3],
+                                                          vec!["X", "Y"]);
+    assert_eq!(dg.cumprod(true), exp);
+    assert_eq!(dg.cumcount(), Series::new(vec![0, 0, 1, 1], vec![0, 1, 2, 3]));
+}
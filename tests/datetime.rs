@@ -0,0 +1,81 @@
+#[macro_use]
+extern crate brassfibre;
+use brassfibre::prelude::*;
+
+extern crate csv;
+
+#[test]
+fn test_datetime_index_parse() {
+    let idx = DatetimeIndex::parse(&vec!["2017-01-01 09:00", "2017-01-02 10:30"],
+                                   "%Y-%m-%d %H:%M")
+        .unwrap();
+    let exp = Indexer::new(vec![Datetime::from_ymd_hms(2017, 1, 1, 9, 0, 0),
+                                Datetime::from_ymd_hms(2017, 1, 2, 10, 30, 0)]);
+    assert_eq!(idx, exp);
+    assert_eq!(idx.get_loc(&Datetime::from_ymd_hms(2017, 1, 2, 10, 30, 0)), 1);
+    assert!(idx.contains(&Datetime::from_ymd_hms(2017, 1, 1, 9, 0, 0)));
+
+    let res = DatetimeIndex::parse(&vec!["2017-01-01", "x"], "%Y-%m-%d");
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_datetime_index_fields() {
+    let idx = DatetimeIndex::parse(&vec!["2017-01-01 09:00", "2017-02-15 10:30"],
+                                   "%Y-%m-%d %H:%M")
+        .unwrap();
+    assert_eq!(idx.year(), Series::new(vec![2017, 2017], idx.clone()));
+    assert_eq!(idx.month(), Series::new(vec![1, 2], idx.clone()));
+    assert_eq!(idx.day(), Series::new(vec![1, 15], idx.clone()));
+    assert_eq!(idx.hour(), Series::new(vec![9, 10], idx.clone()));
+    // Sunday, Wednesday
+    assert_eq!(idx.weekday(), Series::new(vec![6, 2], idx.clone()));
+}
+
+#[test]
+fn test_datetime_index_time_slice() {
+    let idx = DatetimeIndex::parse(&vec!["2017-01-01", "2017-01-02", "2017-01-03"],
+                                   "%Y-%m-%d")
+        .unwrap();
+    let res = idx.time_slice(&Datetime::from_ymd(2017, 1, 2),
+                             &Datetime::from_ymd(2017, 1, 5));
+    assert_eq!(res,
+               Indexer::new(vec![Datetime::from_ymd(2017, 1, 2), Datetime::from_ymd(2017, 1, 3)]));
+}
+
+#[test]
+fn test_frame_datetime_index_from_csv() {
+    let data = "date,value
+2017-01-01,1.5
+2017-01-02,2.5
+2017-01-03,3.5";
+
+    let rdr = csv::Reader::from_string(data).has_headers(true);
+    let df = DataFrame::<usize, String>::read_csv(rdr).unwrap();
+
+    let idx = DatetimeIndex::from_array(&df.get(&"date".to_string()), "%Y-%m-%d").unwrap();
+    let df = df.gets(&vec!["value".to_string()]).set_index(idx);
+
+    let res = df.time_slice(&Datetime::from_ymd(2017, 1, 2),
+                            &Datetime::from_ymd(2017, 1, 3));
+    let exp = DataFrame::from_vec(vec![array![2.5, 3.5]],
+                                  vec![Datetime::from_ymd(2017, 1, 2),
+                                       Datetime::from_ymd(2017, 1, 3)],
+                                  vec!["value".to_string()]);
+    assert_eq!(res, exp);
+
+    let err = DatetimeIndex::from_array(&array![1.5, 2.5], "%Y-%m-%d").unwrap_err();
+    assert_eq!(err.to_string(),
+               "unable to parse 'f64' with format '%Y-%m-%d': unsupported dtype, expected str \
+                or i64");
+
+    // null is an error rather than a panic
+    let values = Array::StringArray(NullVec::with_mask(vec!["2017-01-01".to_string(),
+                                                            "".to_string()],
+                                                       Some(vec![false, true])));
+    let err = DatetimeIndex::from_array(&values, "%Y-%m-%d").unwrap_err();
+    assert_eq!(err.to_string(),
+               "unable to parse 'null' with format '%Y-%m-%d': null is not allowed");
+    let values = Array::Int64Array(NullVec::with_mask(vec![0, 1], Some(vec![true, false])));
+    assert!(DatetimeIndex::from_array(&values, "%Y-%m-%d").is_err());
+}
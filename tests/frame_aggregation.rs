@@ -2,6 +2,8 @@
 extern crate brassfibre;
 use brassfibre::prelude::*;
 
+use std::f64;
+
 #[test]
 fn test_frame_agg() {
     let values: Vec<Array> = vec![array![1i64, 2, 3, 4, 5], array![6.0f64, 7., 8., 9., 10.]];
@@ -66,15 +68,15 @@ fn test_frame_agg_non_numerics() {
                                              vec!["B", "D"]);
     assert_eq!(df.mean(), exp);
 
-    let exp: Series<f64, &str> = Series::new(vec![9.7599999999999998, 5.621599999999999],
+    let exp: Series<f64, &str> = Series::new(vec![9.7599999999999998, 5.621600000000001],
                                              vec!["B", "D"]);
     assert_eq!(df.var(), exp);
 
-    let exp: Series<f64, &str> = Series::new(vec![12.199999999999999, 7.0269999999999992],
+    let exp: Series<f64, &str> = Series::new(vec![12.199999999999999, 7.027000000000001],
                                              vec!["B", "D"]);
     assert_eq!(df.unbiased_var(), exp);
 
-    let exp: Series<f64, &str> = Series::new(vec![3.1240998703626617, 2.3709913538433662],
+    let exp: Series<f64, &str> = Series::new(vec![3.1240998703626617, 2.3709913538433667],
                                              vec!["B", "D"]);
     assert_eq!(df.std(), exp);
 
@@ -91,6 +93,29 @@ fn test_frame_agg_non_numerics() {
     // assert_eq!(df.max(), exp);
 }
 
+#[test]
+fn test_frame_agg_higher_order() {
+    let values: Vec<Array> = vec![array![1i64, 2, 3, 4, 10],
+                                  array!["a".to_string(),
+                                         "b".to_string(),
+                                         "c".to_string(),
+                                         "d".to_string(),
+                                         "e".to_string()],
+                                  array![2., 2., f64::NAN, 2., 2.]];
+    let df = DataFrame::from_vec(values, vec![10, 20, 30, 40, 50], vec!["A", "B", "C"]);
+
+    assert_eq!(df.prod(), Series::new(vec![Scalar::i64(240), Scalar::f64(16.)], vec!["A", "C"]));
+    assert_eq!(df.skew(), Series::new(vec![1.1384199576606167, 0.], vec!["A", "C"]));
+
+    let res = df.unbiased_kurt();
+    assert!((res.values[0] - 3.152000000000001).abs() < 1e-10);
+    assert_eq!(res.values[1], 0.);
+
+    let res = df.unbiased_sem();
+    assert!((res.values[0] - (12.5f64 / 5.).sqrt()).abs() < 1e-10);
+    assert_eq!(res.values[1], 0.);
+}
+
 #[test]
 fn test_frame_describe() {
     let values: Vec<Array> = vec![array!["a".to_string(),
@@ -105,10 +130,65 @@ fn test_frame_describe() {
     let columns: Vec<&str> = vec!["A", "B", "C", "D"];
     let df = DataFrame::from_vec(values, index, columns);
 
-    let exp_values: Vec<Array> = vec![array![5., 3.8, 2.4819347291981715, 1., 8.],
-                                      array![5., 1.8400000000000003, 0.8912911982062878, 0.8, 3.2]];
-    let exp = DataFrame::from_vec(exp_values,
-                                  vec!["count", "mean", "std", "min", "max"],
-                                  vec!["B", "D"]);
+    let exp_values: Vec<Array> = vec![array![5., 3.8, 2.4819347291981715, 1., 2., 3., 5., 8.],
+                                      array![5., 1.84, 0.8912911982062878, 0.8,
+                                             1.1, 1.6, 2.5, 3.2]];
+    let exp_index: Vec<String> = vec!["count", "mean", "std", "min", "25%", "50%", "75%", "max"]
+        .iter()
+        .map(|x| x.to_string())
+        .collect();
+    let exp = DataFrame::from_vec(exp_values, exp_index, vec!["B", "D"]);
     assert_eq!(df.describe(), exp);
 }
+
+#[test]
+fn test_frame_quantile() {
+    let values: Vec<Array> = vec![array![1i64, 3, 2, 5, 8],
+                                  array!["a".to_string(),
+                                         "b".to_string(),
+                                         "c".to_string(),
+                                         "d".to_string(),
+                                         "e".to_string()],
+                                  array![1.1, 2.5, 3.2, 1.6, 0.8]];
+    let df = DataFrame::from_vec(values, vec![10, 20, 30, 40, 50], vec!["A", "B", "C"]);
+
+    assert_eq!(df.median(), Series::new(vec![3., 1.6], vec!["A", "C"]));
+    assert_eq!(df.quantile(0.25, QuantileInterpolation::Higher),
+               Series::new(vec![2., 1.1], vec!["A", "C"]));
+
+    let res = df.quantiles(&[0.5, 1.], QuantileInterpolation::Linear);
+    let exp = DataFrame::from_vec(vec![array![3., 8.], array![1.6, 3.2]],
+                                  vec!["50%".to_string(), "100%".to_string()],
+                                  vec!["A", "C"]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_frame_prod_overflow() {
+    let df = DataFrame::from_vec(vec![array![16u8, 16], array![16i64, 16]],
+                                 vec![1, 2],
+                                 vec!["A", "B"]);
+    assert_eq!(df.prod(), Series::new(vec![Scalar::Null, Scalar::i64(256)], vec!["A", "B"]));
+}
+
+#[test]
+fn test_frame_agg_keep_dtype() {
+    let values: Vec<Array> = vec![array![1i64, 2, 3, 4, 5], array![6., 7., 8., 9., 10.]];
+    let df = DataFrame::from_vec(values, vec![10, 20, 30, 40, 50], vec!["X", "Y"]);
+
+    let exp: Series<Scalar, &str> = Series::new(vec![Scalar::i64(15), Scalar::f64(40.)],
+                                                vec!["X", "Y"]);
+    assert_eq!(df.sum(), exp);
+
+    let exp: Series<Scalar, &str> = Series::new(vec![Scalar::i64(1), Scalar::f64(6.)],
+                                                vec!["X", "Y"]);
+    assert_eq!(df.min(), exp);
+
+    let exp: Series<Scalar, &str> = Series::new(vec![Scalar::i64(5), Scalar::f64(10.)],
+                                                vec!["X", "Y"]);
+    assert_eq!(df.max(), exp);
+
+    // null if integer sum overflows
+    let df = DataFrame::from_vec(vec![array![200u8, 100]], vec![1, 2], vec!["X"]);
+    assert_eq!(df.sum(), Series::new(vec![Scalar::Null], vec!["X"]));
+}
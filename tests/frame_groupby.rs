@@ -21,3 +21,188 @@ fn test_frame_get_group() {
     assert_eq!(df1.index, exp.index);
     assert_eq!(df1.columns, exp.columns);
 }
+
+#[test]
+fn test_frame_groupby_agg() {
+    let values = vec![array![1, 2, 3, 4, 5],
+                      array![6., 7., 8., 9., 10.],
+                      array!["a".to_string(), "b".to_string(), "c".to_string(), "d".to_string(),
+                             "e".to_string()]];
+    let df = DataFrame::from_vec(values,
+                                 vec!["A", "BB", "CC", "D", "EEE"],
+                                 vec!["X", "YYY", "ZZ"]);
+    let dg = df.groupby(vec![1, 2, 1, 1, 2]);
+
+    let exp = DataFrame::from_vec(vec![array![8, 7], array![23., 17.]],
+                                  vec![1, 2],
+                                  vec!["X", "YYY"]);
+    assert_eq!(dg.sum(), exp);
+
+    let exp = DataFrame::from_vec(vec![array![3usize, 2], array![3usize, 2]],
+                                  vec![1, 2],
+                                  vec!["X", "YYY"]);
+    assert_eq!(dg.count(), exp);
+
+    let exp = DataFrame::from_vec(vec![array![8. / 3., 3.5], array![23. / 3., 8.5]],
+                                  vec![1, 2],
+                                  vec!["X", "YYY"]);
+    assert_eq!(dg.mean(), exp);
+
+    let exp = DataFrame::from_vec(vec![array![1, 2], array![6., 7.]],
+                                  vec![1, 2],
+                                  vec!["X", "YYY"]);
+    assert_eq!(dg.min(), exp);
+
+    let exp = DataFrame::from_vec(vec![array![4, 5], array![9., 10.]],
+                                  vec![1, 2],
+                                  vec!["X", "YYY"]);
+    assert_eq!(dg.max(), exp);
+}
+
+#[test]
+fn test_frame_groupby_first_last() {
+    let values = vec![array![1, 2, 3, 4, 5],
+                      array!["a".to_string(), "b".to_string(), "c".to_string(), "d".to_string(),
+                             "e".to_string()]];
+    let df = DataFrame::from_vec(values, vec![10, 20, 30, 40, 50], vec!["X", "Y"]);
+    let dg = df.groupby(vec![1, 2, 1, 1, 2]);
+
+    let exp = DataFrame::from_vec(vec![array![1, 2], array!["a".to_string(), "b".to_string()]],
+                                  vec![1, 2],
+                                  vec!["X", "Y"]);
+    assert_eq!(dg.first(), exp);
+
+    let exp = DataFrame::from_vec(vec![array![4, 5], array!["d".to_string(), "e".to_string()]],
+                                  vec![1, 2],
+                                  vec!["X", "Y"]);
+    assert_eq!(dg.last(), exp);
+}
+
+#[test]
+fn test_frame_groupby_with_null() {
+    let values = vec![Array::Int64Array(NullVec::with_mask(vec![0, 2, 3, 4, 0],
+                                                           Some(vec![true, false, false, false,
+                                                                     true]))),
+                      Array::Float64Array(NullVec::with_mask(vec![0., 7., 0., 9., 10.],
+                                                             Some(vec![true, false, true, false,
+                                                                       false])))];
+    let df = DataFrame::from_vec(values, vec![10, 20, 30, 40, 50], vec!["X", "Y"]);
+    let dg = df.groupby(vec![1, 1, 2, 2, 3]);
+
+    // nulls are skipped, null if all values are null
+    let exp = DataFrame::from_vec(vec![Array::Int64Array(NullVec::with_mask(vec![2, 3, 0],
+                                                                            Some(vec![false,
+                                                                                      false,
+                                                                                      true]))),
+                                       array![7., 9., 10.]],
+                                  vec![1, 2, 3],
+                                  vec!["X", "Y"]);
+    assert_eq!(dg.first(), exp);
+
+    let exp = DataFrame::from_vec(vec![Array::Int64Array(NullVec::with_mask(vec![2, 4, 0],
+                                                                            Some(vec![false,
+                                                                                      false,
+                                                                                      true]))),
+                                       array![7., 9., 10.]],
+                                  vec![1, 2, 3],
+                                  vec!["X", "Y"]);
+    assert_eq!(dg.last(), exp);
+    assert_eq!(dg.max(), exp);
+
+    // the first group is all null
+    let values = vec![Array::Int64Array(NullVec::with_mask(vec![0, 0, 3],
+                                                           Some(vec![true, true, false])))];
+    let df = DataFrame::from_vec(values, vec![10, 20, 30], vec!["X"]);
+    let dg = df.groupby(vec![1, 1, 2]);
+    let exp = DataFrame::from_vec(vec![Array::Int64Array(NullVec::with_mask(vec![0, 3],
+                                                                            Some(vec![true,
+                                                                                      false])))],
+                                  vec![1, 2],
+                                  vec!["X"]);
+    assert_eq!(dg.first(), exp);
+    assert_eq!(dg.min(), exp);
+}
+
+#[test]
+fn test_frame_groupby_empty() {
+    let values = vec![Array::from(Vec::<i64>::new()), Array::from(Vec::<f64>::new())];
+    let df: DataFrame<i64, &str> = DataFrame::from_vec(values, vec![], vec!["X", "Y"]);
+    let dg = df.groupby(Vec::<i64>::new());
+    let res = dg.first();
+    assert_eq!(res.len(), 0);
+    assert_eq!(res.dtypes(), vec!["i64".to_string(), "f64".to_string()]);
+    assert_eq!(dg.sum().len(), 0);
+    assert_eq!(dg.mean().dtypes(), vec!["f64".to_string(), "f64".to_string()]);
+}
+
+#[test]
+fn test_frame_groupby_quantile() {
+    let values = vec![array![1, 2, 3, 4, 5],
+                      array![6., 7., 8., 9., 10.],
+                      array!["a".to_string(), "b".to_string(), "c".to_string(), "d".to_string(),
+                             "e".to_string()]];
+    let df = DataFrame::from_vec(values,
+                                 vec!["A", "BB", "CC", "D", "EEE"],
+                                 vec!["X", "YYY", "ZZ"]);
+    let dg = df.groupby(vec![1, 2, 1, 1, 2]);
+
+    let exp = DataFrame::from_vec(vec![array![3., 3.5], array![8., 8.5]],
+                                  vec![1, 2],
+                                  vec!["X", "YYY"]);
+    assert_eq!(dg.median(), exp);
+
+    let exp = DataFrame::from_vec(vec![array![4., 5.], array![9., 10.]],
+                                  vec![1, 2],
+                                  vec!["X", "YYY"]);
+    assert_eq!(dg.quantile(0.75, QuantileInterpolation::Higher), exp);
+}
+
+#[test]
+fn test_frame_groupby_describe() {
+    let values = vec![array![1, 2, 3, 4, 5],
+                      array![6., 7., 8., 9., 10.],
+                      array!["a".to_string(), "b".to_string(), "c".to_string(), "d".to_string(),
+                             "e".to_string()]];
+    let df = DataFrame::from_vec(values,
+                                 vec!["A", "BB", "CC", "D", "EEE"],
+                                 vec!["X", "YYY", "ZZ"]);
+    let dg = df.groupby(vec![1, 2, 1, 1, 2]);
+
+    let res = dg.describe();
+    assert_eq!(res.index.values, vec![1, 2]);
+    assert_eq!(res.columns.len(), 16);
+    assert_eq!(res.columns.values[0], ("X", "count".to_string()));
+    assert_eq!(res.columns.values[8], ("YYY", "count".to_string()));
+
+    let res = dg.describe_percentiles(&[]);
+    let labels = vec!["count", "mean", "std", "min", "50%", "max"];
+    let exp_columns: Vec<(&str, String)> = vec!["X", "YYY"]
+        .iter()
+        .flat_map(|c| labels.iter().map(move |l| (*c, l.to_string())))
+        .collect();
+    assert_eq!(res.columns.values, exp_columns);
+    assert_eq!(res.get(&("X", "count".to_string())), Array::new(vec![3., 2.]));
+    assert_eq!(res.get(&("X", "50%".to_string())), Array::new(vec![3., 3.5]));
+    assert_eq!(res.get(&("YYY", "min".to_string())), Array::new(vec![6., 7.]));
+    assert_eq!(res.get(&("YYY", "max".to_string())), Array::new(vec![9., 10.]));
+}
+
+#[test]
+fn test_frame_groupby_higher_order() {
+    let values = vec![array![1, 2, 3, 4, 5],
+                      array![6., 7., 8., 9., 10.],
+                      array!["a".to_string(), "b".to_string(), "c".to_string(), "d".to_string(),
+                             "e".to_string()]];
+    let df = DataFrame::from_vec(values,
+                                 vec!["A", "BB", "CC", "D", "EEE"],
+                                 vec!["X", "YYY", "ZZ"]);
+    let dg = df.groupby(vec![1, 2, 1, 1, 2]);
+
+    let exp = DataFrame::from_vec(vec![array![12, 10], array![432., 70.]],
+                                  vec![1, 2],
+                                  vec!["X", "YYY"]);
+    assert_eq!(dg.prod(), exp);
+
+    let res = dg.unbiased_sem();
+    assert_eq!(res.get(&"YYY"), res.get(&"X"));
+}
@@ -0,0 +1,64 @@
+#[macro_use]
+extern crate brassfibre;
+use brassfibre::prelude::*;
+
+use std::f64;
+
+#[test]
+fn test_series_interpolate() {
+    let s = Series::new(vec![f64::NAN, 1., f64::NAN, f64::NAN, 4., f64::NAN],
+                        vec![1, 2, 3, 4, 5, 6]);
+    let res = s.interpolate(Interpolation::Linear, None, LimitDirection::Both);
+    assert_eq!(res, Series::new(vec![1., 1., 2., 3., 4., 4.], vec![1, 2, 3, 4, 5, 6]));
+
+    let res = s.interpolate(Interpolation::Nearest, None, LimitDirection::Backward);
+    assert!(res.values[5].is_nan());
+    assert_eq!(res.values[0..5], [1., 1., 1., 4., 4.]);
+
+    let res = s.interpolate(Interpolation::Linear, Some(1), LimitDirection::Forward);
+    assert!(res.values[0].is_nan());
+    assert_eq!(res.values[1..3], [1., 2.]);
+    assert!(res.values[3].is_nan());
+    assert_eq!(res.values[4..6], [4., 4.]);
+}
+
+#[test]
+fn test_series_interpolate_datetime_index() {
+    let index = vec![Datetime::from_ymd(2017, 1, 1),
+                     Datetime::from_ymd(2017, 1, 2),
+                     Datetime::from_ymd(2017, 1, 5)];
+    let s = Series::new(vec![1., f64::NAN, 5.], index.clone());
+    let res = s.interpolate(Interpolation::Nearest, None, LimitDirection::Forward);
+    assert_eq!(res, Series::new(vec![1., 1., 5.], index.clone()));
+    let res = s.interpolate_index(None, LimitDirection::Forward);
+    assert_eq!(res, Series::new(vec![1., 2., 5.], index));
+}
+
+#[test]
+fn test_frame_interpolate() {
+    let values = vec![array![1., f64::NAN, 3.],
+                      Array::Int64Array(NullVec::with_mask(vec![10, 0, 20],
+                                                           Some(vec![false, true, false]))),
+                      array!["a".to_string(), "b".to_string(), "c".to_string()],
+                      array![1, 2, 3]];
+    let df = DataFrame::from_vec(values, vec![0, 1, 4], vec!["X", "Y", "Z", "W"]);
+
+    // columns without nulls keep their dtype
+    let res = df.interpolate(Interpolation::Linear, None, LimitDirection::Forward);
+    let exp = DataFrame::from_vec(vec![array![1., 2., 3.],
+                                       array![10., 15., 20.],
+                                       array!["a".to_string(), "b".to_string(), "c".to_string()],
+                                       array![1, 2, 3]],
+                                  vec![0, 1, 4],
+                                  vec!["X", "Y", "Z", "W"]);
+    assert_eq!(res, exp);
+
+    let res = df.interpolate_index(None, LimitDirection::Forward);
+    let exp = DataFrame::from_vec(vec![array![1., 1.5, 3.],
+                                       array![10., 12.5, 20.],
+                                       array!["a".to_string(), "b".to_string(), "c".to_string()],
+                                       array![1, 2, 3]],
+                                  vec![0, 1, 4],
+                                  vec!["X", "Y", "Z", "W"]);
+    assert_eq!(res, exp);
+}
@@ -0,0 +1,88 @@
+#![cfg(feature = "arrow")]
+
+#[macro_use]
+extern crate brassfibre;
+use brassfibre::prelude::*;
+
+use std::io::Cursor;
+
+mod common;
+use common::sample;
+
+#[test]
+fn test_record_batch_round_trip() {
+    let df = sample();
+    let batch = df.to_record_batch(Some("idx")).unwrap();
+    assert_eq!(batch.num_rows(), 3);
+    assert_eq!(batch.num_columns(), 15);
+    assert_eq!(batch.schema().field(0).name(), "idx");
+    assert_eq!(batch.column(1).null_count(), 1);
+
+    let res = DataFrame::<i64, String>::from_record_batch(&batch, Some("idx")).unwrap();
+    assert_eq!(res, df);
+    assert_eq!(res.dtypes(), df.dtypes());
+}
+
+#[test]
+fn test_record_batch_without_index() {
+    let df = DataFrame::from_vec(vec![array![1i64, 2, 3], array!["a", "b", "c"]],
+                                 vec!["x", "y", "z"],
+                                 vec!["A", "B"]);
+    let batch = df.to_record_batch(None).unwrap();
+    assert_eq!(batch.num_columns(), 2);
+
+    let res = DataFrame::<usize, String>::from_record_batch(&batch, None).unwrap();
+    let exp = DataFrame::from_vec(vec![array![1i64, 2, 3], array!["a", "b", "c"]],
+                                  vec![0usize, 1, 2],
+                                  vec!["A".to_string(), "B".to_string()]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_record_batch_nulls() {
+    let values = vec![Array::UsizeArray(NullVec::with_mask(vec![1, 2, 0],
+                                                           Some(vec![false, false, true]))),
+                      Array::BoolArray(NullVec::with_mask(vec![true, false, false],
+                                                          Some(vec![false, false, true])))];
+    let df = DataFrame::from_vec(values,
+                                 vec![10i64, 20, 30],
+                                 vec!["A".to_string(), "B".to_string()]);
+    let batch = df.to_record_batch(Some("idx")).unwrap();
+    assert_eq!(batch.column(1).null_count(), 1);
+    assert_eq!(batch.column(2).null_count(), 1);
+
+    let res = DataFrame::<i64, String>::from_record_batch(&batch, Some("idx")).unwrap();
+    assert_eq!(res, df);
+    assert_eq!(res.dtypes(), df.dtypes());
+}
+
+#[test]
+fn test_arrow_ipc_round_trip() {
+    let df = sample();
+
+    let mut buf: Vec<u8> = vec![];
+    df.write_arrow_file(&mut buf, Some("idx")).unwrap();
+    let res = DataFrame::<i64, String>::read_arrow_file(Cursor::new(&buf), Some("idx")).unwrap();
+    assert_eq!(res, df);
+
+    let mut buf: Vec<u8> = vec![];
+    df.write_arrow_stream(&mut buf, Some("idx")).unwrap();
+    let res = DataFrame::<i64, String>::read_arrow_stream(Cursor::new(&buf), Some("idx"))
+        .unwrap();
+    assert_eq!(res, df);
+}
+
+#[test]
+fn test_arrow_index_errors() {
+    let df = sample();
+    let batch = df.to_record_batch(Some("idx")).unwrap();
+
+    let err = DataFrame::<i64, String>::from_record_batch(&batch, Some("X")).unwrap_err();
+    assert!(err.to_string().contains("index column 'X' not found"));
+
+    let err = DataFrame::<String, String>::from_record_batch(&batch, Some("idx")).unwrap_err();
+    assert!(err.to_string().contains("index column 'idx' has dtype i64, expected str"));
+
+    let err = DataFrame::<i64, String>::from_record_batch(&batch, Some("c0")).unwrap_err();
+    assert!(err.to_string().contains("index column 'c0' contains null"));
+}
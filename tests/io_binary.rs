@@ -0,0 +1,126 @@
+#[macro_use]
+extern crate brassfibre;
+use brassfibre::prelude::*;
+
+use std::io::Cursor;
+
+mod common;
+use common::sample;
+
+#[test]
+fn test_binary_round_trip() {
+    let df = sample();
+    let mut buf: Vec<u8> = vec![];
+    df.write_binary(&mut buf).unwrap();
+    assert_eq!(&buf[..4], b"BFDF");
+
+    let res = DataFrame::<i64, String>::read_binary(Cursor::new(&buf)).unwrap();
+    assert_eq!(res, df);
+    assert_eq!(res.dtypes(), df.dtypes());
+
+    // empty
+    let df: DataFrame<usize, String> = DataFrame::from_vec(vec![], vec![], vec![]);
+    let mut buf: Vec<u8> = vec![];
+    df.write_binary(&mut buf).unwrap();
+    let res = DataFrame::<usize, String>::read_binary(Cursor::new(&buf)).unwrap();
+    assert_eq!(res, df);
+}
+
+#[test]
+fn test_binary_read_columns() {
+    let df = sample();
+    let mut buf: Vec<u8> = vec![];
+    df.write_binary(&mut buf).unwrap();
+
+    let res = DataFrame::<i64, String>::read_binary_columns(Cursor::new(&buf), &["c13", "c0"])
+        .unwrap();
+    let exp = DataFrame::from_vec(vec![df.values[0].clone().into_owned(),
+                                       df.values[13].clone().into_owned()],
+                                  vec![10, 20, 30],
+                                  vec!["c0".to_string(), "c13".to_string()]);
+    assert_eq!(res, exp);
+
+    let err = DataFrame::<i64, String>::read_binary_columns(Cursor::new(&buf), &["X"])
+        .unwrap_err();
+    assert_eq!(err.to_string(), "column 'X' not found");
+}
+
+#[test]
+fn test_binary_errors() {
+    let df = sample();
+    let mut buf: Vec<u8> = vec![];
+    df.write_binary(&mut buf).unwrap();
+
+    let mut invalid = buf.clone();
+    invalid[4] = 99;
+    let err = DataFrame::<i64, String>::read_binary(Cursor::new(&invalid)).unwrap_err();
+    assert_eq!(err.to_string(), "unsupported binary format version 99");
+
+    let err = DataFrame::<i64, String>::read_binary(Cursor::new(b"a,b\n1,2\n")).unwrap_err();
+    assert_eq!(err.to_string(), "not a brassfibre binary file");
+
+    // truncated
+    let truncated = &buf[..buf.len() - 1];
+    assert!(DataFrame::<i64, String>::read_binary(Cursor::new(truncated)).is_err());
+
+    // corrupted sizes are rejected before allocating
+    let df = DataFrame::from_vec(vec![array![1i64]], vec![1i64], vec!["A".to_string()]);
+    let mut buf: Vec<u8> = vec![];
+    df.write_binary(&mut buf).unwrap();
+
+    let mut invalid = buf.clone();
+    invalid[8..16].copy_from_slice(&u64::max_value().to_le_bytes());
+    let err = DataFrame::<i64, String>::read_binary(Cursor::new(&invalid)).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    assert_eq!(err.to_string(), "number of rows 18446744073709551615 exceeds the input");
+
+    let mut invalid = buf.clone();
+    invalid[16..24].copy_from_slice(&(1u64 << 40).to_le_bytes());
+    let err = DataFrame::<i64, String>::read_binary(Cursor::new(&invalid)).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+    let mut invalid = buf.clone();
+    invalid[25..33].copy_from_slice(&(1u64 << 40).to_le_bytes());
+    let err = DataFrame::<i64, String>::read_binary(Cursor::new(&invalid)).unwrap_err();
+    assert_eq!(err.to_string(), "number of columns 1099511627776 exceeds the input");
+
+    let mut invalid = buf.clone();
+    invalid[51..59].copy_from_slice(&u64::max_value().to_le_bytes());
+    let err = DataFrame::<i64, String>::read_binary(Cursor::new(&invalid)).unwrap_err();
+    assert_eq!(err.to_string(), "block of column 'A' exceeds the input");
+
+    // index can't be parsed
+    let df = DataFrame::from_vec(vec![array![1i64]], vec!["a".to_string()], vec!["A".to_string()]);
+    let mut buf: Vec<u8> = vec![];
+    df.write_binary(&mut buf).unwrap();
+    let err = DataFrame::<i64, String>::read_binary(Cursor::new(&buf)).unwrap_err();
+    assert_eq!(err.to_string(), "unable to parse 'a' as index");
+}
+
+#[cfg(feature = "mmap")]
+#[test]
+fn test_binary_mmap() {
+    use std::fs;
+
+    let df = sample();
+    let file = fs::File::create("./data_mmap.bin").unwrap();
+    df.write_binary(file).unwrap();
+
+    let res = DataFrame::<i64, String>::read_binary_mmap("./data_mmap.bin").unwrap();
+    assert_eq!(res, df);
+
+    let res = DataFrame::<i64, String>::read_binary_mmap_columns("./data_mmap.bin", &["c1"])
+        .unwrap();
+    assert_eq!(res.columns.values, vec!["c1".to_string()]);
+    assert_eq!(res.values[0], df.values[1]);
+
+    // block beyond the end of the file
+    let mut buf: Vec<u8> = vec![];
+    df.write_binary(&mut buf).unwrap();
+    let len = buf.len();
+    fs::write("./data_mmap.bin", &buf[..len - 1]).unwrap();
+    let err = DataFrame::<i64, String>::read_binary_mmap("./data_mmap.bin").unwrap_err();
+    assert_eq!(err.to_string(), "block of column 'c13' exceeds the input");
+
+    fs::remove_file("./data_mmap.bin").unwrap();
+}
@@ -136,3 +136,380 @@ fn test_file_io() {
     use std::fs;
     fs::remove_file("./data.csv").unwrap();
 }
+
+#[test]
+fn test_read_csv_with_index_col_usecols() {
+    let data = "key,A,B,C
+x,1,2.5,p
+y,3,,q
+z,5,4.5,r";
+
+    let rdr = csv::Reader::from_string(data).has_headers(true);
+    let options = CsvReadOptions::new().index_col("key").usecols(&["key", "B", "A"]);
+    let res = DataFrame::<String, String>::read_csv_with(rdr, &options).unwrap();
+
+    // empty field is null
+    let exp = DataFrame::from_vec(vec![array![1i64, 3, 5],
+                                       Array::Float64Array(NullVec::with_mask(vec![2.5, 0., 4.5],
+                                                                              Some(vec![false,
+                                                                                        true,
+                                                                                        false])))],
+                                  vec!["x".to_string(), "y".to_string(), "z".to_string()],
+                                  vec!["A".to_string(), "B".to_string()]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_read_csv_with_na_and_bool_values() {
+    let data = "A,B,C
+1,yes,x
+-,no,NA
+3,yes,y";
+
+    let rdr = csv::Reader::from_string(data).has_headers(true);
+    let options = CsvReadOptions::new()
+        .na_values(&["-", "NA"])
+        .true_values(&["yes"])
+        .false_values(&["no"]);
+    let res = DataFrame::<usize, String>::read_csv_with(rdr, &options).unwrap();
+
+    let exp_values = vec![Array::Int64Array(NullVec::with_mask(vec![1, 0, 3],
+                                                               Some(vec![false, true, false]))),
+                          array![true, false, true],
+                          Array::StringArray(NullVec::with_mask(vec!["x".to_string(),
+                                                                     "".to_string(),
+                                                                     "y".to_string()],
+                                                                Some(vec![false, true, false])))];
+    let exp = DataFrame::from_vec(exp_values,
+                                  vec![0, 1, 2],
+                                  vec!["A".to_string(), "B".to_string(), "C".to_string()]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_read_csv_with_rows_and_comment() {
+    let data = "A;B
+# comment line
+1;1.000,5
+2;2.000,5 # trailing comment
+3;3.000,5
+4;4.000,5";
+
+    let rdr = csv::Reader::from_reader(CommentFilter::new(data.as_bytes(), '#'))
+        .has_headers(true)
+        .delimiter(b';')
+        .flexible(true);
+    let options = CsvReadOptions::new()
+        .skiprows(1)
+        .nrows(2)
+        .thousands('.')
+        .decimal(',')
+        .dtype("A", "u8");
+    let res = DataFrame::<usize, String>::read_csv_with(rdr, &options).unwrap();
+
+    let exp = DataFrame::from_vec(vec![array![2u8, 3], array![2000.5, 3000.5]],
+                                  vec![0, 1],
+                                  vec!["A".to_string(), "B".to_string()]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_read_csv_with_quoted_comment() {
+    let data = "A,B
+\"a#b\",1 # comment
+# \"quoted\"
+\"c
+#d\",2";
+
+    let rdr = csv::Reader::from_reader(CommentFilter::new(data.as_bytes(), '#'))
+        .has_headers(true);
+    let res = DataFrame::<usize, String>::read_csv(rdr).unwrap();
+    let exp = DataFrame::from_vec(vec![array!["a#b".to_string(), "c\n#d".to_string()],
+                                       array![1i64, 2]],
+                                  vec![0, 1],
+                                  vec!["A".to_string(), "B".to_string()]);
+    assert_eq!(res, exp);
+
+    // built csv::Reader no longer knows quotes
+    let rdr = csv::Reader::from_string(data).has_headers(true);
+    let options = CsvReadOptions::new().comment('#');
+    let err = DataFrame::<usize, String>::read_csv_with(rdr, &options).unwrap_err();
+    assert!(err.to_string().contains("CommentFilter"));
+}
+
+#[test]
+fn test_read_csv_from_comment() {
+    let data = "A,B
+\"a#b\",1 # comment
+# \"quoted\"
+\"c
+#d\",2";
+
+    let options = CsvReadOptions::new().comment('#');
+    let res = DataFrame::<usize, String>::read_csv_from(data.as_bytes(), &options).unwrap();
+    let exp = DataFrame::from_vec(vec![array!["a#b".to_string(), "c\n#d".to_string()],
+                                       array![1i64, 2]],
+                                  vec![0, 1],
+                                  vec!["A".to_string(), "B".to_string()]);
+    assert_eq!(res, exp);
+
+    // without comment, the input is parsed as is
+    let data = "A,B\n1,x#y\n2,z";
+    let res = DataFrame::<usize, String>::read_csv_from(data.as_bytes(), &CsvReadOptions::new())
+        .unwrap();
+    let exp = DataFrame::from_vec(vec![array![1i64, 2],
+                                       array!["x#y".to_string(), "z".to_string()]],
+                                  vec![0, 1],
+                                  vec!["A".to_string(), "B".to_string()]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_read_csv_with_parse_dates() {
+    let data = "date,when,value
+2017-01-01,2017-01-01 12:00:00,1
+2017-01-02,2017-01-02T00:00:00Z,2";
+
+    let rdr = csv::Reader::from_string(data).has_headers(true);
+    let options = CsvReadOptions::new().index_col("date").parse_dates(&["when"]);
+    let res = DataFrame::<Datetime, String>::read_csv_with(rdr, &options).unwrap();
+
+    let when = vec![Datetime::from_ymd_hms(2017, 1, 1, 12, 0, 0).nanos,
+                    Datetime::from_ymd(2017, 1, 2).nanos];
+    let exp = DataFrame::from_vec(vec![Array::new(when), array![1i64, 2]],
+                                  vec![Datetime::from_ymd(2017, 1, 1),
+                                       Datetime::from_ymd(2017, 1, 2)],
+                                  vec!["when".to_string(), "value".to_string()]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_read_csv_with_errors() {
+    let data = "A,B
+1,x
+2,y";
+
+    let rdr = csv::Reader::from_string(data).has_headers(true);
+    let options = CsvReadOptions::new().usecols(&["C"]);
+    let err = DataFrame::<usize, String>::read_csv_with(rdr, &options).unwrap_err();
+    assert_eq!(err.to_string(), "CSV decode error: column 'C' not found");
+
+    let rdr = csv::Reader::from_string(data).has_headers(true);
+    let options = CsvReadOptions::new().dtype("B", "i64");
+    let err = DataFrame::<usize, String>::read_csv_with(rdr, &options).unwrap_err();
+    assert_eq!(err.to_string(),
+               "CSV decode error: unable to parse 'x' in column 'B' as i64");
+
+    let rdr = csv::Reader::from_string(data).has_headers(true);
+    let options = CsvReadOptions::new().index_col("B");
+    let err = DataFrame::<usize, String>::read_csv_with(rdr, &options).unwrap_err();
+    assert_eq!(err.to_string(),
+               "CSV decode error: unable to parse 'x' in column 'B' as index");
+}
+
+#[test]
+fn test_read_csv_chunked() {
+    let data = "A,B,C
+1,x,1.5
+2,y,2.5
+3,z,3.5
+4,w,4.5
+5,v,5.5";
+
+    let rdr = csv::Reader::from_string(data).has_headers(true);
+    let chunks = DataFrame::<usize, String>::read_csv_chunked(rdr, 2).unwrap();
+    let res: Vec<DataFrame<usize, String>> = chunks.map(|x| x.unwrap()).collect();
+    assert_eq!(res.len(), 3);
+
+    let columns = vec!["A".to_string(), "B".to_string(), "C".to_string()];
+    let exp = DataFrame::from_vec(vec![array![1i64, 2],
+                                       array!["x".to_string(), "y".to_string()],
+                                       array![1.5, 2.5]],
+                                  vec![0, 1],
+                                  columns.clone());
+    assert_eq!(res[0], exp);
+    let exp = DataFrame::from_vec(vec![array![5i64],
+                                       array!["v".to_string()],
+                                       array![5.5]],
+                                  vec![4],
+                                  columns.clone());
+    assert_eq!(res[2], exp);
+
+    // no headers
+    let rdr = csv::Reader::from_string(data).has_headers(false);
+    let chunks = DataFrame::<usize, String>::read_csv_chunked(rdr, 4).unwrap();
+    let res: Vec<DataFrame<usize, String>> = chunks.map(|x| x.unwrap()).collect();
+    assert_eq!(res.len(), 2);
+    assert_eq!(res[0].dtypes(),
+               vec!["str".to_string(), "str".to_string(), "str".to_string()]);
+    assert_eq!(res[1].len(), 2);
+}
+
+#[test]
+fn test_read_csv_chunked_with() {
+    let data = "A,B
+1,x
+2,y
+#3,z
+4,w
+5,v";
+
+    let rdr = csv::Reader::from_reader(CommentFilter::new(data.as_bytes(), '#'))
+        .has_headers(true);
+    let options = CsvReadOptions::new().index_col("B").nrows(3);
+    let chunks = DataFrame::<String, String>::read_csv_chunked_with(rdr, 2, &options).unwrap();
+    let res: Vec<DataFrame<String, String>> = chunks.map(|x| x.unwrap()).collect();
+    assert_eq!(res.len(), 2);
+
+    let exp = DataFrame::from_vec(vec![array![4i64]],
+                                  vec!["w".to_string()],
+                                  vec!["A".to_string()]);
+    assert_eq!(res[1], exp);
+}
+
+#[test]
+fn test_read_csv_chunked_schema_mismatch() {
+    let data = "A,B
+1,x
+2,y
+3.5,z";
+
+    let rdr = csv::Reader::from_string(data).has_headers(true);
+    let mut chunks = DataFrame::<usize, String>::read_csv_chunked(rdr, 2).unwrap();
+    assert_eq!(chunks.next().unwrap().unwrap().dtypes(),
+               vec!["i64".to_string(), "str".to_string()]);
+    let err = chunks.next().unwrap().unwrap_err();
+    assert_eq!(err.to_string(),
+               "CSV decode error: unable to parse '3.5' in column 'A' as i64");
+    assert!(chunks.next().is_none());
+}
+
+#[test]
+fn test_csv_schema() {
+    let data = "A,B,C,D,E
+1,1.5,x,true,
+2,,y,false,
+3,3,3,true,";
+
+    let rdr = csv::Reader::from_string(data).has_headers(true);
+    let schema = Schema::from_csv(rdr, &CsvReadOptions::new()).unwrap();
+    let exp = Schema {
+        columns: vec!["A".to_string(),
+                      "B".to_string(),
+                      "C".to_string(),
+                      "D".to_string(),
+                      "E".to_string()],
+        dtypes: vec!["i64".to_string(),
+                     "f64".to_string(),
+                     "str".to_string(),
+                     "bool".to_string(),
+                     "f64".to_string()],
+        nullable: vec![false, true, false, false, true],
+    };
+    assert_eq!(schema, exp);
+    assert_eq!(schema.get_dtype("B"), Some("f64"));
+    assert_eq!(schema.get_dtype("X"), None);
+
+    // override inferred dtype
+    let err = schema.clone().dtype("X", "u8").unwrap_err();
+    assert!(err.to_string().contains("column 'X' not found"));
+    let schema = schema.dtype("A", "u8").unwrap();
+    let rdr = csv::Reader::from_string(data).has_headers(true);
+    let options = CsvReadOptions::new().schema(&schema).usecols(&["A", "E"]);
+    let res = DataFrame::<usize, String>::read_csv_with(rdr, &options).unwrap();
+    assert_eq!(res.dtypes(), vec!["u8".to_string(), "f64".to_string()]);
+
+    // columns read follow options
+    let rdr = csv::Reader::from_string(data).has_headers(true);
+    let options = CsvReadOptions::new().index_col("C").usecols(&["B", "C"]).dtype("B", "f32");
+    let schema = Schema::from_csv(rdr, &options).unwrap();
+    assert_eq!(schema, Schema::new(vec!["B".to_string()], vec!["f32".to_string()]));
+}
+
+#[test]
+fn test_csv_schema_mixed_types() {
+    let data = "A,B
+1,1
+2,true
+x,3";
+
+    let rdr = csv::Reader::from_string(data).has_headers(true);
+    let schema = Schema::from_csv(rdr, &CsvReadOptions::new()).unwrap();
+    assert_eq!(schema.dtypes, vec!["str".to_string(), "str".to_string()]);
+
+    // inferred from leading rows
+    let rdr = csv::Reader::from_string(data).has_headers(true);
+    let options = CsvReadOptions::new().infer_rows(1);
+    let schema = Schema::from_csv(rdr, &options).unwrap();
+    assert_eq!(schema.dtypes, vec!["i64".to_string(), "i64".to_string()]);
+
+    let rdr = csv::Reader::from_string(data).has_headers(true);
+    let err = DataFrame::<usize, String>::read_csv_with(rdr, &options).unwrap_err();
+    assert_eq!(err.to_string(),
+               "CSV decode error: unable to parse 'x' in column 'A' as i64");
+
+    // empty field can be kept as string
+    let data = "A,B
+x,1
+,2";
+    let rdr = csv::Reader::from_string(data).has_headers(true);
+    let options = CsvReadOptions::new().empty_as_null(false).usecols(&["A"]);
+    let res = DataFrame::<usize, String>::read_csv_with(rdr, &options).unwrap();
+    let exp = DataFrame::from_vec(vec![array!["x".to_string(), "".to_string()]],
+                                  vec![0, 1],
+                                  vec!["A".to_string()]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_write_csv_with_index() {
+    let values = vec![array!["x".to_string(), "y,z".to_string(), "w".to_string()],
+                      Array::Float64Array(NullVec::with_mask(vec![1., 0., 3.],
+                                                             Some(vec![false, true, false]))),
+                      array![7i64, 3, 1]];
+    let df = DataFrame::from_vec(values,
+                                 vec!["a".to_string(), "b".to_string(), "c".to_string()],
+                                 vec!["A".to_string(), "B".to_string(), "C".to_string()]);
+
+    let mut buf: Vec<u8> = vec![];
+    let options = CsvWriteOptions::new().index_label("key");
+    df.write_csv_with(&mut buf, &options).unwrap();
+    let res = String::from_utf8(buf).unwrap();
+    assert_eq!(res, "key,A,B,C\na,x,1.0,7\nb,\"y,z\",,3\nc,w,3.0,1\n");
+
+    // test round-trip
+    let rdr = csv::Reader::from_string(res).has_headers(true);
+    let options = CsvReadOptions::new().index_col("key");
+    let res = DataFrame::<String, String>::read_csv_with(rdr, &options).unwrap();
+    assert_eq!(res, df);
+}
+
+#[test]
+fn test_write_csv_with_options() {
+    let values = vec![array![1.5, 2.25, 3.],
+                      Array::Int64Array(NullVec::with_mask(vec![1, 2, 3],
+                                                           Some(vec![false, true, false]))),
+                      array![true, false, true]];
+    let df = DataFrame::from_vec(values,
+                                 vec![0, 1, 2],
+                                 vec!["A".to_string(), "B".to_string(), "C".to_string()]);
+
+    let mut buf: Vec<u8> = vec![];
+    let options = CsvWriteOptions::new()
+        .index(false)
+        .header(false)
+        .na_rep("NA")
+        .float_format(2)
+        .columns(&["C", "B", "A"])
+        .delimiter(b';')
+        .quoting(csv::QuoteStyle::Always);
+    df.write_csv_with(&mut buf, &options).unwrap();
+    let res = String::from_utf8(buf).unwrap();
+    assert_eq!(res,
+               "\"true\";\"1\";\"1.50\"\n\"false\";\"NA\";\"2.25\"\n\"true\";\"3\";\"3.00\"\n");
+
+    let mut buf: Vec<u8> = vec![];
+    let options = CsvWriteOptions::new().columns(&["X"]);
+    let err = df.write_csv_with(&mut buf, &options).unwrap_err();
+    assert_eq!(err.to_string(), "CSV encode error: column 'X' not found");
+}
@@ -0,0 +1,156 @@
+#![cfg(feature = "json")]
+
+#[macro_use]
+extern crate brassfibre;
+use brassfibre::prelude::*;
+
+use std::f64;
+
+fn sample() -> DataFrame<'static, 'static, 'static, String, String> {
+    let values = vec![array![1i64, 2, 3],
+                      Array::Float64Array(NullVec::with_mask(vec![1.5, 0., 3.5],
+                                                             Some(vec![false, true, false]))),
+                      array!["x".to_string(), "y".to_string(), "z".to_string()],
+                      array![true, false, true]];
+    DataFrame::from_vec(values,
+                        vec!["a".to_string(), "b".to_string(), "c".to_string()],
+                        vec!["A".to_string(), "B".to_string(), "C".to_string(), "D".to_string()])
+}
+
+#[test]
+fn test_to_json() {
+    let df = sample();
+    assert_eq!(df.to_json(JsonOrient::Records).unwrap(),
+               r#"[{"A":1,"B":1.5,"C":"x","D":true},{"A":2,"B":null,"C":"y","D":false},{"A":3,"B":3.5,"C":"z","D":true}]"#);
+    assert_eq!(df.to_json(JsonOrient::Columns).unwrap(),
+               r#"{"A":{"a":1,"b":2,"c":3},"B":{"a":1.5,"b":null,"c":3.5},"C":{"a":"x","b":"y","c":"z"},"D":{"a":true,"b":false,"c":true}}"#);
+    assert_eq!(df.to_json(JsonOrient::Index).unwrap(),
+               r#"{"a":{"A":1,"B":1.5,"C":"x","D":true},"b":{"A":2,"B":null,"C":"y","D":false},"c":{"A":3,"B":3.5,"C":"z","D":true}}"#);
+    assert_eq!(df.to_json(JsonOrient::Split).unwrap(),
+               r#"{"columns":["A","B","C","D"],"index":["a","b","c"],"data":[[1,1.5,"x",true],[2,null,"y",false],[3,3.5,"z",true]]}"#);
+    assert_eq!(df.to_json(JsonOrient::Values).unwrap(),
+               r#"[[1,1.5,"x",true],[2,null,"y",false],[3,3.5,"z",true]]"#);
+}
+
+#[test]
+fn test_json_round_trip() {
+    let df = sample();
+    for orient in [JsonOrient::Columns, JsonOrient::Index, JsonOrient::Split].iter() {
+        let json = df.to_json(*orient).unwrap();
+        let res = DataFrame::<String, String>::read_json(json.as_bytes(), *orient).unwrap();
+        assert_eq!(res, df);
+    }
+
+    // records and values don't contain index
+    let df = DataFrame::from_vec(vec![array![1i64, 2], array![1.5, 2.5]],
+                                 vec![0usize, 1],
+                                 vec!["A".to_string(), "B".to_string()]);
+    let json = df.to_json(JsonOrient::Records).unwrap();
+    let res = DataFrame::<usize, String>::read_json(json.as_bytes(), JsonOrient::Records).unwrap();
+    assert_eq!(res, df);
+
+    let json = df.to_json(JsonOrient::Values).unwrap();
+    let res = DataFrame::<usize, String>::read_json(json.as_bytes(), JsonOrient::Values).unwrap();
+    assert_eq!(res.dtypes(), vec!["i64".to_string(), "f64".to_string()]);
+    assert_eq!(res.columns.values, vec!["0".to_string(), "1".to_string()]);
+
+    // numeric index is kept
+    let json = df.to_json(JsonOrient::Index).unwrap();
+    assert_eq!(json, r#"{"0":{"A":1,"B":1.5},"1":{"A":2,"B":2.5}}"#);
+    let res = DataFrame::<usize, String>::read_json(json.as_bytes(), JsonOrient::Index).unwrap();
+    assert_eq!(res, df);
+}
+
+#[test]
+fn test_json_lines() {
+    let df = DataFrame::from_vec(vec![array![1i64, 2], array!["x".to_string(), "y".to_string()]],
+                                 vec![0usize, 1],
+                                 vec!["A".to_string(), "B".to_string()]);
+    let lines = df.to_json_lines().unwrap();
+    assert_eq!(lines, "{\"A\":1,\"B\":\"x\"}\n{\"A\":2,\"B\":\"y\"}\n");
+
+    let res = DataFrame::<usize, String>::read_json_lines(lines.as_bytes()).unwrap();
+    assert_eq!(res, df);
+
+    // missing keys are null
+    let lines = "{\"A\":1}\n{\"B\":true}\n";
+    let res = DataFrame::<usize, String>::read_json_lines(lines.as_bytes()).unwrap();
+    let exp = DataFrame::from_vec(vec![Array::Int64Array(NullVec::with_mask(vec![1, 0],
+                                                                            Some(vec![false,
+                                                                                      true]))),
+                                       Array::BoolArray(NullVec::with_mask(vec![false, true],
+                                                                           Some(vec![true,
+                                                                                     false])))],
+                                  vec![0, 1],
+                                  vec!["A".to_string(), "B".to_string()]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_read_json_errors() {
+    let json = r#"[{"A":1},{"A":"x"}]"#;
+    let err = DataFrame::<usize, String>::read_json(json.as_bytes(), JsonOrient::Records)
+        .unwrap_err();
+    assert_eq!(err.to_string(), "JSON format error: column 'A' contains mixed types");
+
+    let json = r#"[{"A":[1]}]"#;
+    let err = DataFrame::<usize, String>::read_json(json.as_bytes(), JsonOrient::Records)
+        .unwrap_err();
+    assert_eq!(err.to_string(), "JSON format error: column 'A' contains nested value");
+
+    let json = r#"{"columns":["A"],"index":[0],"data":[[1,2]]}"#;
+    let err = DataFrame::<usize, String>::read_json(json.as_bytes(), JsonOrient::Split)
+        .unwrap_err();
+    assert_eq!(err.to_string(),
+               "JSON format error: expected 1 values in a row, found 2");
+
+    let json = r#"[1, 2"#;
+    let err = DataFrame::<usize, String>::read_json(json.as_bytes(), JsonOrient::Values)
+        .unwrap_err();
+    assert!(err.to_string().starts_with("JSON error: "));
+}
+
+#[test]
+fn test_series_json() {
+    let s = Series::<f64, String>::new(vec![1.5, 2., 3.],
+                                       vec!["a".to_string(), "b".to_string(), "c".to_string()]);
+    assert_eq!(s.to_json(JsonOrient::Index).unwrap(),
+               r#"{"a":1.5,"b":2.0,"c":3.0}"#);
+    assert_eq!(s.to_json(JsonOrient::Split).unwrap(),
+               r#"{"index":["a","b","c"],"data":[1.5,2.0,3.0]}"#);
+    assert_eq!(s.to_json(JsonOrient::Values).unwrap(), "[1.5,2.0,3.0]");
+
+    for orient in [JsonOrient::Index, JsonOrient::Split].iter() {
+        let json = s.to_json(*orient).unwrap();
+        let res = Series::<f64, String>::read_json(json.as_bytes(), *orient).unwrap();
+        assert_eq!(res, s);
+    }
+
+    let res = Series::<i64, usize>::read_json("[1, 2]".as_bytes(), JsonOrient::Values).unwrap();
+    assert_eq!(res, Series::<i64, usize>::new(vec![1, 2], vec![0, 1]));
+}
+
+#[test]
+fn test_series_json_nan() {
+    let s = Series::<f64, usize>::new(vec![1.5, f64::NAN], vec![0, 1]);
+    let json = s.to_json(JsonOrient::Split).unwrap();
+    assert_eq!(json, r#"{"index":[0,1],"data":[1.5,null]}"#);
+
+    // null is read back as NaN
+    let res = Series::<f64, usize>::read_json(json.as_bytes(), JsonOrient::Split).unwrap();
+    assert_eq!(res.values[0], 1.5);
+    assert!(res.values[1].is_nan());
+
+    let res = Series::<f32, usize>::read_json("[null]".as_bytes(), JsonOrient::Values).unwrap();
+    assert!(res.values[0].is_nan());
+
+    let err = Series::<i64, usize>::read_json("[1, null]".as_bytes(), JsonOrient::Values)
+        .unwrap_err();
+    assert!(err.to_string().starts_with("JSON error: "));
+
+    // null is read as None for optional values
+    let res = Series::<Option<usize>, usize>::read_json("[1, null]".as_bytes(),
+                                                        JsonOrient::Values)
+        .unwrap();
+    assert_eq!(res.values.to_vec(), vec![Some(1), None]);
+}
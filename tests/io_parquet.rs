@@ -0,0 +1,79 @@
+#![cfg(feature = "parquet")]
+
+#[macro_use]
+extern crate brassfibre;
+extern crate parquet;
+use brassfibre::prelude::*;
+
+use parquet::basic::{Compression, GzipLevel, ZstdLevel};
+use std::fs;
+
+mod common;
+use common::sample;
+
+#[test]
+fn test_parquet_round_trip() {
+    let df = sample();
+    let codecs = vec![Compression::UNCOMPRESSED,
+                      Compression::SNAPPY,
+                      Compression::GZIP(GzipLevel::default()),
+                      Compression::ZSTD(ZstdLevel::default()),
+                      Compression::LZ4_RAW];
+    for (i, codec) in codecs.into_iter().enumerate() {
+        let path = format!("./data_parquet_{}.parquet", i);
+        let options = ParquetWriteOptions::new().compression(codec).index("idx");
+        df.write_parquet(&path, &options).unwrap();
+
+        let res = DataFrame::<i64, String>::read_parquet(&path, None).unwrap();
+        assert_eq!(res, df);
+        assert_eq!(res.dtypes(), df.dtypes());
+        fs::remove_file(&path).unwrap();
+    }
+}
+
+#[test]
+fn test_parquet_columns() {
+    let df = sample();
+    let path = "./data_parquet_columns.parquet";
+    df.write_parquet(path, &ParquetWriteOptions::new().index("idx")).unwrap();
+
+    // columns are read in file order, with index
+    let res = DataFrame::<i64, String>::read_parquet(path, Some(&["c13", "c0"])).unwrap();
+    assert_eq!(res.columns.values, vec!["c0".to_string(), "c13".to_string()]);
+    assert_eq!(res.index.values, vec![10, 20, 30]);
+    assert_eq!(res.values[0], df.values[0]);
+    assert_eq!(res.values[1], df.values[13]);
+
+    let err = DataFrame::<i64, String>::read_parquet(path, Some(&["X"])).unwrap_err();
+    assert!(err.to_string().contains("column 'X' not found"));
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_parquet_row_groups() {
+    let df = DataFrame::from_vec(vec![array![1i64, 2, 3, 4, 5], array!["a", "b", "c", "d", "e"]],
+                                 vec![0usize, 1, 2, 3, 4],
+                                 vec!["A", "B"]);
+    let path = "./data_parquet_row_groups.parquet";
+    df.write_parquet(path, &ParquetWriteOptions::new().row_group_size(2)).unwrap();
+
+    let groups = DataFrame::<usize, String>::read_parquet_row_groups(path, Some(&["B"])).unwrap();
+    assert_eq!(groups.num_row_groups(), 3);
+    let res: Vec<DataFrame<usize, String>> = groups.map(|x| x.unwrap()).collect();
+    assert_eq!(res.len(), 3);
+    // row numbers continue across row groups
+    let exp = DataFrame::from_vec(vec![array!["e"]], vec![4usize], vec!["B".to_string()]);
+    assert_eq!(res[2], exp);
+    let exp = DataFrame::from_vec(vec![array!["c", "d"]],
+                                  vec![2usize, 3],
+                                  vec!["B".to_string()]);
+    assert_eq!(res[1], exp);
+
+    // without index, row numbers are used
+    let res = DataFrame::<usize, String>::read_parquet(path, None).unwrap();
+    let exp = DataFrame::from_vec(vec![array![1i64, 2, 3, 4, 5], array!["a", "b", "c", "d", "e"]],
+                                  vec![0usize, 1, 2, 3, 4],
+                                  vec!["A".to_string(), "B".to_string()]);
+    assert_eq!(res, exp);
+    fs::remove_file(path).unwrap();
+}
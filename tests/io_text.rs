@@ -0,0 +1,142 @@
+#[macro_use]
+extern crate brassfibre;
+use brassfibre::prelude::*;
+
+#[test]
+fn test_read_fwf() {
+    let data = "name  flag  n   val
+x     true  7   1.1
+
+y     false 13  2.2
+z     true      4.5
+";
+
+    let format = FwfFormat::widths(vec![6, 6, 4, 3]);
+    let res = DataFrame::<usize, String>::read_fwf(data.as_bytes(), &format).unwrap();
+
+    let exp_dtypes: Vec<String> =
+        vec!["str".to_string(), "bool".to_string(), "i64".to_string(), "f64".to_string()];
+    assert_eq!(res.dtypes(), exp_dtypes);
+
+    let exp_values = vec![array!["x".to_string(), "y".to_string(), "z".to_string()],
+                          array![true, false, true],
+                          Array::Int64Array(NullVec::with_mask(vec![7, 13, 0],
+                                                               Some(vec![false, false, true]))),
+                          array![1.1, 2.2, 4.5]];
+    let exp = DataFrame::from_vec(exp_values,
+                                  vec![0, 1, 2],
+                                  vec!["name".to_string(),
+                                       "flag".to_string(),
+                                       "n".to_string(),
+                                       "val".to_string()]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_read_fwf_with() {
+    let data = "a001  2.5
+b002  3.5
+c003  4.5";
+
+    let format = FwfFormat::colspecs(vec![(0, 1), (1, 4), (6, 9)]).has_headers(false);
+    let options = CsvReadOptions::new().index_col("0").dtype("1", "str");
+    let res = DataFrame::<String, String>::read_fwf_with(data.as_bytes(), &format, &options)
+        .unwrap();
+
+    let exp = DataFrame::from_vec(vec![array!["001".to_string(),
+                                              "002".to_string(),
+                                              "003".to_string()],
+                                       array![2.5, 3.5, 4.5]],
+                                  vec!["a".to_string(), "b".to_string(), "c".to_string()],
+                                  vec!["1".to_string(), "2".to_string()]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_read_table() {
+    let data = "A\tB\tC
+x\t\"1\t2\"\t1
+\"y\"\"z\"\t3\t";
+
+    let res = DataFrame::<usize, String>::read_table(data.as_bytes(), &TableFormat::new())
+        .unwrap();
+    let exp = DataFrame::from_vec(vec![array!["x".to_string(), "y\"z".to_string()],
+                                       array!["1\t2".to_string(), "3".to_string()],
+                                       Array::Int64Array(NullVec::with_mask(vec![1, 0],
+                                                                            Some(vec![false,
+                                                                                      true])))],
+                                  vec![0, 1],
+                                  vec!["A".to_string(), "B".to_string(), "C".to_string()]);
+    assert_eq!(res, exp);
+
+    // escape character instead of doubled quotes
+    let data = "A;B
+'x\\'y';1
+z;2";
+    let format = TableFormat::new().delimiter(b';').quote(Some(b'\'')).escape(b'\\');
+    let res = DataFrame::<usize, String>::read_table(data.as_bytes(), &format).unwrap();
+    let exp = DataFrame::from_vec(vec![array!["x'y".to_string(), "z".to_string()],
+                                       array![1i64, 2]],
+                                  vec![0, 1],
+                                  vec!["A".to_string(), "B".to_string()]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_read_table_whitespace() {
+    let data = "  id   name      score
+  1    \"a b\"     1.5
+  2    c
+
+  3    \"\"        2.5  ";
+
+    let format = TableFormat::new().whitespace();
+    let options = CsvReadOptions::new().index_col("id");
+    let res = DataFrame::<i64, String>::read_table_with(data.as_bytes(), &format, &options)
+        .unwrap();
+
+    let exp = DataFrame::from_vec(vec![Array::StringArray(NullVec::with_mask(vec!["a b"
+                                                                                  .to_string(),
+                                                                              "c".to_string(),
+                                                                              "".to_string()],
+                                                                         Some(vec![false,
+                                                                                   false,
+                                                                                   true]))),
+                                       Array::Float64Array(NullVec::with_mask(vec![1.5, 0., 2.5],
+                                                                              Some(vec![false,
+                                                                                        true,
+                                                                                        false])))],
+                                  vec![1, 2, 3],
+                                  vec!["name".to_string(), "score".to_string()]);
+    assert_eq!(res, exp);
+
+    let data = "a \"b";
+    let err = DataFrame::<usize, String>::read_table(data.as_bytes(), &format).unwrap_err();
+    assert!(err.to_string().contains("unterminated quote"));
+}
+
+#[test]
+fn test_read_table_quoted_comment() {
+    let data = "A\tB# header
+\"x#y\"\t1 # comment
+# \"quoted\"
+z\t2#";
+
+    let options = CsvReadOptions::new().comment('#');
+    let res = DataFrame::<usize, String>::read_table_with(data.as_bytes(),
+                                                          &TableFormat::new(),
+                                                          &options)
+        .unwrap();
+    let exp = DataFrame::from_vec(vec![array!["x#y".to_string(), "z".to_string()],
+                                       array![1i64, 2]],
+                                  vec![0, 1],
+                                  vec!["A".to_string(), "B".to_string()]);
+    assert_eq!(res, exp);
+
+    let format = TableFormat::new().whitespace();
+    let res = DataFrame::<usize, String>::read_table_with(data.replace('\t', " ").as_bytes(),
+                                                          &format,
+                                                          &options)
+        .unwrap();
+    assert_eq!(res, exp);
+}
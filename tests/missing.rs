@@ -0,0 +1,122 @@
+#[macro_use]
+extern crate brassfibre;
+use brassfibre::prelude::*;
+use std::f64;
+
+fn sample_frame<'a>() -> DataFrame<'a, 'a, 'a, usize, &'static str> {
+    let values = vec![Array::Int64Array(NullVec::with_mask(vec![1, 2, 3, 4],
+                                                           Some(vec![false, true, false,
+                                                                     true]))),
+                      array![1.5, f64::NAN, f64::NAN, 4.5],
+                      Array::StringArray(NullVec::with_mask(vec!["a".to_string(),
+                                                                 "b".to_string(),
+                                                                 "c".to_string(),
+                                                                 "d".to_string()],
+                                                            Some(vec![false, true, false,
+                                                                      false])))];
+    DataFrame::from_vec(values, vec![10, 20, 30, 40], vec!["X", "Y", "Z"])
+}
+
+#[test]
+fn test_series_missing() {
+    let s: Series<f64, &str> = Series::new(vec![1., f64::NAN, f64::NAN, 4.],
+                                           vec!["a", "b", "c", "d"]);
+    assert_eq!(s.isnull(),
+               Series::new(vec![false, true, true, false], vec!["a", "b", "c", "d"]));
+    assert_eq!(s.fillna(-1.),
+               Series::new(vec![1., -1., -1., 4.], vec!["a", "b", "c", "d"]));
+    assert_eq!(s.ffill(None),
+               Series::new(vec![1., 1., 1., 4.], vec!["a", "b", "c", "d"]));
+    assert_eq!(s.dropna(), Series::new(vec![1., 4.], vec!["a", "d"]));
+
+    let res = s.bfill(Some(1));
+    assert_eq!(res.values[0], 1.);
+    assert!(res.values[1].is_nan());
+    assert_eq!(res.values[2..], [4., 4.]);
+}
+
+#[test]
+fn test_frame_isnull() {
+    let df = sample_frame();
+    let exp: DataFrame<usize, &str> =
+        DataFrame::from_vec(vec![array![false, true, false, true],
+                                 array![false, true, true, false],
+                                 array![false, true, false, false]],
+                            vec![10, 20, 30, 40],
+                            vec!["X", "Y", "Z"]);
+    assert_eq!(df.isnull(), exp);
+    let exp: DataFrame<usize, &str> =
+        DataFrame::from_vec(vec![array![true, false, true, false],
+                                 array![true, false, false, true],
+                                 array![true, false, true, true]],
+                            vec![10, 20, 30, 40],
+                            vec!["X", "Y", "Z"]);
+    assert_eq!(df.notnull(), exp);
+}
+
+#[test]
+fn test_frame_fillna() {
+    let df = sample_frame();
+    let res = df.fillna(&Scalar::i64(0));
+    let exp: DataFrame<usize, &str> =
+        DataFrame::from_vec(vec![array![1i64, 0, 3, 0],
+                                 array![1.5, 0., 0., 4.5],
+                                 df.values[2].clone().into_owned()],
+                            vec![10, 20, 30, 40],
+                            vec!["X", "Y", "Z"]);
+    assert_eq!(res, exp);
+
+    let res = df.ffill(Some(1));
+    let exp: DataFrame<usize, &str> =
+        DataFrame::from_vec(vec![array![1i64, 1, 3, 3],
+                                 array![1.5, 1.5, f64::NAN, 4.5],
+                                 array!["a".to_string(), "a".to_string(), "c".to_string(),
+                                        "d".to_string()]],
+                            vec![10, 20, 30, 40],
+                            vec!["X", "Y", "Z"]);
+    assert_eq!(res, exp);
+
+    let res = df.bfill(None);
+    let exp: DataFrame<usize, &str> =
+        DataFrame::from_vec(vec![Array::Int64Array(NullVec::with_mask(vec![1, 3, 3, 4],
+                                                                      Some(vec![false, false,
+                                                                                false, true]))),
+                                 array![1.5, 4.5, 4.5, 4.5],
+                                 array!["a".to_string(), "c".to_string(), "c".to_string(),
+                                        "d".to_string()]],
+                            vec![10, 20, 30, 40],
+                            vec!["X", "Y", "Z"]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_frame_dropna_rows() {
+    let df = sample_frame();
+    let res = df.dropna(DropHow::Any, None, None);
+    assert_eq!(res.index.values, vec![10]);
+
+    let res = df.dropna(DropHow::All, None, None);
+    assert_eq!(res.index.values, vec![10, 30, 40]);
+
+    let res = df.dropna(DropHow::Any, Some(&["X", "Z"]), None);
+    assert_eq!(res.index.values, vec![10, 30]);
+
+    let res = df.dropna(DropHow::Any, None, Some(2));
+    assert_eq!(res.index.values, vec![10, 30, 40]);
+
+    let res = df.dropna(DropHow::Any, None, Some(3));
+    assert_eq!(res.index.values, vec![10]);
+}
+
+#[test]
+fn test_frame_dropna_columns() {
+    let df = sample_frame();
+    let res = df.dropna_columns(DropHow::Any, Some(&[10, 30]), None);
+    assert_eq!(res.columns.values, vec!["X", "Z"]);
+
+    let res = df.dropna_columns(DropHow::Any, None, Some(3));
+    assert_eq!(res.columns.values, vec!["Z"]);
+
+    let res = df.dropna_columns(DropHow::All, Some(&[20]), None);
+    assert_eq!(res.columns.values.len(), 0);
+}
@@ -0,0 +1,22 @@
+#![cfg(feature = "parallel")]
+
+extern crate brassfibre;
+extern crate rayon;
+use brassfibre::prelude::*;
+
+#[test]
+fn test_series_groupby_par_apply() {
+    let values: Vec<i64> = (0..1000).collect();
+    let s = Series::<i64, usize>::from_vec(values);
+    let grouper: Vec<usize> = (0..1000).map(|x| x % 7).collect();
+    let sg = s.groupby(grouper);
+
+    // groups are processed on the rayon thread pool
+    let res = sg.par_apply(|_: &Series<i64, usize>| rayon::current_thread_index().is_some());
+    assert_eq!(res.values.to_vec(), vec![true; 7]);
+
+    // results keep the group order
+    let res = sg.par_apply(|x: &Series<i64, usize>| x.values[0]);
+    let exp: Series<i64, usize> = Series::new((0..7).collect(), (0..7).collect::<Vec<usize>>());
+    assert_eq!(res, exp);
+}
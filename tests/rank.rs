@@ -0,0 +1,179 @@
+#[macro_use]
+extern crate brassfibre;
+use brassfibre::prelude::*;
+
+use std::f64;
+
+#[test]
+fn test_series_rank() {
+    let s = Series::new(vec![3, 1, 3, 2], vec!["a", "b", "c", "d"]);
+    let res = s.rank(RankMethod::Average, true, NaOption::Keep);
+    assert_eq!(res, Series::new(vec![3.5, 1., 3.5, 2.], vec!["a", "b", "c", "d"]));
+
+    let res = s.rank(RankMethod::Min, false, NaOption::Keep);
+    assert_eq!(res, Series::new(vec![1., 4., 1., 3.], vec!["a", "b", "c", "d"]));
+
+    let s = Series::new(vec![2., f64::NAN, 1.], vec!["a", "b", "c"]);
+    let res = s.rank(RankMethod::First, true, NaOption::Keep);
+    assert_eq!(res.values[0], 2.);
+    assert!(res.values[1].is_nan());
+    assert_eq!(res.values[2], 1.);
+}
+
+#[test]
+fn test_series_mode_nunique() {
+    let s = Series::<i64, usize>::from_vec(vec![3, 1, 3, 2, 1]);
+    assert_eq!(s.mode(), Series::new(vec![1, 3], vec![0, 1]));
+    assert_eq!(s.nunique(), 3);
+
+    // float values are counted by their bits, NaN is ignored
+    let s = Series::<f64, usize>::from_vec(vec![1.5, f64::NAN, 0.5, 1.5, f64::NAN]);
+    assert_eq!(s.mode(), Series::new(vec![1.5], vec![0]));
+    assert_eq!(s.nunique(), 2);
+}
+
+#[test]
+fn test_series_argmin_argmax() {
+    let s = Series::new(vec![3, 1, 5, 1, 5], vec![10, 20, 30, 40, 50]);
+    assert_eq!(s.argmin(), Some(1));
+    assert_eq!(s.argmax(), Some(2));
+    assert_eq!(s.idxmin(), Some(20));
+    assert_eq!(s.idxmax(), Some(30));
+
+    let s = Series::new(vec![f64::NAN, f64::NAN], vec![10, 20]);
+    assert_eq!(s.argmin(), None);
+    assert_eq!(s.idxmax(), None);
+}
+
+#[test]
+fn test_series_groupby_rank() {
+    let s = Series::new(vec![3, 1, 3, 2, 1], vec![10, 20, 30, 40, 50]);
+    let sg = s.groupby(vec!["A", "B", "A", "A", "B"]);
+
+    let res = sg.rank(RankMethod::Average, true, NaOption::Keep);
+    assert_eq!(res, Series::new(vec![2.5, 1.5, 2.5, 1., 1.5], vec![10, 20, 30, 40, 50]));
+
+    assert_eq!(sg.nunique(), Series::new(vec![2, 1], vec!["A", "B"]));
+    assert_eq!(sg.mode().values.to_vec(), vec![Some(3), Some(1)]);
+    assert_eq!(sg.argmin().values.to_vec(), vec![Some(2), Some(0)]);
+    assert_eq!(sg.argmax().values.to_vec(), vec![Some(0), Some(0)]);
+    assert_eq!(sg.idxmin().values.to_vec(), vec![Some(40), Some(20)]);
+    assert_eq!(sg.idxmax().values.to_vec(), vec![Some(10), Some(20)]);
+
+    let s = Series::new(vec![f64::NAN, 1., f64::NAN], vec![10, 20, 30]);
+    let sg = s.groupby(vec!["A", "B", "A"]);
+    assert_eq!(sg.mode().values.to_vec(), vec![None, Some(1.)]);
+    assert_eq!(sg.argmin().values.to_vec(), vec![None, Some(0)]);
+    assert_eq!(sg.idxmax().values.to_vec(), vec![None, Some(20)]);
+}
+
+#[test]
+fn test_frame_rank() {
+    let values = vec![array![3i64, 1, 2],
+                      array!["a".to_string(), "b".to_string(), "c".to_string()],
+                      array![0.5, f64::NAN, 0.5]];
+    let df = DataFrame::from_vec(values, vec!["x", "y", "z"], vec!["A", "B", "C"]);
+
+    let res = df.rank(RankMethod::Average, true, NaOption::Bottom);
+    let exp = DataFrame::from_vec(vec![array![3., 1., 2.], array![1.5, 3., 1.5]],
+                                  vec!["x", "y", "z"],
+                                  vec!["A", "C"]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_frame_mode_nunique() {
+    let values = vec![array![1i64, 2, 2, 1],
+                      array!["a".to_string(), "b".to_string(), "b".to_string(), "c".to_string()],
+                      array![0.5, f64::NAN, 1.5, 2.5]];
+    let df = DataFrame::from_vec(values, vec![1, 2, 3, 4], vec!["A", "B", "C"]);
+
+    let res = df.mode();
+    assert_eq!(res.index.values, vec![0, 1, 2]);
+    assert_eq!(res.get(&"A"),
+               Array::Int64Array(NullVec::with_mask(vec![1, 2, 0], Some(vec![false, false, true]))));
+    assert_eq!(res.get(&"B"),
+               Array::StringArray(NullVec::with_mask(vec!["b".to_string(), "".to_string(),
+                                                          "".to_string()],
+                                                     Some(vec![false, true, true]))));
+    assert_eq!(res.get(&"C"), array![0.5, 1.5, 2.5]);
+
+    assert_eq!(df.nunique(), Series::new(vec![2, 3, 3], vec!["A", "B", "C"]));
+}
+
+#[test]
+fn test_frame_argmin_argmax() {
+    let values = vec![array![3i64, 1, 5],
+                      array!["a".to_string(), "b".to_string(), "c".to_string()],
+                      array![f64::NAN, 2.5, 0.5]];
+    let df = DataFrame::from_vec(values, vec!["x", "y", "z"], vec!["A", "B", "C"]);
+
+    assert_eq!(df.argmin().values.to_vec(), vec![Some(1), Some(2)]);
+    assert_eq!(df.argmax().values.to_vec(), vec![Some(2), Some(1)]);
+    assert_eq!(df.idxmin().values.to_vec(), vec![Some("y"), Some("z")]);
+    assert_eq!(df.idxmax().values.to_vec(), vec![Some("z"), Some("y")]);
+
+    let df = DataFrame::from_vec(vec![array![f64::NAN, f64::NAN]], vec!["x", "y"], vec!["A"]);
+    assert_eq!(df.argmin().values.to_vec(), vec![None]);
+    assert_eq!(df.idxmax().values.to_vec(), vec![None]);
+}
+
+#[test]
+fn test_frame_groupby_rank_nunique() {
+    let values = vec![array![3i64, 1, 3, 2],
+                      array!["a".to_string(), "a".to_string(), "b".to_string(), "c".to_string()]];
+    let df = DataFrame::from_vec(values, vec![1, 2, 3, 4], vec!["A", "B"]);
+    let dg = df.groupby(vec![1, 1, 2, 2]);
+
+    let res = dg.rank(RankMethod::Dense, true, NaOption::Keep);
+    let exp = DataFrame::from_vec(vec![array![2., 1., 2., 1.]], vec![1, 2, 3, 4], vec!["A"]);
+    assert_eq!(res, exp);
+
+    let exp = DataFrame::from_vec(vec![array![2usize, 2], array![1usize, 2]],
+                                  vec![1, 2],
+                                  vec!["A", "B"]);
+    assert_eq!(dg.nunique(), exp);
+}
+
+#[test]
+fn test_frame_groupby_mode_idxmin_idxmax() {
+    let values = vec![array![3i64, 1, 3, 2, 2],
+                      array![f64::NAN, 0.5, 2.5, f64::NAN, 1.5],
+                      array!["a".to_string(), "a".to_string(), "b".to_string(), "c".to_string(),
+                             "c".to_string()]];
+    let df = DataFrame::from_vec(values, vec![10i64, 20, 30, 40, 50], vec!["A", "B", "C"]);
+    let dg = df.groupby(vec![1, 1, 2, 3, 3]);
+
+    let exp = DataFrame::from_vec(vec![array![1i64, 3, 2],
+                                       array![0.5, 2.5, 1.5],
+                                       array!["a".to_string(), "b".to_string(), "c".to_string()]],
+                                  vec![1, 2, 3],
+                                  vec!["A", "B", "C"]);
+    assert_eq!(dg.mode(), exp);
+
+    let exp = DataFrame::from_vec(vec![array![20i64, 30, 40], array![20i64, 30, 50]],
+                                  vec![1, 2, 3],
+                                  vec!["A", "B"]);
+    assert_eq!(dg.idxmin(), exp);
+
+    let exp = DataFrame::from_vec(vec![array![10i64, 30, 40], array![20i64, 30, 50]],
+                                  vec![1, 2, 3],
+                                  vec!["A", "B"]);
+    assert_eq!(dg.idxmax(), exp);
+
+    // all null group
+    let df = DataFrame::from_vec(vec![array![f64::NAN, 1.]], vec![10i64, 20], vec!["A"]);
+    let dg = df.groupby(vec![1, 2]);
+    let exp = DataFrame::from_vec(vec![Array::Int64Array(NullVec::with_mask(vec![0, 20],
+                                                                            Some(vec![true,
+                                                                                      false])))],
+                                  vec![1, 2],
+                                  vec!["A"]);
+    assert_eq!(dg.idxmin(), exp);
+    let exp = DataFrame::from_vec(vec![Array::Float64Array(NullVec::with_mask(vec![0., 1.],
+                                                                              Some(vec![true,
+                                                                                        false])))],
+                                  vec![1, 2],
+                                  vec!["A"]);
+    assert_eq!(dg.mode(), exp);
+}
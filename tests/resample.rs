@@ -0,0 +1,79 @@
+#[macro_use]
+extern crate brassfibre;
+use brassfibre::prelude::*;
+
+fn hourly_index() -> DatetimeIndex {
+    DatetimeIndex::parse(&vec!["2017-01-01 09:10", "2017-01-01 09:50", "2017-01-01 10:05",
+                               "2017-01-01 12:30", "2017-01-01 12:45"],
+                         "%Y-%m-%d %H:%M")
+        .unwrap()
+}
+
+#[test]
+fn test_series_resample_hour() {
+    let s = Series::new(vec![1., 2., 3., 4., 5.], hourly_index());
+    let r = s.resample(Frequency::Hour(1));
+
+    let exp_index = vec![Datetime::from_ymd_hms(2017, 1, 1, 9, 0, 0),
+                         Datetime::from_ymd_hms(2017, 1, 1, 10, 0, 0),
+                         Datetime::from_ymd_hms(2017, 1, 1, 12, 0, 0)];
+    assert_eq!(r.groups(), exp_index);
+
+    assert_eq!(r.sum(), Series::new(vec![3., 3., 9.], exp_index.clone()));
+    assert_eq!(r.mean(), Series::new(vec![1.5, 3., 4.5], exp_index.clone()));
+    assert_eq!(r.count(), Series::new(vec![2, 1, 2], exp_index.clone()));
+    assert_eq!(r.min(), Series::new(vec![1., 3., 4.], exp_index.clone()));
+    assert_eq!(r.max(), Series::new(vec![2., 3., 5.], exp_index.clone()));
+    assert_eq!(r.first(), Series::new(vec![1., 3., 4.], exp_index.clone()));
+    assert_eq!(r.last(), Series::new(vec![2., 3., 5.], exp_index.clone()));
+}
+
+#[test]
+fn test_series_resample_ohlc() {
+    let s = Series::new(vec![3., 1., 2., 5., 4.], hourly_index());
+    let r = s.resample(Frequency::Day(1));
+    let res = r.ohlc();
+
+    let exp = DataFrame::from_vec(vec![array![3.], array![5.], array![1.], array![4.]],
+                                  vec![Datetime::from_ymd(2017, 1, 1)],
+                                  vec!["open", "high", "low", "close"]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_series_resample_month() {
+    let idx = DatetimeIndex::parse(&vec!["2016-12-31", "2017-01-01", "2017-01-31", "2017-02-01"],
+                                   "%Y-%m-%d")
+        .unwrap();
+    let s = Series::new(vec![1, 2, 3, 4], idx);
+    let r = s.resample(Frequency::Month(1));
+    let res = r.sum();
+
+    let exp = Series::new(vec![1, 5, 4],
+                          vec![Datetime::from_ymd(2016, 12, 1),
+                               Datetime::from_ymd(2017, 1, 1),
+                               Datetime::from_ymd(2017, 2, 1)]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_frame_resample() {
+    let values = vec![array![1, 2, 3, 4, 5], array![1., 2., 3., 4., 5.]];
+    let df = DataFrame::from_vec(values, hourly_index(), vec!["X", "Y"]);
+    let r = df.resample(Frequency::Minute(30));
+
+    let exp_index = vec![Datetime::from_ymd_hms(2017, 1, 1, 9, 0, 0),
+                         Datetime::from_ymd_hms(2017, 1, 1, 9, 30, 0),
+                         Datetime::from_ymd_hms(2017, 1, 1, 10, 0, 0),
+                         Datetime::from_ymd_hms(2017, 1, 1, 12, 30, 0)];
+
+    let exp = DataFrame::from_vec(vec![array![1, 2, 3, 9], array![1., 2., 3., 9.]],
+                                  exp_index.clone(),
+                                  vec!["X", "Y"]);
+    assert_eq!(r.sum(), exp);
+
+    let exp = DataFrame::from_vec(vec![array![1., 2., 3., 4.5], array![1., 2., 3., 4.5]],
+                                  exp_index.clone(),
+                                  vec!["X", "Y"]);
+    assert_eq!(r.mean(), exp);
+}
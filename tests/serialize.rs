@@ -0,0 +1,131 @@
+#![cfg(feature = "json")]
+
+#[macro_use]
+extern crate brassfibre;
+use brassfibre::prelude::*;
+
+extern crate bincode;
+extern crate serde_json;
+
+use std::f64;
+
+#[test]
+fn test_indexer_serialize() {
+    let idx: Indexer<String> = Indexer::new(vec!["a".to_string(), "b".to_string()]);
+    assert_eq!(idx.get_loc(&"b".to_string()), 1);
+
+    let json = serde_json::to_string(&idx).unwrap();
+    assert_eq!(json, r#"{"values":["a","b"]}"#);
+
+    let res: Indexer<String> = serde_json::from_str(&json).unwrap();
+    assert_eq!(res, idx);
+    // hash table is rebuilt
+    assert_eq!(res.get_loc(&"b".to_string()), 1);
+    assert!(!res.contains(&"c".to_string()));
+}
+
+#[test]
+fn test_series_serialize() {
+    let s = Series::<f64, i64>::new(vec![1.5, 2., 3.], vec![10, 20, 30]);
+    let json = serde_json::to_string(&s).unwrap();
+    assert_eq!(json, r#"{"values":[1.5,2.0,3.0],"index":{"values":[10,20,30]}}"#);
+
+    let res: Series<f64, i64> = serde_json::from_str(&json).unwrap();
+    assert_eq!(res, s);
+    assert_eq!(res.loc(&20), 2.);
+
+    let bytes = bincode::serialize(&s).unwrap();
+    let res: Series<f64, i64> = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(res, s);
+
+    let json = r#"{"values":[1.5,2.0],"index":{"values":[10,20,30]}}"#;
+    let err = serde_json::from_str::<Series<f64, i64>>(json).unwrap_err();
+    assert!(err.to_string().starts_with("values length 2 doesn't match index length 3"));
+}
+
+#[test]
+fn test_series_serialize_nan() {
+    let s = Series::<f64, i64>::new(vec![1.5, f64::NAN, 3.], vec![10, 20, 30]);
+    let json = serde_json::to_string(&s).unwrap();
+    assert_eq!(json, r#"{"values":[1.5,null,3.0],"index":{"values":[10,20,30]}}"#);
+
+    // null is read back as NaN
+    let res: Series<f64, i64> = serde_json::from_str(&json).unwrap();
+    assert_eq!(res.values[0], 1.5);
+    assert!(res.values[1].is_nan());
+    assert_eq!(res.values[2], 3.);
+    assert_eq!(res.index, s.index);
+
+    let res: Series<f32, i64> = serde_json::from_str(&json).unwrap();
+    assert!(res.values[1].is_nan());
+
+    let bytes = bincode::serialize(&s).unwrap();
+    let res: Series<f64, i64> = bincode::deserialize(&bytes).unwrap();
+    assert!(res.values[1].is_nan());
+
+    // integers cannot hold null
+    assert!(serde_json::from_str::<Series<i64, i64>>(&json.replace(".5", "").replace(".0", ""))
+        .is_err());
+}
+
+#[test]
+fn test_series_serialize_option() {
+    let s = Series::<Option<usize>, i64>::new(vec![Some(1), None, Some(3)], vec![10, 20, 30]);
+    let json = serde_json::to_string(&s).unwrap();
+    assert_eq!(json, r#"{"values":[1,null,3],"index":{"values":[10,20,30]}}"#);
+
+    // null is read back as None
+    let res: Series<Option<usize>, i64> = serde_json::from_str(&json).unwrap();
+    assert_eq!(res.values, s.values);
+    assert_eq!(res.index, s.index);
+
+    let res: Series<Option<f64>, i64> = serde_json::from_str(&json).unwrap();
+    assert_eq!(res.values.to_vec(), vec![Some(1.), None, Some(3.)]);
+}
+
+#[test]
+fn test_frame_serialize() {
+    let values = vec![Array::Int64Array(NullVec::with_mask(vec![1, 0, 3],
+                                                           Some(vec![false, true, false]))),
+                      array![1.5f32, 2.5, 3.5],
+                      Array::StringArray(NullVec::with_mask(vec!["".to_string(),
+                                                                "y".to_string(),
+                                                                "z".to_string()],
+                                                            Some(vec![true, false, false]))),
+                      array![true, false, true]];
+    let df = DataFrame::from_vec(values,
+                                 vec!["a".to_string(), "b".to_string(), "c".to_string()],
+                                 vec!["A".to_string(), "B".to_string(), "C".to_string(),
+                                      "D".to_string()]);
+
+    let json = serde_json::to_string(&df).unwrap();
+    assert_eq!(json,
+               concat!(r#"{"values":[{"i64":{"values":[1,0,3],"mask":[false,true,false]}},"#,
+                       r#"{"f32":{"values":[1.5,2.5,3.5],"mask":null}},"#,
+                       r#"{"str":{"values":["","y","z"],"mask":[true,false,false]}},"#,
+                       r#"{"bool":{"values":[true,false,true],"mask":null}}],"#,
+                       r#""index":{"values":["a","b","c"]},"#,
+                       r#""columns":{"values":["A","B","C","D"]}}"#));
+
+    let res: DataFrame<String, String> = serde_json::from_str(&json).unwrap();
+    assert_eq!(res, df);
+
+    let bytes = bincode::serialize(&df).unwrap();
+    let res: DataFrame<String, String> = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(res, df);
+
+    let json = r#"{"values":[{"i64":{"values":[1,2],"mask":[false]}}],
+                   "index":{"values":[0,1]},"columns":{"values":["A"]}}"#;
+    let err = serde_json::from_str::<DataFrame<usize, String>>(json).unwrap_err();
+    assert!(err.to_string().starts_with("mask length 1 doesn't match values length 2"));
+
+    let json = r#"{"values":[{"i64":{"values":[1,2],"mask":null}}],
+                   "index":{"values":[0,1]},"columns":{"values":["A","B"]}}"#;
+    let err = serde_json::from_str::<DataFrame<usize, String>>(json).unwrap_err();
+    assert!(err.to_string().starts_with("1 arrays don't match columns length 2"));
+
+    let json = r#"{"values":[{"i64":{"values":[1,2],"mask":null}}],
+                   "index":{"values":[0,1,2]},"columns":{"values":["A"]}}"#;
+    let err = serde_json::from_str::<DataFrame<usize, String>>(json).unwrap_err();
+    assert!(err.to_string().starts_with("array length 2 doesn't match index length 3"));
+}
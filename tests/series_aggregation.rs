@@ -1,6 +1,8 @@
 extern crate brassfibre;
 use brassfibre::prelude::*;
 
+use std::f64;
+
 #[test]
 fn test_series_agg_int() {
     let values: Vec<i64> = vec![1, 2, 3, 4, 5];
@@ -58,14 +60,50 @@ fn test_series_agg_float() {
     assert_eq!(s.unbiased_std(), 1.2247448713915889);
 }
 
+#[test]
+fn test_series_agg_higher_order() {
+    let values: Vec<i64> = vec![1, 2, 3, 4, 10];
+    let s = Series::<i64, usize>::from_vec(values);
+
+    assert_eq!(s.prod(), 240);
+    assert_eq!(s.skew(), 1.1384199576606167);
+    assert!((s.unbiased_skew() - 1.6970562748477143).abs() < 1e-10);
+    assert!((s.kurt() - -0.21199999999999974).abs() < 1e-10);
+    assert!((s.unbiased_kurt() - 3.152000000000001).abs() < 1e-10);
+    assert!((s.sem() - (10.0f64 / 5.).sqrt()).abs() < 1e-10);
+    assert!((s.unbiased_sem() - (12.5f64 / 5.).sqrt()).abs() < 1e-10);
+}
+
+#[test]
+fn test_series_checked_prod() {
+    let s = Series::<i64, usize>::from_vec(vec![1, 2, 3, 4, 10]);
+    assert_eq!(s.checked_prod(), Some(240));
+
+    let s = Series::<u8, usize>::from_vec(vec![16, 16]);
+    assert_eq!(s.checked_prod(), None);
+}
+
+#[test]
+fn test_series_checked_sum() {
+    let s = Series::<i64, usize>::from_vec(vec![1, 2, 3, 4, 5]);
+    assert_eq!(s.checked_sum(), Some(15));
+
+    let s = Series::<u8, usize>::from_vec(vec![200, 100]);
+    assert_eq!(s.checked_sum(), None);
+}
+
 #[test]
 fn test_series_describe_int() {
     let values: Vec<i64> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
     let s = Series::<i64, i64>::from_vec(values);
 
     let d = s.describe();
-    let exp: Series<f64, &str> = Series::new(vec![10., 5.5, 2.8722813232690143, 1., 10.],
-                                             vec!["count", "mean", "std", "min", "max"]);
+    let exp: Series<f64, String> =
+        Series::new(vec![10., 5.5, 2.8722813232690143, 1., 3.25, 5.5, 7.75, 10.],
+                    vec!["count", "mean", "std", "min", "25%", "50%", "75%", "max"]
+                        .iter()
+                        .map(|x| x.to_string())
+                        .collect::<Vec<String>>());
     assert_eq!(d, exp);
 }
 
@@ -75,11 +113,48 @@ fn test_series_describe_float() {
     let s = Series::<f64, i64>::from_vec(values);
 
     let d = s.describe();
-    let exp: Series<f64, &str> = Series::new(vec![10., 5.5, 2.8722813232690143, 1., 10.],
-                                             vec!["count", "mean", "std", "min", "max"]);
+    let exp: Series<f64, String> =
+        Series::new(vec![10., 5.5, 2.8722813232690143, 1., 3.25, 5.5, 7.75, 10.],
+                    vec!["count", "mean", "std", "min", "25%", "50%", "75%", "max"]
+                        .iter()
+                        .map(|x| x.to_string())
+                        .collect::<Vec<String>>());
     assert_eq!(d, exp);
 }
 
+#[test]
+fn test_series_describe_percentiles() {
+    let values: Vec<i64> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
+    let s = Series::<i64, i64>::from_vec(values);
+
+    let d = s.describe_percentiles(&[0.1, 0.9]);
+    let exp: Vec<String> = vec!["count", "mean", "std", "min", "10%", "50%", "90%", "max"]
+        .iter()
+        .map(|x| x.to_string())
+        .collect();
+    assert_eq!(d.index.values, exp);
+    assert_eq!(d.values[4..7], [2., 6., 10.]);
+}
+
+#[test]
+fn test_series_quantile() {
+    let s = Series::<f64, usize>::from_vec(vec![4., 1., f64::NAN, 3., 2.]);
+    assert_eq!(s.median(), 2.5);
+    assert_eq!(s.quantile(0.5, QuantileInterpolation::Lower), 2.);
+    assert_eq!(s.quantile(0.5, QuantileInterpolation::Higher), 3.);
+    assert_eq!(s.quantile(0.5, QuantileInterpolation::Midpoint), 2.5);
+    assert_eq!(s.quantile(0.5, QuantileInterpolation::Nearest), 3.);
+    assert_eq!(s.quantile(0.25, QuantileInterpolation::Linear), 1.75);
+    assert!(s.quantile(1.5, QuantileInterpolation::Linear).is_nan());
+
+    let res = s.quantiles(&[0., 0.5, 1.], QuantileInterpolation::Linear);
+    let exp: Series<f64, String> = Series::new(vec![1., 2.5, 4.],
+                                               vec!["0%".to_string(),
+                                                    "50%".to_string(),
+                                                    "100%".to_string()]);
+    assert_eq!(res, exp);
+}
+
 #[test]
 fn test_series_value_counts_int() {
     let values: Vec<i64> = vec![1, 1, 3, 4, 2, 1, 1, 2, 3, 3];
@@ -58,3 +58,85 @@ fn test_series_agg_mean_integer_grouper() {
     let exp: Series<f64, i64> = Series::new(vec![2.0, 4.5], vec![1, 2]);
     assert_eq!(sum, exp);
 }
+
+#[test]
+fn test_series_agg_first_last() {
+    let values: Vec<i64> = vec![1, 2, 3, 4, 5];
+    let index: Vec<i64> = vec![10, 20, 30, 40, 50];
+    let s = Series::<i64, i64>::new(values, index);
+
+    let sg = s.groupby(vec![1, 2, 1, 1, 2]);
+    assert_eq!(sg.first(), Series::new(vec![1, 2], vec![1, 2]));
+    assert_eq!(sg.last(), Series::new(vec![4, 5], vec![1, 2]));
+}
+
+#[test]
+fn test_series_agg_ohlc() {
+    let values: Vec<i64> = vec![3, 2, 5, 1, 4, 6];
+    let s = Series::<i64, usize>::from_vec(values);
+
+    let sg = s.groupby(vec!["A", "A", "A", "A", "B", "B"]);
+    let res = sg.ohlc();
+
+    let exp_values = vec![Array::new(vec![3i64, 4]),
+                          Array::new(vec![5i64, 6]),
+                          Array::new(vec![1i64, 4]),
+                          Array::new(vec![1i64, 6])];
+    let exp = DataFrame::from_vec(exp_values,
+                                  vec!["A", "B"],
+                                  vec!["open", "high", "low", "close"]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_series_agg_quantile() {
+    let values: Vec<i64> = vec![3, 2, 5, 1, 4, 6];
+    let s = Series::<i64, usize>::from_vec(values);
+
+    let sg = s.groupby(vec!["A", "A", "A", "A", "B", "B"]);
+    assert_eq!(sg.median(), Series::new(vec![2.5, 5.], vec!["A", "B"]));
+    assert_eq!(sg.quantile(0.5, QuantileInterpolation::Lower),
+               Series::new(vec![2., 4.], vec!["A", "B"]));
+
+    let res = sg.describe();
+    let exp_columns: Vec<String> =
+        vec!["count", "mean", "std", "min", "25%", "50%", "75%", "max"]
+            .iter()
+            .map(|x| x.to_string())
+            .collect();
+    assert_eq!(res.columns.values, exp_columns);
+    assert_eq!(res.index.values, vec!["A", "B"]);
+    assert_eq!(res.get(&"50%".to_string()), Array::new(vec![2.5, 5.]));
+    assert_eq!(res.get(&"max".to_string()), Array::new(vec![5., 6.]));
+}
+
+#[test]
+fn test_series_agg_higher_order() {
+    let values: Vec<i64> = vec![1, 2, 3, 4, 10, 2, 4];
+    let s = Series::<i64, usize>::from_vec(values);
+
+    let sg = s.groupby(vec![1, 1, 1, 1, 1, 2, 2]);
+    assert_eq!(sg.prod(), Series::new(vec![240, 8], vec![1, 2]));
+    assert_eq!(sg.skew(), Series::new(vec![1.1384199576606167, 0.], vec![1, 2]));
+
+    let res = sg.unbiased_kurt();
+    assert!((res.values[0] - 3.152000000000001).abs() < 1e-10);
+    assert!(res.values[1].is_nan());
+}
+
+#[test]
+#[cfg(feature = "parallel")]
+fn test_series_par_apply() {
+    let values: Vec<f64> = (0..1000).map(|x| x as f64).collect();
+    let s = Series::<f64, usize>::from_vec(values);
+    let grouper: Vec<usize> = (0..1000).map(|x| x % 7).collect();
+
+    let sg = s.groupby(grouper);
+    let res = sg.par_apply(|x: &Series<f64, usize>| x.sum());
+    let exp = sg.apply(&|x: &Series<f64, usize>| x.sum());
+    assert_eq!(res, exp);
+
+    let res = sg.par_apply(|x: &Series<f64, usize>| x.index.values[0]);
+    let exp: Series<usize, usize> = Series::new((0..7).collect(), (0..7).collect::<Vec<usize>>());
+    assert_eq!(res, exp);
+}
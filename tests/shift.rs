@@ -0,0 +1,85 @@
+#[macro_use]
+extern crate brassfibre;
+use brassfibre::prelude::*;
+use std::f64;
+
+#[test]
+fn test_series_shift() {
+    let s: Series<i64, &str> = Series::new(vec![1, 2, 3, 6], vec!["a", "b", "c", "d"]);
+    let res = s.shift(2);
+    assert_eq!(res.values.to_vec(),
+               vec![Nullable::Null, Nullable::Null, Nullable::new(1), Nullable::new(2)]);
+    assert_eq!(res.index.values, vec!["a", "b", "c", "d"]);
+
+    let res = s.diff(-1);
+    assert_eq!(&res.values[..3], &[-1., -1., -3.]);
+    assert!(res.values[3].is_nan());
+
+    let res = s.pct_change(1);
+    assert!(res.values[0].is_nan());
+    assert_eq!(&res.values[1..], &[1., 0.5, 1.]);
+    assert_eq!(res.index.values, vec!["a", "b", "c", "d"]);
+}
+
+#[test]
+fn test_frame_shift() {
+    let values = vec![array![1, 2, 3],
+                      array!["a".to_string(), "b".to_string(), "c".to_string()]];
+    let df: DataFrame<usize, &str> = DataFrame::from_vec(values, vec![10, 20, 30],
+                                                         vec!["X", "Y"]);
+    let res = df.shift(1);
+    let exp_values = vec![Array::Int32Array(NullVec::with_mask(vec![0, 1, 2],
+                                                               Some(vec![true, false, false]))),
+                          Array::StringArray(NullVec::with_mask(vec!["".to_string(),
+                                                                     "a".to_string(),
+                                                                     "b".to_string()],
+                                                                Some(vec![true, false,
+                                                                          false])))];
+    let exp: DataFrame<usize, &str> = DataFrame::from_vec(exp_values, vec![10, 20, 30],
+                                                          vec!["X", "Y"]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_frame_diff_pct_change() {
+    let values = vec![array![1, 2, 4],
+                      array![2., f64::NAN, 3.],
+                      array!["a".to_string(), "b".to_string(), "c".to_string()]];
+    let df: DataFrame<usize, &str> = DataFrame::from_vec(values, vec![10, 20, 30],
+                                                         vec!["X", "Y", "Z"]);
+    let exp: DataFrame<usize, &str> = DataFrame::from_vec(vec![array![f64::NAN, 1., 2.],
+                                                               array![f64::NAN, f64::NAN,
+                                                                      f64::NAN]],
+                                                          vec![10, 20, 30],
+                                                          vec!["X", "Y"]);
+    assert_eq!(df.diff(1), exp);
+
+    let exp: DataFrame<usize, &str> = DataFrame::from_vec(vec![array![f64::NAN, f64::NAN, 3.],
+                                                               array![f64::NAN, f64::NAN, 0.5]],
+                                                          vec![10, 20, 30],
+                                                          vec!["X", "Y"]);
+    assert_eq!(df.pct_change(2), exp);
+}
+
+#[test]
+fn test_tshift() {
+    let idx = DatetimeIndex::parse(&vec!["2017-01-31", "2017-02-28"], "%Y-%m-%d").unwrap();
+    let s = Series::new(vec![1, 2], idx);
+
+    let res = s.tshift(1, Frequency::Day(1));
+    assert_eq!(res.values.to_vec(), vec![1, 2]);
+    assert_eq!(res.index.values,
+               vec![Datetime::from_ymd(2017, 2, 1), Datetime::from_ymd(2017, 3, 1)]);
+
+    let res = s.tshift(-1, Frequency::Month(1));
+    assert_eq!(res.index.values,
+               vec![Datetime::from_ymd(2016, 12, 31), Datetime::from_ymd(2017, 1, 28)]);
+
+    let df: DataFrame<Datetime, &str> = DataFrame::from_vec(vec![array![1, 2]],
+                                                            s.index.clone().into_owned(),
+                                                            vec!["X"]);
+    let res = df.tshift(2, Frequency::Hour(12));
+    assert_eq!(res.index.values,
+               vec![Datetime::from_ymd(2017, 2, 1), Datetime::from_ymd(2017, 3, 1)]);
+    assert_eq!(res.values, df.values);
+}
@@ -0,0 +1,172 @@
+#[macro_use]
+extern crate brassfibre;
+use brassfibre::prelude::*;
+use std::f64;
+
+fn assert_nan_eq(res: &[f64], exp: &[f64]) {
+    assert_eq!(res.len(), exp.len());
+    for (r, e) in res.iter().zip(exp.iter()) {
+        if e.is_nan() {
+            assert!(r.is_nan(), "{:?} != {:?}", res, exp);
+        } else {
+            assert!((r - e).abs() < 1e-10, "{:?} != {:?}", res, exp);
+        }
+    }
+}
+
+#[test]
+fn test_series_rolling_sum() {
+    let s: Series<i64, i64> = Series::new(vec![1, 2, 3, 4, 5], vec![10, 20, 30, 40, 50]);
+    let r = s.rolling(3);
+    let res = r.sum();
+    assert_eq!(res.index.values, vec![10, 20, 30, 40, 50]);
+    assert_nan_eq(&res.values, &[f64::NAN, f64::NAN, 6., 9., 12.]);
+
+    let r = s.rolling(3).min_periods(1);
+    assert_nan_eq(&r.sum().values, &[1., 3., 6., 9., 12.]);
+    assert_nan_eq(&r.mean().values, &[1., 1.5, 2., 3., 4.]);
+    assert_eq!(r.count().values.to_vec(), vec![1, 2, 3, 3, 3]);
+}
+
+#[test]
+fn test_series_rolling_center() {
+    let s: Series<f64, usize> = Series::<f64, usize>::from_vec(vec![1., 2., 3., 4., 5.]);
+    let r = s.rolling(3).center(true);
+    assert_nan_eq(&r.mean().values, &[f64::NAN, 2., 3., 4., f64::NAN]);
+    assert_nan_eq(&r.max().values, &[f64::NAN, 3., 4., 5., f64::NAN]);
+}
+
+#[test]
+fn test_series_rolling_null() {
+    let s: Series<f64, usize> = Series::<f64, usize>::from_vec(vec![1., f64::NAN, 3., 5., 2.]);
+    let r = s.rolling(3).min_periods(2);
+    assert_nan_eq(&r.sum().values, &[f64::NAN, f64::NAN, 4., 8., 10.]);
+    assert_nan_eq(&r.min().values, &[f64::NAN, f64::NAN, 1., 3., 2.]);
+    assert_nan_eq(&r.unbiased_var().values, &[f64::NAN, f64::NAN, 2., 2., 7. / 3.]);
+    assert_nan_eq(&r.var().values, &[f64::NAN, f64::NAN, 1., 1., 14. / 9.]);
+}
+
+#[test]
+fn test_frame_rolling() {
+    let values = vec![array![1, 2, 3, 4],
+                      array![1.5, 2.5, 3.5, 4.5],
+                      array!["a".to_string(), "b".to_string(), "c".to_string(),
+                             "d".to_string()]];
+    let df: DataFrame<usize, &str> = DataFrame::from_vec(values, vec![0, 1, 2, 3],
+                                                         vec!["X", "Y", "Z"]);
+    let r = df.rolling(2);
+    let res = r.sum();
+    let exp: DataFrame<usize, &str> = DataFrame::from_vec(vec![array![f64::NAN, 3., 5., 7.],
+                                                               array![f64::NAN, 4., 6., 8.]],
+                                                          vec![0, 1, 2, 3],
+                                                          vec!["X", "Y"]);
+    assert_eq!(res, exp);
+
+    let res = r.count();
+    let exp: DataFrame<usize, &str> = DataFrame::from_vec(vec![array![1usize, 2, 2, 2],
+                                                               array![1usize, 2, 2, 2]],
+                                                          vec![0, 1, 2, 3],
+                                                          vec!["X", "Y"]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_frame_rolling_null() {
+    let values = vec![Array::Int64Array(NullVec::with_mask(vec![1, 2, 3, 4],
+                                                           Some(vec![false, true, false,
+                                                                     false])))];
+    let df: DataFrame<usize, &str> = DataFrame::from_vec(values, vec![0, 1, 2, 3], vec!["X"]);
+    let r = df.rolling(2).min_periods(1);
+    let res = r.max();
+    let exp: DataFrame<usize, &str> = DataFrame::from_vec(vec![array![1., 1., 3., 4.]],
+                                                          vec![0, 1, 2, 3],
+                                                          vec!["X"]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_series_expanding() {
+    let s: Series<f64, usize> = Series::<f64, usize>::from_vec(vec![3., f64::NAN, 1., 5.]);
+    let e = s.expanding();
+    assert_nan_eq(&e.mean().values, &[3., 3., 2., 3.]);
+    assert_nan_eq(&e.max().values, &[3., 3., 3., 5.]);
+    assert_nan_eq(&e.unbiased_std().values, &[f64::NAN, f64::NAN, 2f64.sqrt(), 2.]);
+
+    let e = s.expanding().min_periods(2);
+    assert_nan_eq(&e.min().values, &[f64::NAN, f64::NAN, 1., 1.]);
+}
+
+#[test]
+fn test_series_ewm() {
+    let s: Series<i64, usize> = Series::<i64, usize>::from_vec(vec![1, 2, 3, 4]);
+    // pandas: Series([1, 2, 3, 4]).ewm(span=3).mean()
+    let e = s.ewm(Decay::Span(3.));
+    assert_nan_eq(&e.mean().values,
+                  &[1., 1.6666666666666667, 2.4285714285714284, 3.2666666666666666]);
+    assert_nan_eq(&e.unbiased_var().values,
+                  &[f64::NAN, 0.5, 0.9285714285714286, 1.3857142857142857]);
+
+    let e = s.ewm(Decay::Alpha(0.5)).adjust(false);
+    assert_nan_eq(&e.mean().values, &[1., 1.5, 2.25, 3.125]);
+
+    let e = s.ewm(Decay::Com(1.)).min_periods(3);
+    assert_nan_eq(&e.mean().values,
+                  &[f64::NAN, f64::NAN, 2.4285714285714284, 3.2666666666666666]);
+}
+
+#[test]
+fn test_frame_expanding_ewm() {
+    let values = vec![array![1, 2, 3, 4],
+                      array!["a".to_string(), "b".to_string(), "c".to_string(),
+                             "d".to_string()]];
+    let df: DataFrame<usize, &str> = DataFrame::from_vec(values, vec![0, 1, 2, 3],
+                                                         vec!["X", "Y"]);
+    let e = df.expanding();
+    let exp: DataFrame<usize, &str> = DataFrame::from_vec(vec![array![1., 3., 6., 10.]],
+                                                          vec![0, 1, 2, 3],
+                                                          vec!["X"]);
+    assert_eq!(e.sum(), exp);
+
+    let e = df.ewm(Decay::Alpha(0.5)).adjust(false);
+    let exp: DataFrame<usize, &str> = DataFrame::from_vec(vec![array![1., 1.5, 2.25, 3.125]],
+                                                          vec![0, 1, 2, 3],
+                                                          vec!["X"]);
+    assert_eq!(e.mean(), exp);
+}
+
+#[test]
+fn test_series_groupby_window() {
+    let s: Series<f64, &str> = Series::new(vec![1., 10., 2., 20., 3.],
+                                           vec!["a", "b", "c", "d", "e"]);
+    let sg = s.groupby(vec![1, 2, 1, 2, 1]);
+
+    let e = sg.expanding();
+    let res = e.sum();
+    assert_eq!(res.index.values, vec!["a", "b", "c", "d", "e"]);
+    assert_nan_eq(&res.values, &[1., 10., 3., 30., 6.]);
+
+    let r = sg.rolling(2);
+    assert_nan_eq(&r.mean().values, &[f64::NAN, f64::NAN, 1.5, 15., 2.5]);
+
+    let e = sg.ewm(Decay::Alpha(0.5)).adjust(false);
+    assert_nan_eq(&e.mean().values, &[1., 10., 1.5, 15., 2.25]);
+}
+
+#[test]
+fn test_frame_groupby_window() {
+    let values = vec![array![1, 10, 2, 20, 3], array![1., 2., 3., 4., 5.]];
+    let df: DataFrame<usize, &str> = DataFrame::from_vec(values, vec![0, 1, 2, 3, 4],
+                                                         vec!["X", "Y"]);
+    let dg = df.groupby(vec!["a", "b", "a", "b", "a"]);
+    let e = dg.expanding();
+    let exp: DataFrame<usize, &str> =
+        DataFrame::from_vec(vec![array![1., 10., 2., 20., 3.], array![1., 2., 3., 4., 5.]],
+                            vec![0, 1, 2, 3, 4],
+                            vec!["X", "Y"]);
+    assert_eq!(e.max(), exp);
+    let exp: DataFrame<usize, &str> =
+        DataFrame::from_vec(vec![array![1usize, 1, 2, 2, 3], array![1usize, 1, 2, 2, 3]],
+                            vec![0, 1, 2, 3, 4],
+                            vec!["X", "Y"]);
+    assert_eq!(e.count(), exp);
+}